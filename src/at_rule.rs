@@ -0,0 +1,427 @@
+//! # CSS At-Rule Module
+//!
+//! This module provides the `AtRule` type, a generic representation of CSS at-rules
+//! such as `@import`, `@font-face` or `@media`.
+//!
+//! At-rules come in three shapes, represented by `AtRuleBody`:
+//!
+//! - **Statements** end with a semicolon and have no block (`@import url("reset.css");`)
+//! - **Declaration blocks** contain declarations, like a style rule (`@font-face { ... }`)
+//! - **Nested blocks** contain other rules and at-rules (`@media screen { ... }`)
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::at_rule::AtRule;
+//! use mew_css::{rule, style};
+//! use mew_css::values::Color;
+//!
+//! let import = AtRule::statement("import", "url(\"reset.css\")");
+//! assert_eq!(import.to_string(), "@import url(\"reset.css\");");
+//!
+//! let print = AtRule::with_items("media", "print", vec![
+//!     rule(".nav", style().background_color(Color::White)).into(),
+//! ]);
+//! assert_eq!(print.to_string(), "@media print { .nav { background-color: white; } }");
+//! ```
+
+use crate::container::ContainerPrelude;
+use crate::format::OutputOptions;
+use crate::media::MediaQuery;
+use crate::style::Style;
+use crate::stylesheet::StylesheetItem;
+use crate::supports::SupportsCondition;
+use std::fmt;
+
+/// The content of an at-rule.
+///
+/// Each variant corresponds to one of the syntactic forms an at-rule can take.
+#[derive(Debug, Clone)]
+pub enum AtRuleBody {
+    /// No block, the at-rule is terminated by a semicolon
+    Statement,
+    /// A block of declarations (e.g., `@font-face`, `@page`)
+    Declarations(Style),
+    /// A block of nested rules and at-rules (e.g., `@media`, `@supports`)
+    Items(Vec<StylesheetItem>),
+}
+
+/// Represents a CSS at-rule with a name, an optional prelude and a body.
+///
+/// The name is stored without the leading `@`, and the prelude is everything
+/// between the name and the block (or the semicolon for statements).
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::at_rule::AtRule;
+/// use mew_css::style;
+///
+/// let page = AtRule::with_declarations("page", "", style().custom_property("margin", "1cm"));
+/// assert_eq!(page.to_string(), "@page { margin: 1cm; }");
+/// ```
+#[derive(Debug, Clone)]
+pub struct AtRule {
+    /// The at-rule name without the `@` (e.g., "media", "import")
+    name: String,
+    /// The prelude of the at-rule (e.g., "screen and (min-width: 768px)")
+    prelude: String,
+    /// The content of the at-rule
+    body: AtRuleBody,
+}
+
+impl AtRule {
+    /// Creates a new at-rule from its name, prelude and body.
+    ///
+    /// The name can be provided with or without the leading `@`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The at-rule name (with or without the `@` prefix)
+    /// * `prelude` - The prelude of the at-rule, which can be empty
+    /// * `body` - The content of the at-rule
+    ///
+    /// # Returns
+    ///
+    /// A new `AtRule` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::at_rule::{AtRule, AtRuleBody};
+    ///
+    /// let charset = AtRule::new("@charset", "\"utf-8\"", AtRuleBody::Statement);
+    /// assert_eq!(charset.to_string(), "@charset \"utf-8\";");
+    /// ```
+    pub fn new(name: &str, prelude: &str, body: AtRuleBody) -> Self {
+        Self {
+            name: name.trim().trim_start_matches('@').to_string(),
+            prelude: prelude.trim().to_string(),
+            body,
+        }
+    }
+
+    /// Creates a statement at-rule, which has no block.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The at-rule name (with or without the `@` prefix)
+    /// * `prelude` - The prelude of the at-rule
+    ///
+    /// # Returns
+    ///
+    /// A new `AtRule` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::at_rule::AtRule;
+    ///
+    /// let import = AtRule::statement("import", "url(\"theme.css\") screen");
+    /// assert_eq!(import.to_string(), "@import url(\"theme.css\") screen;");
+    /// ```
+    pub fn statement(name: &str, prelude: &str) -> Self {
+        Self::new(name, prelude, AtRuleBody::Statement)
+    }
+
+    /// Creates an at-rule whose block contains declarations.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The at-rule name (with or without the `@` prefix)
+    /// * `prelude` - The prelude of the at-rule, which can be empty
+    /// * `style` - The declarations of the block
+    ///
+    /// # Returns
+    ///
+    /// A new `AtRule` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::at_rule::AtRule;
+    /// use mew_css::style;
+    ///
+    /// let font = AtRule::with_declarations("font-face", "", style()
+    ///     .custom_property("font-family", "\"Inter\""));
+    /// assert_eq!(font.to_string(), "@font-face { font-family: \"Inter\"; }");
+    /// ```
+    pub fn with_declarations(name: &str, prelude: &str, style: &Style) -> Self {
+        Self::new(name, prelude, AtRuleBody::Declarations(style.clone()))
+    }
+
+    /// Creates an at-rule whose block contains nested rules and at-rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The at-rule name (with or without the `@` prefix)
+    /// * `prelude` - The prelude of the at-rule, which can be empty
+    /// * `items` - The rules and at-rules of the block
+    ///
+    /// # Returns
+    ///
+    /// A new `AtRule` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::at_rule::AtRule;
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::AlignItems;
+    ///
+    /// let media = AtRule::with_items("media", "screen", vec![
+    ///     rule(".row", style().align_items(AlignItems::Center)).into(),
+    /// ]);
+    /// assert_eq!(media.to_string(), "@media screen { .row { align-items: center; } }");
+    /// ```
+    pub fn with_items(name: &str, prelude: &str, items: Vec<StylesheetItem>) -> Self {
+        Self::new(name, prelude, AtRuleBody::Items(items))
+    }
+
+    /// Creates a `@media` at-rule containing the given rules and at-rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The media query that must match
+    /// * `items` - The rules and at-rules of the block
+    ///
+    /// # Returns
+    ///
+    /// A new `AtRule` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::at_rule::AtRule;
+    /// use mew_css::media::MediaQuery;
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let print = AtRule::media(&MediaQuery::print(), vec![
+    ///     rule("body", style().background_color(Color::White)).into(),
+    /// ]);
+    /// assert_eq!(print.to_string(), "@media print { body { background-color: white; } }");
+    /// ```
+    pub fn media(query: &MediaQuery, items: Vec<StylesheetItem>) -> Self {
+        Self::with_items("media", &query.to_string(), items)
+    }
+
+    /// Creates a `@supports` at-rule containing the given rules and at-rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - The feature query that must be supported
+    /// * `items` - The rules and at-rules of the block
+    ///
+    /// # Returns
+    ///
+    /// A new `AtRule` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::at_rule::AtRule;
+    /// use mew_css::supports::SupportsCondition;
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let at_rule = AtRule::supports(&SupportsCondition::selector(":has(a)"), vec![
+    ///     rule("li:has(a)", style().background_color(Color::Yellow)).into(),
+    /// ]);
+    /// assert_eq!(at_rule.to_string(), "@supports selector(:has(a)) { li:has(a) { background-color: yellow; } }");
+    /// ```
+    pub fn supports(condition: &SupportsCondition, items: Vec<StylesheetItem>) -> Self {
+        Self::with_items("supports", &condition.to_string(), items)
+    }
+
+    /// Creates a `@container` at-rule containing the given rules and at-rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The container query that must match, optionally named
+    /// * `items` - The rules and at-rules of the block
+    ///
+    /// # Returns
+    ///
+    /// A new `AtRule` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::at_rule::AtRule;
+    /// use mew_css::container::ContainerQuery;
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::{Display, Size};
+    ///
+    /// let at_rule = AtRule::container(&ContainerQuery::max_width(Size::Px(300.0)), vec![
+    ///     rule(".meta", style().display(Display::None)).into(),
+    /// ]);
+    /// assert_eq!(at_rule.to_string(), "@container (max-width: 300px) { .meta { display: none; } }");
+    /// ```
+    pub fn container(query: impl Into<ContainerPrelude>, items: Vec<StylesheetItem>) -> Self {
+        Self::with_items("container", &query.into().to_string(), items)
+    }
+
+    /// Creates a named `@layer` block containing the given rules and at-rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The layer name, which can be a dotted path to a nested layer (e.g., `"components.buttons"`)
+    /// * `items` - The rules and at-rules of the block
+    ///
+    /// # Returns
+    ///
+    /// A new `AtRule` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::at_rule::AtRule;
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let at_rule = AtRule::layer("base", vec![
+    ///     rule("body", style().background_color(Color::White)).into(),
+    /// ]);
+    /// assert_eq!(at_rule.to_string(), "@layer base { body { background-color: white; } }");
+    /// ```
+    pub fn layer(name: &str, items: Vec<StylesheetItem>) -> Self {
+        Self::with_items("layer", name, items)
+    }
+
+    /// Creates a `@layer` statement declaring the order of the given layers.
+    ///
+    /// Layers declared first have the lowest priority. This statement is usually placed
+    /// at the top of a stylesheet, before any layer block.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The layer names, from lowest to highest priority
+    ///
+    /// # Returns
+    ///
+    /// A new `AtRule` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::at_rule::AtRule;
+    ///
+    /// let at_rule = AtRule::layer_order(&["reset", "base", "components"]);
+    /// assert_eq!(at_rule.to_string(), "@layer reset, base, components;");
+    /// ```
+    pub fn layer_order(names: &[&str]) -> Self {
+        let names: Vec<&str> = names.iter().map(|name| name.trim()).collect();
+        Self::statement("layer", &names.join(", "))
+    }
+
+    /// Returns the name of the at-rule, without the leading `@`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the prelude of the at-rule.
+    pub fn prelude(&self) -> &str {
+        &self.prelude
+    }
+
+    /// Returns the body of the at-rule.
+    pub fn body(&self) -> &AtRuleBody {
+        &self.body
+    }
+
+    /// Generates the CSS text of this at-rule with the given output options.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The output options to use
+    ///
+    /// # Returns
+    ///
+    /// A string containing the CSS representation of the at-rule
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::at_rule::AtRule;
+    /// use mew_css::format::OutputOptions;
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let print = AtRule::with_items("media", "print", vec![
+    ///     rule(".nav", style().background_color(Color::White)).into(),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     print.to_css_with(&OutputOptions::pretty()),
+    ///     "@media print {\n  .nav {\n    background-color: white;\n  }\n}"
+    /// );
+    /// assert_eq!(print.to_css_with(&OutputOptions::minified()), "@media print{.nav{background-color:white}}");
+    /// ```
+    pub fn to_css_with(&self, options: &OutputOptions) -> String {
+        options.block(&self.clone().into(), 0)
+    }
+}
+
+impl fmt::Display for AtRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_css_with(&OutputOptions::compact()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::rule;
+    use crate::style::style;
+    use crate::values::{AlignItems, Color};
+
+    #[test]
+    fn test_statement() {
+        let at_rule = AtRule::statement("import", "url(\"reset.css\")");
+        assert_eq!(at_rule.to_string(), "@import url(\"reset.css\");");
+
+        let at_rule = AtRule::statement("@layer", "base");
+        assert_eq!(at_rule.name(), "layer");
+        assert_eq!(at_rule.to_string(), "@layer base;");
+    }
+
+    #[test]
+    fn test_declarations() {
+        let at_rule = AtRule::with_declarations("page", ":first", style().custom_property("margin", "2cm"));
+        assert_eq!(at_rule.to_string(), "@page :first { margin: 2cm; }");
+
+        let at_rule = AtRule::with_declarations("font-face", "", &style());
+        assert_eq!(at_rule.to_string(), "@font-face {}");
+    }
+
+    #[test]
+    fn test_items() {
+        let at_rule = AtRule::with_items(
+            "media",
+            "print",
+            vec![
+                rule(".nav", style().background_color(Color::White)).into(),
+                rule(".row", style().align_items(AlignItems::Start)).into(),
+            ],
+        );
+        assert_eq!(
+            at_rule.to_string(),
+            "@media print { .nav { background-color: white; } .row { align-items: start; } }"
+        );
+    }
+
+    #[test]
+    fn test_nested_items() {
+        let inner = AtRule::with_items(
+            "media",
+            "(min-width: 768px)",
+            vec![rule(".nav", style().background_color(Color::Black)).into()],
+        );
+        let outer = AtRule::with_items("supports", "(display: grid)", vec![inner.into()]);
+        assert_eq!(
+            outer.to_string(),
+            "@supports (display: grid) { @media (min-width: 768px) { .nav { background-color: black; } } }"
+        );
+    }
+}
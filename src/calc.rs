@@ -0,0 +1,1816 @@
+//! # Calc Module
+//!
+//! This module provides `Calc`, a typed `calc()` expression, and the arithmetic
+//! operators on `Size` that build it, as well as the `min()`, `max()` and `clamp()`
+//! comparison functions (see `Calc::min`, `Calc::max`, `Calc::clamp` and `Calc::fluid`)
+//! and the other CSS math functions: stepped value functions (`round()`, `mod()`,
+//! `rem()`), sign-related functions (`abs()`, `sign()`), trigonometric functions (`sin()`,
+//! `cos()`, `tan()`, `asin()`, `acos()`, `atan()`, `atan2()`) and exponential functions
+//! (`pow()`, `sqrt()`, `hypot()`, `log()`, `exp()`).
+//!
+//! Adding or subtracting sizes (`+`, `-`), or multiplying and dividing them by a number
+//! (`*`, `/`), returns a new `Size`. Terms of the same unit are simplified, so that a
+//! `calc()` expression is only written when the browser has to compute the value, such
+//! as when percentages and `rem` are mixed or when a CSS variable is involved.
+//!
+//! The math functions check the types of their arguments and return an error for CSS
+//! that browsers would reject, such as `sqrt(4px)` or `min(1px, 90deg)`.
+//!
+//! `Size::Auto` has no length to compute with and cannot be part of an expression. The
+//! operators and `Calc::from` keep it as an invalid term, which `Calc::validate` and
+//! `Size::validate` report (as do the `Style` methods taking a `Size`), while
+//! `Size::try_add`, `Size::try_sub`, `Size::try_mul`, `Size::try_div` and `Calc::try_size`
+//! return an error right away.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::calc::Calc;
+//! use mew_css::values::{Angle, FontSize, Size};
+//! use mew_css::var;
+//!
+//! let width = Size::Percent(100.0) - Size::Rem(2.0);
+//! assert_eq!(width.to_string(), "calc(100% - 2rem)");
+//!
+//! // Terms of the same unit are simplified
+//! assert_eq!((width + Size::Rem(0.5)).to_string(), "calc(100% - 1.5rem)");
+//! assert_eq!((Size::Px(10.0) + Size::Px(6.0)) * 2.0, Size::Px(32.0));
+//!
+//! // CSS variables are left to the browser
+//! let gap = Size::Var(var("gap")) * 2.0;
+//! assert_eq!(gap.to_string(), "calc(var(--gap) * 2)");
+//! assert_eq!(((Size::Percent(100.0) - gap) / 3.0).to_string(), "calc((100% - var(--gap) * 2) / 3)");
+//!
+//! // Comparison functions can be used as sizes, font sizes and line heights
+//! let width: Size = Calc::min([Size::Percent(100.0), Size::Px(960.0)]).unwrap().into();
+//! assert_eq!(width.to_string(), "min(100%, 960px)");
+//!
+//! let font_size = FontSize::from(Calc::fluid(16.0, 24.0, 320.0, 1280.0));
+//! assert_eq!(font_size.to_string(), "clamp(1rem, 0.8333vw + 0.8333rem, 1.5rem)");
+//!
+//! // Math functions compose with lengths, angles and CSS variables
+//! let step = Calc::from(Angle::Turn(1.0)) * Calc::from(var("index")) / Calc::from(var("count"));
+//! let x = Size::from(Calc::from(Size::Px(100.0)) * Calc::cos(step).unwrap());
+//! assert_eq!(x.to_string(), "calc(100px * cos(1turn * var(--index) / var(--count)))");
+//! assert_eq!(Calc::sin(Angle::Deg(30.0)).unwrap(), Calc::Number(0.5));
+//! ```
+
+use crate::error::MewError;
+use crate::values::{Angle, Number, ParseValueError, Size};
+use crate::variable::CssVar;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// A typed `calc()` expression.
+///
+/// Expressions are usually built with the arithmetic operators on `Size` (see the
+/// module documentation), which simplify them as they go. The variants can also be used
+/// directly, in which case the expression is written as given.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::calc::Calc;
+/// use mew_css::values::{LineHeight, Size};
+///
+/// let calc = Calc::from(Size::Em(1.0)) + Calc::Number(0.5) * Calc::from(Size::Rem(1.0));
+/// assert_eq!(calc.to_string(), "calc(1em + 0.5rem)");
+///
+/// let line_height = LineHeight::Calc(Calc::Number(1.2) + Calc::Number(0.3));
+/// assert_eq!(line_height.to_string(), "calc(1.5)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Calc {
+    /// A unitless number
+    Number(f32),
+    /// A length or a percentage
+    Length(Size),
+    /// An angle
+    Angle(Angle),
+    /// A CSS variable
+    Var(CssVar),
+    /// A sum of terms, where subtracted terms are negated
+    Sum(Vec<Calc>),
+    /// A negated term, for terms without a number to negate (e.g., `var(--gap)`)
+    Negate(Box<Calc>),
+    /// A product of two terms
+    Product(Box<Calc>, Box<Calc>),
+    /// A quotient of two terms
+    Quotient(Box<Calc>, Box<Calc>),
+    /// A math function such as `min()`, with its arguments
+    Function(MathFunction, Vec<Calc>),
+}
+
+/// A CSS math function, used in a `Calc` expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MathFunction {
+    /// `min()`: the smallest of its arguments
+    Min,
+    /// `max()`: the largest of its arguments
+    Max,
+    /// `clamp()`: its second argument, clamped between the first and the third one
+    Clamp,
+    /// `round()`: its first argument, rounded to a multiple of the second one (1 by default)
+    Round(RoundingStrategy),
+    /// `mod()`: the modulus of its arguments, with the sign of the second one
+    Mod,
+    /// `rem()`: the remainder of its arguments, with the sign of the first one
+    Rem,
+    /// `abs()`: the absolute value of its argument
+    Abs,
+    /// `sign()`: -1, 0 or 1 depending on the sign of its argument
+    Sign,
+    /// `sin()`: the sine of an angle or a number of radians
+    Sin,
+    /// `cos()`: the cosine of an angle or a number of radians
+    Cos,
+    /// `tan()`: the tangent of an angle or a number of radians
+    Tan,
+    /// `asin()`: the angle whose sine is its argument
+    Asin,
+    /// `acos()`: the angle whose cosine is its argument
+    Acos,
+    /// `atan()`: the angle whose tangent is its argument
+    Atan,
+    /// `atan2()`: the angle of the point whose coordinates are its arguments (y, x)
+    Atan2,
+    /// `pow()`: its first argument raised to the power of the second one
+    Pow,
+    /// `sqrt()`: the square root of its argument
+    Sqrt,
+    /// `hypot()`: the square root of the sum of the squares of its arguments
+    Hypot,
+    /// `log()`: the logarithm of its first argument, in base e or in the base of the
+    /// second argument
+    Log,
+    /// `exp()`: e raised to the power of its argument
+    Exp,
+}
+
+/// How `round()` rounds a value to a multiple of its step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RoundingStrategy {
+    /// The nearest multiple, or the upper one if both are as near (`nearest`, the default)
+    Nearest,
+    /// The upper multiple (`up`)
+    Up,
+    /// The lower multiple (`down`)
+    Down,
+    /// The multiple nearest to zero (`to-zero`)
+    ToZero,
+}
+
+impl fmt::Display for RoundingStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundingStrategy::Nearest => write!(f, "nearest"),
+            RoundingStrategy::Up => write!(f, "up"),
+            RoundingStrategy::Down => write!(f, "down"),
+            RoundingStrategy::ToZero => write!(f, "to-zero"),
+        }
+    }
+}
+
+impl FromStr for RoundingStrategy {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "nearest" => Ok(RoundingStrategy::Nearest),
+            "up" => Ok(RoundingStrategy::Up),
+            "down" => Ok(RoundingStrategy::Down),
+            "to-zero" => Ok(RoundingStrategy::ToZero),
+            _ => Err(ParseValueError::new("RoundingStrategy", s)),
+        }
+    }
+}
+
+/// The type a `Calc` expression resolves to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Kind {
+    Number,
+    Length,
+    Angle,
+}
+
+impl MathFunction {
+    /// Returns the function with the given name (case-insensitive).
+    fn from_name(name: &str) -> Option<Self> {
+        let function = match name.to_ascii_lowercase().as_str() {
+            "min" => MathFunction::Min,
+            "max" => MathFunction::Max,
+            "clamp" => MathFunction::Clamp,
+            "round" => MathFunction::Round(RoundingStrategy::Nearest),
+            "mod" => MathFunction::Mod,
+            "rem" => MathFunction::Rem,
+            "abs" => MathFunction::Abs,
+            "sign" => MathFunction::Sign,
+            "sin" => MathFunction::Sin,
+            "cos" => MathFunction::Cos,
+            "tan" => MathFunction::Tan,
+            "asin" => MathFunction::Asin,
+            "acos" => MathFunction::Acos,
+            "atan" => MathFunction::Atan,
+            "atan2" => MathFunction::Atan2,
+            "pow" => MathFunction::Pow,
+            "sqrt" => MathFunction::Sqrt,
+            "hypot" => MathFunction::Hypot,
+            "log" => MathFunction::Log,
+            "exp" => MathFunction::Exp,
+            _ => return None,
+        };
+        Some(function)
+    }
+
+    /// Returns `true` if the function takes arguments of the same type and resolves to
+    /// that type, such as `min()` or `abs()`.
+    fn keeps_type(self) -> bool {
+        matches!(
+            self,
+            MathFunction::Min
+                | MathFunction::Max
+                | MathFunction::Clamp
+                | MathFunction::Round(_)
+                | MathFunction::Mod
+                | MathFunction::Rem
+                | MathFunction::Abs
+                | MathFunction::Hypot
+        )
+    }
+
+    /// Returns the type the function resolves to, given the type of its arguments.
+    fn kind(self, args: Option<Kind>) -> Option<Kind> {
+        match self {
+            function if function.keeps_type() => args,
+            MathFunction::Asin | MathFunction::Acos | MathFunction::Atan | MathFunction::Atan2 => Some(Kind::Angle),
+            _ => Some(Kind::Number),
+        }
+    }
+
+    /// Returns `true` if the function accepts the given number of arguments of the given
+    /// types (`None` for types that depend on CSS variables).
+    fn accepts(self, args: &[Option<Kind>]) -> bool {
+        let count = args.len();
+        let arity = match self {
+            MathFunction::Min | MathFunction::Max | MathFunction::Hypot => count >= 1,
+            MathFunction::Clamp => count == 3,
+            MathFunction::Round(_) | MathFunction::Log => (1..=2).contains(&count),
+            MathFunction::Mod | MathFunction::Rem | MathFunction::Atan2 | MathFunction::Pow => count == 2,
+            _ => count == 1,
+        };
+
+        let known: Vec<Kind> = args.iter().flatten().copied().collect();
+        let same_kind = known.windows(2).all(|pair| pair[0] == pair[1]);
+        let all = |kinds: &[Kind]| known.iter().all(|kind| kinds.contains(kind));
+
+        arity
+            && same_kind
+            && match self {
+                // A step can only be omitted when rounding a number
+                MathFunction::Round(_) if count == 1 => all(&[Kind::Number]),
+                MathFunction::Sin | MathFunction::Cos | MathFunction::Tan => all(&[Kind::Number, Kind::Angle]),
+                MathFunction::Asin
+                | MathFunction::Acos
+                | MathFunction::Atan
+                | MathFunction::Pow
+                | MathFunction::Sqrt
+                | MathFunction::Log
+                | MathFunction::Exp => all(&[Kind::Number]),
+                _ => true,
+            }
+    }
+
+    /// Computes the function over numbers, with angles in radians.
+    pub(crate) fn apply(self, args: &[f64]) -> Option<f64> {
+        let result = match (self, args) {
+            (MathFunction::Min, [first, rest @ ..]) => rest.iter().fold(*first, |a, b| a.min(*b)),
+            (MathFunction::Max, [first, rest @ ..]) => rest.iter().fold(*first, |a, b| a.max(*b)),
+            // The minimum wins over the maximum, as in CSS
+            (MathFunction::Clamp, [min, val, max]) => val.min(*max).max(*min),
+            (MathFunction::Round(strategy), [val]) => MathFunction::Round(strategy).apply(&[*val, 1.0])?,
+            (MathFunction::Round(strategy), [val, step]) => {
+                let steps = val / step;
+                let steps = match strategy {
+                    RoundingStrategy::Nearest => (steps + 0.5).floor(),
+                    RoundingStrategy::Up => steps.ceil(),
+                    RoundingStrategy::Down => steps.floor(),
+                    RoundingStrategy::ToZero => steps.trunc(),
+                };
+                steps * step
+            }
+            (MathFunction::Mod, [a, b]) => a - b * (a / b).floor(),
+            (MathFunction::Rem, [a, b]) => a % b,
+            (MathFunction::Abs, [val]) => val.abs(),
+            (MathFunction::Sign, [val]) => {
+                if *val == 0.0 {
+                    0.0
+                } else {
+                    val.signum()
+                }
+            }
+            (MathFunction::Sin, [val]) => val.sin(),
+            (MathFunction::Cos, [val]) => val.cos(),
+            (MathFunction::Tan, [val]) => val.tan(),
+            (MathFunction::Asin, [val]) => val.asin(),
+            (MathFunction::Acos, [val]) => val.acos(),
+            (MathFunction::Atan, [val]) => val.atan(),
+            (MathFunction::Atan2, [y, x]) => y.atan2(*x),
+            (MathFunction::Pow, [base, exponent]) => base.powf(*exponent),
+            (MathFunction::Sqrt, [val]) => val.sqrt(),
+            (MathFunction::Hypot, values) if !values.is_empty() => values.iter().map(|val| val * val).sum::<f64>().sqrt(),
+            (MathFunction::Log, [val]) => val.ln(),
+            (MathFunction::Log, [val, base]) => val.ln() / base.ln(),
+            (MathFunction::Exp, [val]) => val.exp(),
+            _ => return None,
+        };
+        // Infinite and NaN results are left to the browser
+        result.is_finite().then_some(result)
+    }
+}
+
+impl fmt::Display for MathFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathFunction::Min => write!(f, "min"),
+            MathFunction::Max => write!(f, "max"),
+            MathFunction::Clamp => write!(f, "clamp"),
+            MathFunction::Round(_) => write!(f, "round"),
+            MathFunction::Mod => write!(f, "mod"),
+            MathFunction::Rem => write!(f, "rem"),
+            MathFunction::Abs => write!(f, "abs"),
+            MathFunction::Sign => write!(f, "sign"),
+            MathFunction::Sin => write!(f, "sin"),
+            MathFunction::Cos => write!(f, "cos"),
+            MathFunction::Tan => write!(f, "tan"),
+            MathFunction::Asin => write!(f, "asin"),
+            MathFunction::Acos => write!(f, "acos"),
+            MathFunction::Atan => write!(f, "atan"),
+            MathFunction::Atan2 => write!(f, "atan2"),
+            MathFunction::Pow => write!(f, "pow"),
+            MathFunction::Sqrt => write!(f, "sqrt"),
+            MathFunction::Hypot => write!(f, "hypot"),
+            MathFunction::Log => write!(f, "log"),
+            MathFunction::Exp => write!(f, "exp"),
+        }
+    }
+}
+
+impl Calc {
+    /// Returns the type the expression resolves to, or `None` if this depends on CSS
+    /// variables.
+    pub(crate) fn kind(&self) -> Option<Kind> {
+        match self {
+            Calc::Number(_) => Some(Kind::Number),
+            Calc::Length(_) => Some(Kind::Length),
+            Calc::Angle(_) => Some(Kind::Angle),
+            Calc::Var(_) => None,
+            Calc::Sum(terms) => terms.iter().find_map(Calc::kind),
+            Calc::Negate(term) => term.kind(),
+            // A product is of the type of its operand that is not a number
+            Calc::Product(a, b) => match (a.kind(), b.kind()) {
+                (Some(Kind::Number), kind) | (kind, Some(Kind::Number)) => kind,
+                (Some(kind), _) | (_, Some(kind)) => Some(kind),
+                _ => None,
+            },
+            Calc::Quotient(a, _) => a.kind(),
+            Calc::Function(function, args) => function.kind(args.iter().find_map(Calc::kind)),
+        }
+    }
+
+    /// Creates a `min()` function, which is resolved to the smallest of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values to compare, such as sizes or other expressions
+    ///
+    /// # Returns
+    ///
+    /// A new `Calc` expression, computed in Rust if the values are numbers or lengths
+    /// of the same unit, or a `MewError` if the values are of different types
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Size;
+    ///
+    /// let width = Calc::min([Size::Percent(100.0), Size::Px(640.0) + Size::Rem(2.0)]).unwrap();
+    /// assert_eq!(width.to_string(), "min(100%, 640px + 2rem)");
+    /// assert_eq!(Size::from(Calc::min([Size::Px(4.0), Size::Px(2.0)]).unwrap()), Size::Px(2.0));
+    /// ```
+    pub fn min<T: Into<Calc>>(values: impl IntoIterator<Item = T>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Min, values.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a `max()` function, which is resolved to the largest of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values to compare, such as sizes or other expressions
+    ///
+    /// # Returns
+    ///
+    /// A new `Calc` expression, computed in Rust if the values are numbers or lengths
+    /// of the same unit, or a `MewError` if the values are of different types
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Size;
+    /// use mew_css::var;
+    ///
+    /// let height = Calc::max([Size::Px(44.0), Size::Var(var("touch-target"))]).unwrap();
+    /// assert_eq!(height.to_string(), "max(44px, var(--touch-target))");
+    /// ```
+    pub fn max<T: Into<Calc>>(values: impl IntoIterator<Item = T>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Max, values.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a `clamp()` function, which is resolved to the preferred value, unless it
+    /// is smaller than the minimum or larger than the maximum.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The minimum value
+    /// * `preferred` - The preferred value
+    /// * `max` - The maximum value
+    ///
+    /// # Returns
+    ///
+    /// A new `Calc` expression, computed in Rust if the values are numbers or lengths
+    /// of the same unit, or a `MewError` if the values are of different types
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::{LineHeight, Size};
+    ///
+    /// let padding = Calc::clamp(Size::Rem(1.0), Size::Vw(5.0), Size::Rem(3.0)).unwrap();
+    /// assert_eq!(padding.to_string(), "clamp(1rem, 5vw, 3rem)");
+    ///
+    /// let line_height = Calc::clamp(Calc::Number(1.2), Calc::Number(2.0), Calc::Number(1.6)).unwrap();
+    /// let line_height = LineHeight::from(line_height);
+    /// assert_eq!(line_height.to_string(), "calc(1.6)");
+    /// ```
+    pub fn clamp(min: impl Into<Calc>, preferred: impl Into<Calc>, max: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Clamp, vec![min.into(), preferred.into(), max.into()])
+    }
+
+    /// Creates a fluid size, which grows linearly with the viewport width from a
+    /// minimum size at a small viewport to a maximum size at a large viewport, and
+    /// stays within these sizes outside of this range.
+    ///
+    /// The result is a `clamp(min, preferred, max)` function, where the preferred size
+    /// is written in `vw` and `rem` (with a root font size of 16px), so that the size
+    /// still follows the user's font size settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_px` - The size in pixels at the small viewport
+    /// * `max_px` - The size in pixels at the large viewport
+    /// * `min_viewport_px` - The width in pixels of the small viewport
+    /// * `max_viewport_px` - The width in pixels of the large viewport
+    ///
+    /// # Returns
+    ///
+    /// A new `Calc` expression
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::FontSize;
+    ///
+    /// // 16px at a 320px wide viewport to 24px at a 1280px wide viewport
+    /// let font_size = FontSize::from(Calc::fluid(16.0, 24.0, 320.0, 1280.0));
+    /// assert_eq!(font_size.to_string(), "clamp(1rem, 0.8333vw + 0.8333rem, 1.5rem)");
+    /// ```
+    pub fn fluid(min_px: f32, max_px: f32, min_viewport_px: f32, max_viewport_px: f32) -> Calc {
+        const ROOT_FONT_SIZE: f32 = 16.0;
+        let rem = |px: f32| Calc::Length(Size::Rem(px / ROOT_FONT_SIZE));
+
+        if min_viewport_px == max_viewport_px {
+            return rem(min_px);
+        }
+
+        // The size grows by `slope` pixels per pixel of viewport width
+        let slope = (max_px - min_px) / (max_viewport_px - min_viewport_px);
+        let intercept = min_px - slope * min_viewport_px;
+        let preferred = Calc::Length(Size::Vw(slope * 100.0)) + rem(intercept);
+
+        // The sizes are swapped for a size that shrinks as the viewport grows. The
+        // arguments are all lengths, which `clamp()` accepts.
+        let args = vec![rem(min_px.min(max_px)), preferred, rem(min_px.max(max_px))];
+        Calc::evaluate(MathFunction::Clamp, &args).unwrap_or(Calc::Function(MathFunction::Clamp, args))
+    }
+
+    /// Creates a `round()` function, which rounds a value to a multiple of a step.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - How the value is rounded
+    /// * `value` - The value to round
+    /// * `step` - The step the value is rounded to a multiple of
+    ///
+    /// # Returns
+    ///
+    /// A new `Calc` expression, computed in Rust if the values are numbers or lengths
+    /// of the same unit, or a `MewError` if the values are of different types
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::{Calc, RoundingStrategy};
+    /// use mew_css::values::Size;
+    /// use mew_css::var;
+    ///
+    /// let width = Calc::round(RoundingStrategy::Down, Size::Var(var("width")), Size::Px(8.0)).unwrap();
+    /// assert_eq!(width.to_string(), "round(down, var(--width), 8px)");
+    /// let rounded = Calc::round(RoundingStrategy::Nearest, Size::Px(13.0), Size::Px(8.0));
+    /// assert_eq!(rounded, Ok(Calc::Length(Size::Px(16.0))));
+    /// ```
+    pub fn round(strategy: RoundingStrategy, value: impl Into<Calc>, step: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Round(strategy), vec![value.into(), step.into()])
+    }
+
+    /// Creates a `mod()` function: the modulus of `value` by `divisor`, which has the
+    /// sign of the divisor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Angle;
+    ///
+    /// assert_eq!(Calc::modulo(Angle::Deg(-90.0), Angle::Deg(360.0)), Ok(Calc::Angle(Angle::Deg(270.0))));
+    /// ```
+    pub fn modulo(value: impl Into<Calc>, divisor: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Mod, vec![value.into(), divisor.into()])
+    }
+
+    /// Creates a `rem()` function: the remainder of `value` divided by `divisor`, which
+    /// has the sign of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Size;
+    ///
+    /// assert_eq!(Calc::remainder(Size::Px(-10.0), Size::Px(3.0)), Ok(Calc::Length(Size::Px(-1.0))));
+    /// ```
+    pub fn remainder(value: impl Into<Calc>, divisor: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Rem, vec![value.into(), divisor.into()])
+    }
+
+    /// Creates an `abs()` function: the absolute value of `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::var;
+    ///
+    /// assert_eq!(Calc::abs(var("offset")).unwrap().to_string(), "abs(var(--offset))");
+    /// ```
+    pub fn abs(value: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Abs, vec![value.into()])
+    }
+
+    /// Creates a `sign()` function: -1, 0 or 1 depending on the sign of `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Size;
+    ///
+    /// assert_eq!(Calc::sign(Size::Rem(-2.0)), Ok(Calc::Number(-1.0)));
+    /// ```
+    pub fn sign(value: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Sign, vec![value.into()])
+    }
+
+    /// Creates a `sin()` function: the sine of an angle (or of a number of radians).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Angle;
+    ///
+    /// assert_eq!(Calc::sin(Angle::Deg(90.0)), Ok(Calc::Number(1.0)));
+    /// ```
+    pub fn sin(angle: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Sin, vec![angle.into()])
+    }
+
+    /// Creates a `cos()` function: the cosine of an angle (or of a number of radians).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Angle;
+    ///
+    /// assert_eq!(Calc::cos(Angle::Turn(0.5)), Ok(Calc::Number(-1.0)));
+    /// ```
+    pub fn cos(angle: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Cos, vec![angle.into()])
+    }
+
+    /// Creates a `tan()` function: the tangent of an angle (or of a number of radians).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::var;
+    ///
+    /// assert_eq!(Calc::tan(var("angle")).unwrap().to_string(), "tan(var(--angle))");
+    /// ```
+    pub fn tan(angle: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Tan, vec![angle.into()])
+    }
+
+    /// Creates an `asin()` function: the angle whose sine is `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Angle;
+    ///
+    /// assert_eq!(Calc::asin(1.0), Ok(Calc::Angle(Angle::Deg(90.0))));
+    /// ```
+    pub fn asin(value: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Asin, vec![value.into()])
+    }
+
+    /// Creates an `acos()` function: the angle whose cosine is `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Angle;
+    ///
+    /// assert_eq!(Calc::acos(-1.0), Ok(Calc::Angle(Angle::Deg(180.0))));
+    /// ```
+    pub fn acos(value: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Acos, vec![value.into()])
+    }
+
+    /// Creates an `atan()` function: the angle whose tangent is `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Angle;
+    ///
+    /// assert_eq!(Calc::atan(1.0), Ok(Calc::Angle(Angle::Deg(45.0))));
+    /// ```
+    pub fn atan(value: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Atan, vec![value.into()])
+    }
+
+    /// Creates an `atan2()` function: the angle of the point at coordinates (`x`, `y`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::{Angle, Size};
+    ///
+    /// assert_eq!(Calc::atan2(Size::Px(10.0), Size::Px(-10.0)), Ok(Calc::Angle(Angle::Deg(135.0))));
+    /// ```
+    pub fn atan2(y: impl Into<Calc>, x: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Atan2, vec![y.into(), x.into()])
+    }
+
+    /// Creates a `pow()` function: `base` raised to the power of `exponent`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Size;
+    /// use mew_css::var;
+    ///
+    /// // A modular scale
+    /// let size = Size::from(Calc::from(Size::Rem(1.0)) * Calc::pow(1.25, var("step")).unwrap());
+    /// assert_eq!(size.to_string(), "calc(1rem * pow(1.25, var(--step)))");
+    /// ```
+    pub fn pow(base: impl Into<Calc>, exponent: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Pow, vec![base.into(), exponent.into()])
+    }
+
+    /// Creates a `sqrt()` function: the square root of `value`, which must be a number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Size;
+    ///
+    /// assert_eq!(Calc::sqrt(2.25), Ok(Calc::Number(1.5)));
+    /// assert!(Calc::sqrt(Size::Px(4.0)).is_err());
+    /// ```
+    pub fn sqrt(value: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Sqrt, vec![value.into()])
+    }
+
+    /// Creates a `hypot()` function: the square root of the sum of the squares of the
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Size;
+    ///
+    /// assert_eq!(Calc::hypot([Size::Px(3.0), Size::Px(4.0)]), Ok(Calc::Length(Size::Px(5.0))));
+    /// ```
+    pub fn hypot<T: Into<Calc>>(values: impl IntoIterator<Item = T>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Hypot, values.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a `log()` function: the natural logarithm of `value`, or its logarithm
+    /// in the given base.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    ///
+    /// assert_eq!(Calc::log(8.0, Some(2.0.into())), Ok(Calc::Number(3.0)));
+    /// assert_eq!(Calc::log(mew_css::var("n"), None).unwrap().to_string(), "log(var(--n))");
+    /// ```
+    pub fn log(value: impl Into<Calc>, base: Option<Calc>) -> Result<Calc, MewError> {
+        let args = std::iter::once(value.into()).chain(base).collect();
+        Calc::function(MathFunction::Log, args)
+    }
+
+    /// Creates an `exp()` function: e raised to the power of `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    ///
+    /// assert_eq!(Calc::exp(0.0), Ok(Calc::Number(1.0)));
+    /// ```
+    pub fn exp(value: impl Into<Calc>) -> Result<Calc, MewError> {
+        Calc::function(MathFunction::Exp, vec![value.into()])
+    }
+
+    /// Creates a math function, computing it if its arguments are numbers, or lengths
+    /// or angles of the same unit.
+    ///
+    /// Returns an error if the function does not accept the number or the types of the
+    /// arguments, such as `sqrt(4px)` or `min(1px, 90deg)`.
+    fn function(function: MathFunction, args: Vec<Calc>) -> Result<Calc, MewError> {
+        let kinds: Vec<Option<Kind>> = args.iter().map(Calc::kind).collect();
+        if !function.accepts(&kinds) {
+            return Err(ParseValueError::new("Calc", &Calc::Function(function, args).to_string()).into());
+        }
+
+        Ok(match Calc::evaluate(function, &args) {
+            Some(result) => result,
+            None => Calc::Function(function, args),
+        })
+    }
+
+    /// Computes a math function whose arguments are numbers, or lengths or angles of the
+    /// same unit.
+    fn evaluate(function: MathFunction, args: &[Calc]) -> Option<Calc> {
+        let constants: Vec<(f32, Kind, &str)> = args
+            .iter()
+            .map(|arg| match arg {
+                Calc::Number(n) => Some((*n, Kind::Number, "")),
+                Calc::Length(size) => size.length().map(|(val, unit)| (val, Kind::Length, unit)),
+                Calc::Angle(angle) => angle.value().map(|(val, unit)| (val, Kind::Angle, unit)),
+                _ => None,
+            })
+            .collect::<Option<_>>()?;
+
+        let &(_, kind, unit) = constants.first()?;
+        if constants.iter().any(|&(_, other_kind, other_unit)| (other_kind, other_unit) != (kind, unit)) {
+            return None;
+        }
+        if !function.accepts(&vec![Some(kind); constants.len()]) {
+            return None;
+        }
+
+        // Trigonometric functions work in radians
+        let values: Vec<f64> = match (function, &args[0]) {
+            (MathFunction::Sin | MathFunction::Cos | MathFunction::Tan, Calc::Angle(angle)) => vec![angle.radians()?],
+            _ => constants.iter().map(|(val, ..)| *val as f64).collect(),
+        };
+        let result = function.apply(&values)?;
+
+        match function.kind(Some(kind))? {
+            Kind::Number => Some(Calc::Number(result as f32)),
+            Kind::Length => Size::with_unit(result as f32, unit).map(Calc::Length),
+            Kind::Angle if function.keeps_type() => Angle::with_unit(result as f32, unit).map(Calc::Angle),
+            Kind::Angle => Some(Calc::Angle(Angle::Deg(result.to_degrees() as f32))),
+        }
+    }
+
+    /// Returns `true` if the expression resolves to a length, or may resolve to one
+    /// depending on CSS variables.
+    pub(crate) fn is_length(&self) -> bool {
+        matches!(self.kind(), Some(Kind::Length) | None)
+    }
+
+    /// Returns `true` if the term is a zero number or length.
+    fn is_zero(&self) -> bool {
+        match self {
+            Calc::Number(n) => *n == 0.0,
+            Calc::Length(size) => size.length().is_some_and(|(val, _)| val == 0.0),
+            Calc::Angle(angle) => angle.value().is_some_and(|(val, _)| val == 0.0),
+            _ => false,
+        }
+    }
+
+    /// Returns the terms of the expression, as a sum.
+    fn into_terms(self) -> Vec<Calc> {
+        match self {
+            Calc::Sum(terms) => terms,
+            term => vec![term],
+        }
+    }
+
+    /// Adds up terms, combining the numbers and the lengths of the same unit.
+    fn sum(terms: impl IntoIterator<Item = Calc>) -> Calc {
+        let mut combined: Vec<Calc> = Vec::new();
+        for term in terms.into_iter().flat_map(Calc::into_terms) {
+            match combined.iter_mut().find_map(|existing| existing.combine(&term).map(|sum| (existing, sum))) {
+                Some((existing, sum)) => *existing = sum,
+                None => combined.push(term),
+            }
+        }
+
+        // Zero terms do not change the sum, unless nothing else is left
+        if combined.iter().any(|term| !term.is_zero()) {
+            combined.retain(|term| !term.is_zero());
+        } else {
+            combined.truncate(1);
+        }
+
+        match combined.len() {
+            1 => combined.remove(0),
+            _ => Calc::Sum(combined),
+        }
+    }
+
+    /// Returns the sum of two numbers or of two lengths of the same unit.
+    fn combine(&self, other: &Calc) -> Option<Calc> {
+        match (self, other) {
+            (Calc::Number(a), Calc::Number(b)) => Some(Calc::Number(a + b)),
+            (Calc::Length(a), Calc::Length(b)) => {
+                let (b, unit) = b.length()?;
+                let (_, own_unit) = a.length()?;
+                (unit == own_unit).then(|| a.map_length(|a| a + b).map(Calc::Length))?
+            }
+            (Calc::Angle(a), Calc::Angle(b)) => {
+                let (b, unit) = b.value()?;
+                let (_, own_unit) = a.value()?;
+                (unit == own_unit).then(|| a.map(|a| a + b).map(Calc::Angle))?
+            }
+            _ => None,
+        }
+    }
+
+    /// Multiplies the expression by a number, folding it into a number or a length.
+    fn scale(self, factor: f32) -> Calc {
+        match self {
+            term if factor == 1.0 => term,
+            Calc::Number(n) => Calc::Number(n * factor),
+            Calc::Length(size) => match size.map_length(|val| val * factor) {
+                Some(size) => Calc::Length(size),
+                None => Calc::Product(Box::new(Calc::Length(size)), Box::new(Calc::Number(factor))),
+            },
+            Calc::Angle(angle) => match angle.map(|val| val * factor) {
+                Some(angle) => Calc::Angle(angle),
+                None => Calc::Product(Box::new(Calc::Angle(angle)), Box::new(Calc::Number(factor))),
+            },
+            Calc::Product(term, n) => match *n {
+                Calc::Number(n) if n * factor == 1.0 => *term,
+                Calc::Number(n) => Calc::Product(term, Box::new(Calc::Number(n * factor))),
+                n => Calc::Product(Box::new(Calc::Product(term, Box::new(n))), Box::new(Calc::Number(factor))),
+            },
+            term => Calc::Product(Box::new(term), Box::new(Calc::Number(factor))),
+        }
+    }
+
+    /// Returns the positive form of a negative term, to write it after a minus sign.
+    fn negative(&self) -> Option<Calc> {
+        match self {
+            Calc::Number(n) if *n < 0.0 => Some(Calc::Number(-n)),
+            Calc::Length(size) => match size.length() {
+                Some((val, _)) if val < 0.0 => size.map_length(|val| -val).map(Calc::Length),
+                _ => None,
+            },
+            Calc::Angle(angle) => match angle.value() {
+                Some((val, _)) if val < 0.0 => angle.map(|val| -val).map(Calc::Angle),
+                _ => None,
+            },
+            Calc::Negate(term) => Some(*term.clone()),
+            _ => None,
+        }
+    }
+
+    /// Writes the expression without the `calc()` function around it.
+    fn write_expression(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Calc::Number(n) => write!(f, "{}", Number::new(*n)),
+            Calc::Length(size) => write!(f, "{}", size),
+            Calc::Angle(angle) => write!(f, "{}", angle),
+            Calc::Var(var) => write!(f, "{}", var),
+            Calc::Sum(terms) => {
+                for (i, term) in terms.iter().enumerate() {
+                    // `+` and `-` must be surrounded by whitespace
+                    match term.negative() {
+                        _ if i == 0 => term.write_expression(f)?,
+                        Some(term) => {
+                            write!(f, " - ")?;
+                            term.write_expression(f)?;
+                        }
+                        None => {
+                            write!(f, " + ")?;
+                            term.write_expression(f)?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Calc::Negate(term) => {
+                write!(f, "-1 * ")?;
+                term.write_operand(f, false)
+            }
+            Calc::Product(a, b) => {
+                a.write_operand(f, false)?;
+                write!(f, " * ")?;
+                b.write_operand(f, false)
+            }
+            Calc::Quotient(a, b) => {
+                a.write_operand(f, false)?;
+                write!(f, " / ")?;
+                b.write_operand(f, true)
+            }
+            Calc::Function(function, args) => {
+                write!(f, "{}(", function)?;
+                if let MathFunction::Round(strategy) = function
+                    && *strategy != RoundingStrategy::Nearest
+                {
+                    write!(f, "{}, ", strategy)?;
+                }
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    arg.write_expression(f)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+
+    /// Writes an operand of a product or a quotient, in parentheses if it is a sum (or,
+    /// for a divisor, any other operation).
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>, divisor: bool) -> fmt::Result {
+        let parenthesize = match self {
+            Calc::Sum(_) => true,
+            Calc::Negate(_) | Calc::Product(..) | Calc::Quotient(..) => divisor,
+            _ => false,
+        };
+
+        if parenthesize {
+            write!(f, "(")?;
+            self.write_expression(f)?;
+            write!(f, ")")
+        } else {
+            self.write_expression(f)
+        }
+    }
+}
+
+impl fmt::Display for Calc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Math functions can be used as values on their own
+        if let Calc::Function(..) = self {
+            return self.write_expression(f);
+        }
+
+        write!(f, "calc(")?;
+        self.write_expression(f)?;
+        write!(f, ")")
+    }
+}
+
+impl Calc {
+    /// Converts a size to a term of an expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size to convert
+    ///
+    /// # Returns
+    ///
+    /// The term, or a `MewError` for `Size::Auto`, which has no length to compute with
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Size;
+    ///
+    /// assert_eq!(Calc::try_size(Size::Rem(2.0)), Ok(Calc::Length(Size::Rem(2.0))));
+    /// assert!(Calc::try_size(Size::Auto).is_err());
+    /// ```
+    pub fn try_size(size: Size) -> Result<Calc, MewError> {
+        match size {
+            // A unitless zero is a number in `calc()`
+            Size::Zero => Ok(Calc::Length(Size::Px(0.0))),
+            Size::Var(var) => Ok(Calc::Var(var)),
+            Size::Calc(calc) => Ok(*calc),
+            Size::Auto => Err(auto_error()),
+            size => Ok(Calc::Length(size)),
+        }
+    }
+
+    /// Checks that the expression has no `Size::Auto` term, which browsers would reject.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the expression is valid, or a `MewError` if it contains `auto`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::calc::Calc;
+    /// use mew_css::values::Size;
+    ///
+    /// assert!((Calc::from(Size::Rem(1.0)) + Calc::from(Size::Px(2.0))).validate().is_ok());
+    /// assert!((Calc::from(Size::Auto) + Calc::from(Size::Px(2.0))).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), MewError> {
+        match self {
+            Calc::Length(Size::Auto) => Err(auto_error()),
+            Calc::Sum(terms) | Calc::Function(_, terms) => terms.iter().try_for_each(Calc::validate),
+            Calc::Negate(term) => term.validate(),
+            Calc::Product(a, b) | Calc::Quotient(a, b) => a.validate().and_then(|_| b.validate()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The error for a `Size::Auto` term, which has no length to compute with.
+fn auto_error() -> MewError {
+    ParseValueError::new("Calc", "auto").into()
+}
+
+// Implement From<Size> for Calc to use a Size as a term of an expression. `Size::Auto`
+// is kept as an invalid term, reported by `Calc::validate` (see also `Calc::try_size`).
+impl From<Size> for Calc {
+    fn from(size: Size) -> Self {
+        Calc::try_size(size).unwrap_or(Calc::Length(Size::Auto))
+    }
+}
+
+// Implement From<f32> for Calc to use a number as a term of an expression
+impl From<f32> for Calc {
+    fn from(n: f32) -> Self {
+        Calc::Number(n)
+    }
+}
+
+// Implement From<Angle> for Calc to use an Angle as a term of an expression
+impl From<Angle> for Calc {
+    fn from(angle: Angle) -> Self {
+        match angle {
+            Angle::Var(var) => Calc::Var(var),
+            angle => Calc::Angle(angle),
+        }
+    }
+}
+
+// Implement From<CssVar> for Calc to use a CSS variable as a term of an expression
+impl From<CssVar> for Calc {
+    fn from(var: CssVar) -> Self {
+        Calc::Var(var)
+    }
+}
+
+// Implement From<Calc> for Size to write an expression as a Size, without `calc()` if
+// it was simplified to a single length
+impl From<Calc> for Size {
+    fn from(calc: Calc) -> Self {
+        match calc {
+            Calc::Length(size) => size,
+            Calc::Var(var) => Size::Var(var),
+            calc => Size::Calc(Box::new(calc)),
+        }
+    }
+}
+
+impl Add for Calc {
+    type Output = Calc;
+
+    fn add(self, rhs: Calc) -> Calc {
+        Calc::sum([self, rhs])
+    }
+}
+
+impl Sub for Calc {
+    type Output = Calc;
+
+    fn sub(self, rhs: Calc) -> Calc {
+        Calc::sum([self, -rhs])
+    }
+}
+
+impl Neg for Calc {
+    type Output = Calc;
+
+    fn neg(self) -> Calc {
+        match self {
+            Calc::Number(n) => Calc::Number(-n),
+            Calc::Length(size) => match size.map_length(|val| -val) {
+                Some(size) => Calc::Length(size),
+                None => Calc::Negate(Box::new(Calc::Length(size))),
+            },
+            Calc::Angle(angle) => match angle.map(|val| -val) {
+                Some(angle) => Calc::Angle(angle),
+                None => Calc::Negate(Box::new(Calc::Angle(angle))),
+            },
+            Calc::Sum(terms) => Calc::Sum(terms.into_iter().map(Neg::neg).collect()),
+            Calc::Negate(term) => *term,
+            term => Calc::Negate(Box::new(term)),
+        }
+    }
+}
+
+impl Mul for Calc {
+    type Output = Calc;
+
+    fn mul(self, rhs: Calc) -> Calc {
+        match (self, rhs) {
+            (Calc::Number(a), Calc::Number(b)) => Calc::Number(a * b),
+            (Calc::Number(n), term) | (term, Calc::Number(n)) => term.scale(n),
+            (a, b) => Calc::Product(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Div for Calc {
+    type Output = Calc;
+
+    fn div(self, rhs: Calc) -> Calc {
+        match (self, rhs) {
+            // A division by zero is left to the browser
+            (Calc::Number(a), Calc::Number(b)) if b != 0.0 => Calc::Number(a / b),
+            (Calc::Length(size), Calc::Number(n)) if n != 0.0 && size.length().is_some() => {
+                Calc::Length(size.map_length(|val| val / n).unwrap_or(size))
+            }
+            (Calc::Angle(angle), Calc::Number(n)) if n != 0.0 && angle.value().is_some() => {
+                Calc::Angle(angle.map(|val| val / n).unwrap_or(angle))
+            }
+            (term, Calc::Number(1.0)) => term,
+            (a, b) => Calc::Quotient(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+/// Combines two sizes with an operator, or returns an error if either size is `Auto`,
+/// as it has no length to compute with.
+fn combine_sizes(lhs: Size, rhs: Size, op: impl FnOnce(Calc, Calc) -> Calc) -> Result<Size, MewError> {
+    Ok(Size::from(op(Calc::try_size(lhs)?, Calc::try_size(rhs)?)))
+}
+
+// The operators keep `Size::Auto` as an invalid term, like `Calc::from`; these methods
+// report it instead.
+impl Size {
+    /// Checks that a `calc()` size has no `Size::Auto` term (see `Calc::validate`).
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the size is valid, or a `MewError` if its expression contains `auto`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Size;
+    ///
+    /// assert!(Size::Auto.validate().is_ok());
+    /// assert!((Size::Percent(100.0) - Size::Rem(2.0)).validate().is_ok());
+    ///
+    /// let size = Size::Auto + Size::Px(5.0);
+    /// assert_eq!(size.to_string(), "calc(auto + 5px)");
+    /// assert!(size.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), MewError> {
+        match self {
+            Size::Calc(calc) => calc.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Adds two sizes, like the `+` operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The size to add
+    ///
+    /// # Returns
+    ///
+    /// The sum, or a `MewError` if either size is `Auto`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Size;
+    ///
+    /// assert_eq!(Size::Px(10.0).try_add(Size::Px(5.0)), Ok(Size::Px(15.0)));
+    /// assert!(Size::Auto.try_add(Size::Px(5.0)).is_err());
+    /// ```
+    pub fn try_add(self, rhs: Size) -> Result<Size, MewError> {
+        combine_sizes(self, rhs, Add::add)
+    }
+
+    /// Subtracts a size from this one, like the `-` operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The size to subtract
+    ///
+    /// # Returns
+    ///
+    /// The difference, or a `MewError` if either size is `Auto`
+    pub fn try_sub(self, rhs: Size) -> Result<Size, MewError> {
+        combine_sizes(self, rhs, Sub::sub)
+    }
+
+    /// Multiplies this size by a number, like the `*` operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The number to multiply by
+    ///
+    /// # Returns
+    ///
+    /// The product, or a `MewError` if the size is `Auto`
+    pub fn try_mul(self, factor: f32) -> Result<Size, MewError> {
+        combine_sizes(self, Size::Zero, |size, _| size * Calc::Number(factor))
+    }
+
+    /// Divides this size by a number, like the `/` operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `divisor` - The number to divide by
+    ///
+    /// # Returns
+    ///
+    /// The quotient, or a `MewError` if the size is `Auto`
+    pub fn try_div(self, divisor: f32) -> Result<Size, MewError> {
+        combine_sizes(self, Size::Zero, |size, _| size / Calc::Number(divisor))
+    }
+}
+
+impl Add for Size {
+    type Output = Size;
+
+    fn add(self, rhs: Size) -> Size {
+        Size::from(Calc::from(self) + Calc::from(rhs))
+    }
+}
+
+impl Sub for Size {
+    type Output = Size;
+
+    fn sub(self, rhs: Size) -> Size {
+        Size::from(Calc::from(self) - Calc::from(rhs))
+    }
+}
+
+impl Neg for Size {
+    type Output = Size;
+
+    fn neg(self) -> Size {
+        Size::from(-Calc::from(self))
+    }
+}
+
+impl Mul<f32> for Size {
+    type Output = Size;
+
+    fn mul(self, rhs: f32) -> Size {
+        Size::from(Calc::from(self) * Calc::Number(rhs))
+    }
+}
+
+impl Mul<Size> for f32 {
+    type Output = Size;
+
+    fn mul(self, rhs: Size) -> Size {
+        rhs * self
+    }
+}
+
+impl Div<f32> for Size {
+    type Output = Size;
+
+    fn div(self, rhs: f32) -> Size {
+        Size::from(Calc::from(self) / Calc::Number(rhs))
+    }
+}
+
+/// A token of a `calc()` expression.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// A number, a length, an angle or a CSS variable
+    Value(Calc),
+    /// The rounding strategy of a `round()` function, such as `up`
+    Strategy(RoundingStrategy),
+    /// An opening parenthesis, or a nested `calc(`
+    Open,
+    /// The name and opening parenthesis of a math function, such as `min(`
+    Function(MathFunction),
+    /// A comma between the arguments of a math function
+    Comma,
+    /// A closing parenthesis
+    Close,
+    /// An operator: `+`, `-`, `*` or `/`
+    Operator(char),
+}
+
+/// Splits a `calc()` expression into tokens.
+fn tokenize(s: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = s;
+
+    while let Some(c) = rest.chars().next() {
+        let spaced = c.is_whitespace();
+        rest = rest.trim_start();
+        let Some(c) = rest.chars().next() else { break };
+
+        let (token, len) = match c {
+            '(' => (Token::Open, 1),
+            ')' => (Token::Close, 1),
+            ',' => (Token::Comma, 1),
+            '*' | '/' => (Token::Operator(c), 1),
+            // `+` and `-` are operators only when followed by whitespace (after an operand,
+            // they must also follow whitespace), and signs of a number otherwise
+            '+' | '-' if rest[1..].starts_with(char::is_whitespace) => {
+                let after_operand = matches!(tokens.last(), Some(Token::Value(_) | Token::Close));
+                if after_operand && !spaced {
+                    return None;
+                }
+                (Token::Operator(c), 1)
+            }
+            c if c.is_ascii_digit() || matches!(c, '.' | '+' | '-') => {
+                let len = number_len(rest);
+                let unit_len = rest[len..]
+                    .find(|c: char| !(c.is_ascii_alphabetic() || c == '%'))
+                    .unwrap_or(rest.len() - len);
+                let (number, unit) = rest[..len + unit_len].split_at(len);
+                let value: f32 = number.parse().ok()?;
+                let value = match unit {
+                    "" => Calc::Number(value),
+                    unit => match Size::with_unit(value, unit) {
+                        Some(size) => Calc::Length(size),
+                        None => Calc::Angle(Angle::with_unit(value, unit)?),
+                    },
+                };
+                (Token::Value(value), len + unit_len)
+            }
+            c if c.is_ascii_alphabetic() => {
+                let name_len = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-')).unwrap_or(rest.len());
+                let name = &rest[..name_len];
+                if !rest[name_len..].starts_with('(') {
+                    (Token::Strategy(name.parse().ok()?), name_len)
+                } else if name.eq_ignore_ascii_case("calc") {
+                    (Token::Open, name_len + 1)
+                } else if name.eq_ignore_ascii_case("var") {
+                    let len = rest.find(')')? + 1;
+                    (Token::Value(Calc::Var(rest[..len].parse().ok()?)), len)
+                } else {
+                    (Token::Function(MathFunction::from_name(name)?), name_len + 1)
+                }
+            }
+            _ => return None,
+        };
+
+        tokens.push(token);
+        rest = &rest[len..];
+    }
+    Some(tokens)
+}
+
+/// Returns the length of the number at the start of the text, including its sign and
+/// exponent (but not the `e` of an `em` or `ex` unit).
+fn number_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    while len < bytes.len() && (bytes[len].is_ascii_digit() || bytes[len] == b'.') {
+        len += 1;
+    }
+
+    let exponent = &bytes[len..];
+    let sign = usize::from(matches!(exponent.get(1), Some(b'+' | b'-')));
+    if matches!(exponent.first(), Some(b'e' | b'E')) && exponent.get(1 + sign).is_some_and(u8::is_ascii_digit) {
+        len += 1 + sign;
+        while len < bytes.len() && bytes[len].is_ascii_digit() {
+            len += 1;
+        }
+    }
+    len
+}
+
+/// A recursive descent parser over the tokens of a `calc()` expression, which checks
+/// that values are only added to values of the same type, multiplied or divided by
+/// numbers, and passed to math functions that accept them.
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    /// Parses a sum: `product (('+' | '-') product)*`.
+    fn sum(&mut self) -> Option<Calc> {
+        let mut calc = self.product()?;
+        while let Some(&Token::Operator(op @ ('+' | '-'))) = self.peek() {
+            self.position += 1;
+            let term = self.product()?;
+            if let (Some(a), Some(b)) = (calc.kind(), term.kind())
+                && a != b
+            {
+                return None;
+            }
+            calc = if op == '+' { calc + term } else { calc - term };
+        }
+        Some(calc)
+    }
+
+    /// Parses a product: `value (('*' | '/') value)*`.
+    fn product(&mut self) -> Option<Calc> {
+        let mut calc = self.value()?;
+        while let Some(&Token::Operator(op @ ('*' | '/'))) = self.peek() {
+            self.position += 1;
+            let factor = self.value()?;
+            let is_dimension = |kind: Option<Kind>| kind.is_some_and(|kind| kind != Kind::Number);
+            calc = match (op, is_dimension(calc.kind()), is_dimension(factor.kind())) {
+                ('*', true, true) | ('/', _, true) => return None,
+                ('*', ..) => calc * factor,
+                _ => calc / factor,
+            };
+        }
+        Some(calc)
+    }
+
+    /// Parses a value: a number, a length, an angle, a CSS variable, a parenthesized sum
+    /// or a math function.
+    fn value(&mut self) -> Option<Calc> {
+        match self.next()? {
+            Token::Value(calc) => Some(calc),
+            Token::Open => {
+                let calc = self.sum()?;
+                (self.next()? == Token::Close).then_some(calc)
+            }
+            Token::Function(mut function) => {
+                if let (MathFunction::Round(_), Some(&Token::Strategy(strategy))) = (function, self.peek()) {
+                    function = MathFunction::Round(strategy);
+                    self.position += 1;
+                    if self.next()? != Token::Comma {
+                        return None;
+                    }
+                }
+
+                let mut args = vec![self.sum()?];
+                while self.next()? == Token::Comma {
+                    args.push(self.sum()?);
+                }
+                if self.tokens.get(self.position - 1) != Some(&Token::Close) {
+                    return None;
+                }
+
+                Calc::function(function, args).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Calc {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseValueError::new("Calc", s);
+
+        let tokens = tokenize(s.trim()).ok_or_else(error)?;
+        // The whole text must be a single `calc()` or math function (not a parenthesis)
+        let is_function = s.trim_start().starts_with(|c: char| c.is_ascii_alphabetic());
+        if !is_function || !matches!(tokens.first(), Some(Token::Open | Token::Function(_))) {
+            return Err(error());
+        }
+
+        let mut parser = Parser { tokens, position: 0 };
+        let calc = parser.value().ok_or_else(error)?;
+        if parser.position != parser.tokens.len() {
+            return Err(error());
+        }
+        Ok(calc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::{Angle, FontSize, LineHeight};
+    use crate::variable::var;
+
+    #[test]
+    fn test_simplification() {
+        assert_eq!(Size::Px(10.0) + Size::Px(5.5), Size::Px(15.5));
+        assert_eq!(Size::Rem(1.0) - Size::Rem(1.0), Size::Rem(0.0));
+        assert_eq!(Size::Zero + Size::Em(2.0), Size::Em(2.0));
+        assert_eq!(Size::Percent(50.0) * 2.0, Size::Percent(100.0));
+        assert_eq!(3.0 * Size::Vw(2.0) / 2.0, Size::Vw(3.0));
+        assert_eq!(-Size::Px(4.0), Size::Px(-4.0));
+
+        let width = Size::Percent(100.0) - Size::Rem(2.0) + Size::Px(4.0) + Size::Rem(0.5) - Size::Px(4.0);
+        assert_eq!(width.to_string(), "calc(100% - 1.5rem)");
+        assert_eq!((width.clone() - width.clone()).to_string(), "0%");
+    }
+
+    #[test]
+    fn test_auto_is_rejected() {
+        let error = MewError::InvalidValue(ParseValueError::new("Calc", "auto"));
+        assert_eq!(Size::Auto.try_add(Size::Px(5.0)), Err(error.clone()));
+        assert_eq!(Size::Px(5.0).try_sub(Size::Auto), Err(error.clone()));
+        assert_eq!(Size::Auto.try_mul(2.0), Err(error.clone()));
+        assert_eq!(Size::Auto.try_div(2.0), Err(error.clone()));
+        assert_eq!(Calc::try_size(Size::Auto), Err(error));
+
+        assert_eq!(Size::Rem(1.0).try_add(Size::Px(5.0)).unwrap().to_string(), "calc(1rem + 5px)");
+        assert_eq!(Size::Var(var("gap")).try_mul(2.0).unwrap().to_string(), "calc(var(--gap) * 2)");
+    }
+
+    #[test]
+    fn test_auto_operand_reported() {
+        let error = MewError::InvalidValue(ParseValueError::new("Calc", "auto"));
+
+        let size = Size::Auto + Size::Px(5.0);
+        assert_eq!(size.to_string(), "calc(auto + 5px)");
+        assert_eq!(size.validate(), Err(error.clone()));
+
+        let size = (Size::Percent(100.0) - Size::Auto) * 2.0;
+        assert_eq!(size.to_string(), "calc((100% - auto) * 2)");
+        assert_eq!(size.validate(), Err(error.clone()));
+
+        let calc = Calc::max([Size::Auto, Size::Px(10.0)]).unwrap();
+        assert_eq!(calc.validate(), Err(error));
+        assert_eq!((Size::Auto / 2.0).to_string(), "calc(auto / 2)");
+    }
+
+    #[test]
+    fn test_variables() {
+        let gap = || Size::Var(var("gap"));
+
+        assert_eq!((gap() + Size::Px(2.0)).to_string(), "calc(var(--gap) + 2px)");
+        assert_eq!((Size::Px(2.0) - gap()).to_string(), "calc(2px - var(--gap))");
+        assert_eq!((-gap()).to_string(), "calc(-1 * var(--gap))");
+        assert_eq!((gap() * 2.0 * 3.0).to_string(), "calc(var(--gap) * 6)");
+        assert_eq!((gap() / 4.0).to_string(), "calc(var(--gap) / 4)");
+        assert_eq!((gap() * 1.0), gap());
+        assert_eq!(((gap() + Size::Px(1.0)) * 2.0).to_string(), "calc((var(--gap) + 1px) * 2)");
+    }
+
+    #[test]
+    fn test_operand_parentheses() {
+        let gap = || Calc::Var(var("gap"));
+        let n = || Calc::Var(var("n"));
+
+        let calc = Calc::from(Size::Percent(100.0)) / (gap() * n());
+        assert_eq!(calc.to_string(), "calc(100% / (var(--gap) * var(--n)))");
+
+        let calc = gap() / n() * Calc::Number(2.0);
+        assert_eq!(calc.to_string(), "calc(var(--gap) / var(--n) * 2)");
+
+        let calc = Calc::from(Size::Px(10.0)) - (gap() - Calc::from(Size::Px(2.0)));
+        assert_eq!(calc.to_string(), "calc(12px - var(--gap))");
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |s: &str| s.parse::<Calc>().map(|calc| calc.to_string());
+
+        assert_eq!(parse("calc(100% - 2rem)").as_deref(), Ok("calc(100% - 2rem)"));
+        assert_eq!(parse("CALC( 1px+2px )").ok(), None);
+        assert_eq!(parse("calc(1px + 2px * 3)").as_deref(), Ok("calc(7px)"));
+        assert_eq!(parse("calc((1em + var(--x)) / 2)").as_deref(), Ok("calc((1em + var(--x)) / 2)"));
+        assert_eq!(parse("calc(1e1px - -2em)").as_deref(), Ok("calc(10px + 2em)"));
+        assert_eq!(parse("calc(100% - calc(2 * 1rem))").as_deref(), Ok("calc(100% - 2rem)"));
+
+        // Lengths can only be added to lengths, and multiplied or divided by numbers
+        assert!(parse("calc(1px + 2)").is_err());
+        assert!(parse("calc(1px * 2px)").is_err());
+        assert!(parse("calc(2 / 1px)").is_err());
+        assert!(parse("calc(1px -2px)").is_err());
+        assert!(parse("calc(1px + 2px").is_err());
+        assert!(parse("calc(1px) + 1px").is_err());
+        assert!(parse("sum(1px, 2px)").is_err());
+    }
+
+    #[test]
+    fn test_comparison_functions() {
+        let gap = || Size::Var(var("gap"));
+
+        assert_eq!(Calc::min([Size::Px(3.0), Size::Px(1.0), Size::Px(2.0)]), Ok(Calc::Length(Size::Px(1.0))));
+        assert_eq!(Calc::max([Calc::Number(1.0), Calc::Number(2.5)]), Ok(Calc::Number(2.5)));
+        assert_eq!(Calc::clamp(Size::Em(1.0), Size::Em(0.5), Size::Em(2.0)), Ok(Calc::Length(Size::Em(1.0))));
+        // The minimum wins over the maximum
+        assert_eq!(Calc::clamp(Size::Px(10.0), Size::Px(30.0), Size::Px(5.0)), Ok(Calc::Length(Size::Px(10.0))));
+
+        let width = Size::from(Calc::min([Size::Percent(100.0) - gap(), Size::Px(960.0)]).unwrap());
+        assert_eq!(width.to_string(), "min(100% - var(--gap), 960px)");
+
+        // Functions nested in expressions are written without `calc()`
+        let padding = Size::from(Calc::max([Size::Rem(1.0), Size::Vw(4.0)]).unwrap()) * 2.0 + Size::Px(1.0);
+        assert_eq!(padding.to_string(), "calc(max(1rem, 4vw) * 2 + 1px)");
+        let size = Calc::clamp(Size::Rem(1.0), Calc::min([Size::Vw(5.0), gap()]).unwrap(), Size::Rem(3.0)).unwrap();
+        assert_eq!(size.to_string(), "clamp(1rem, min(5vw, var(--gap)), 3rem)");
+    }
+
+    #[test]
+    fn test_fluid() {
+        assert_eq!(Calc::fluid(16.0, 32.0, 400.0, 1200.0).to_string(), "clamp(1rem, 2vw + 0.5rem, 2rem)");
+        // A size shrinking as the viewport grows
+        assert_eq!(Calc::fluid(32.0, 16.0, 400.0, 1200.0).to_string(), "clamp(1rem, -2vw + 2.5rem, 2rem)");
+        assert_eq!(Calc::fluid(16.0, 16.0, 400.0, 1200.0), Calc::Length(Size::Rem(1.0)));
+        assert_eq!(Calc::fluid(16.0, 24.0, 800.0, 800.0), Calc::Length(Size::Rem(1.0)));
+    }
+
+    #[test]
+    fn test_parse_functions() {
+        let parse = |s: &str| s.parse::<Calc>().map(|calc| calc.to_string());
+
+        assert_eq!(parse("min(100%, 960px)").as_deref(), Ok("min(100%, 960px)"));
+        assert_eq!(parse("MAX(1rem,4vw)").as_deref(), Ok("max(1rem, 4vw)"));
+        assert_eq!(parse("clamp(1rem, 2vw + 0.5rem, 2rem)").as_deref(), Ok("clamp(1rem, 2vw + 0.5rem, 2rem)"));
+        assert_eq!(parse("calc(min(1px, 2px) + 1px)").as_deref(), Ok("calc(2px)"));
+        assert_eq!(parse("min(var(--a), 2px)").as_deref(), Ok("min(var(--a), 2px)"));
+
+        assert!(parse("min(1px, 2)").is_err());
+        assert!(parse("clamp(1px, 2px)").is_err());
+        assert!(parse("min(1px, 2px").is_err());
+        assert!(parse("min()").is_err());
+        assert!(parse("(1px)").is_err());
+        assert!(parse("var(--a)").is_err());
+    }
+
+    #[test]
+    fn test_value_types() {
+        assert_eq!("calc(100% - 2rem)".parse::<Size>(), Ok(Size::Percent(100.0) - Size::Rem(2.0)));
+        assert_eq!("calc(2px * 2)".parse::<Size>(), Ok(Size::Px(4.0)));
+        assert!("calc(2 * 2)".parse::<Size>().is_err());
+
+        let font_size: FontSize = "calc(1rem + 2vw)".parse().unwrap();
+        assert_eq!(font_size, FontSize::Calc(Calc::from(Size::Rem(1.0) + Size::Vw(2.0))));
+        assert!("calc(1 + 2)".parse::<FontSize>().is_err());
+
+        assert_eq!("calc(1 + 0.5)".parse::<LineHeight>(), Ok(LineHeight::Calc(Calc::Number(1.5))));
+        assert_eq!("min(1.5, var(--lh))".parse::<LineHeight>().map(|lh| lh.to_string()).as_deref(), Ok("min(1.5, var(--lh))"));
+        assert_eq!("max(1px, 2%)".parse::<Size>().map(|size| size.to_string()).as_deref(), Ok("max(1px, 2%)"));
+        assert!("min(1, 2)".parse::<FontSize>().is_err());
+        assert_eq!(LineHeight::Calc(Calc::Number(1.5)).to_string(), "calc(1.5)");
+    }
+
+    #[test]
+    fn test_stepped_value_functions() {
+        let round = |strategy, value: f32| Calc::round(strategy, Size::Px(value), Size::Px(10.0)).unwrap();
+        assert_eq!(round(RoundingStrategy::Nearest, 15.0), Calc::Length(Size::Px(20.0)));
+        assert_eq!(round(RoundingStrategy::Nearest, -15.0), Calc::Length(Size::Px(-10.0)));
+        assert_eq!(round(RoundingStrategy::Up, 11.0), Calc::Length(Size::Px(20.0)));
+        assert_eq!(round(RoundingStrategy::Down, -11.0), Calc::Length(Size::Px(-20.0)));
+        assert_eq!(round(RoundingStrategy::ToZero, -19.0), Calc::Length(Size::Px(-10.0)));
+
+        assert_eq!(Calc::modulo(Size::Px(-7.0), Size::Px(3.0)), Ok(Calc::Length(Size::Px(2.0))));
+        assert_eq!(Calc::remainder(Size::Px(-7.0), Size::Px(3.0)), Ok(Calc::Length(Size::Px(-1.0))));
+        assert_eq!(Calc::abs(Size::Em(-1.5)), Ok(Calc::Length(Size::Em(1.5))));
+        assert_eq!(Calc::sign(0.0), Ok(Calc::Number(0.0)));
+
+        // Different units are left to the browser
+        let mixed = Calc::round(RoundingStrategy::Up, Size::Percent(50.0), Size::Px(8.0)).unwrap();
+        assert_eq!(mixed.to_string(), "round(up, 50%, 8px)");
+        assert_eq!(Calc::modulo(Size::Px(1.0), Size::Px(0.0)).unwrap().to_string(), "mod(1px, 0px)");
+    }
+
+    #[test]
+    fn test_trigonometric_functions() {
+        assert_eq!(Calc::sin(Angle::Deg(30.0)), Ok(Calc::Number(0.5)));
+        assert_eq!(Calc::cos(Angle::Rad(0.0)), Ok(Calc::Number(1.0)));
+        assert_eq!(Calc::tan(Angle::Grad(50.0)), Ok(Calc::Number(1.0)));
+        assert_eq!(Calc::sin(0.0), Ok(Calc::Number(0.0)));
+        assert_eq!(Calc::acos(0.0), Ok(Calc::Angle(Angle::Deg(90.0))));
+        assert_eq!(Calc::atan2(-1.0, 0.0), Ok(Calc::Angle(Angle::Deg(-90.0))));
+        assert_eq!(Calc::asin(2.0).unwrap().to_string(), "asin(2)");
+
+        // Angles take part in arithmetic like lengths
+        let angle = Calc::from(Angle::Deg(90.0)) + Calc::from(Angle::Deg(45.0));
+        assert_eq!(angle, Calc::Angle(Angle::Deg(135.0)));
+        let angle = Calc::from(Angle::Turn(1.0)) / Calc::from(var("count"));
+        assert_eq!(Calc::sin(angle).unwrap().to_string(), "sin(1turn / var(--count))");
+        assert_eq!(Calc::cos(Angle::Var(var("angle"))).unwrap().to_string(), "cos(var(--angle))");
+    }
+
+    #[test]
+    fn test_exponential_functions() {
+        assert_eq!(Calc::pow(2.0, 10.0), Ok(Calc::Number(1024.0)));
+        assert_eq!(Calc::sqrt(16.0), Ok(Calc::Number(4.0)));
+        assert_eq!(Calc::hypot([Size::Em(6.0), Size::Em(8.0)]), Ok(Calc::Length(Size::Em(10.0))));
+        assert_eq!(Calc::log(1.0, None), Ok(Calc::Number(0.0)));
+        assert_eq!(Calc::log(100.0, Some(Calc::Number(10.0))), Ok(Calc::Number(2.0)));
+        assert_eq!(Calc::exp(var("x")).unwrap().to_string(), "exp(var(--x))");
+        assert_eq!(Calc::sqrt(-1.0).unwrap().to_string(), "sqrt(-1)");
+        assert_eq!(Calc::hypot([Size::Px(3.0), Size::Rem(4.0)]).unwrap().to_string(), "hypot(3px, 4rem)");
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let error = |css: &str| Err(MewError::InvalidValue(ParseValueError::new("Calc", css)));
+
+        assert_eq!(Calc::sqrt(Size::Px(4.0)), error("sqrt(4px)"));
+        assert_eq!(Calc::exp(Angle::Deg(90.0)), error("exp(90deg)"));
+        assert_eq!(Calc::log(Size::Rem(8.0), None), error("log(8rem)"));
+        assert_eq!(Calc::log(8.0, Some(Calc::from(Size::Px(2.0)))), error("log(8, 2px)"));
+        assert_eq!(Calc::pow(Size::Px(2.0), 2.0), error("pow(2px, 2)"));
+        assert_eq!(Calc::sin(Size::Px(1.0)), error("sin(1px)"));
+        assert_eq!(Calc::asin(Angle::Turn(0.5)), error("asin(0.5turn)"));
+        assert_eq!(Calc::atan2(Size::Px(1.0), Angle::Deg(1.0)), error("atan2(1px, 1deg)"));
+        assert_eq!(Calc::min([Size::Px(1.0).into(), Calc::from(Angle::Deg(90.0))]), error("min(1px, 90deg)"));
+        assert_eq!(Calc::max(Vec::<Calc>::new()), error("max()"));
+        assert_eq!(Calc::clamp(1.0, Size::Px(2.0), 3.0), error("clamp(1, 2px, 3)"));
+        assert!(Calc::round(RoundingStrategy::Up, Angle::Deg(10.0), Size::Px(1.0)).is_err());
+        assert!(Calc::hypot([Calc::Number(3.0), Calc::from(Size::Px(4.0))]).is_err());
+
+        // Nested expressions are checked through their type
+        let length = Calc::from(Size::Rem(1.0)) * Calc::from(var("scale"));
+        assert!(Calc::sqrt(length).is_err());
+        assert!(Calc::sqrt(Calc::sin(Angle::Deg(30.0)).unwrap()).is_ok());
+
+        // The types of CSS variables are only known to the browser
+        assert!(Calc::sqrt(var("area")).is_ok());
+        assert!(Calc::min([Calc::from(var("a")), Calc::from(Angle::Deg(1.0))]).is_ok());
+    }
+
+    #[test]
+    fn test_parse_math_functions() {
+        let parse = |s: &str| s.parse::<Calc>().map(|calc| calc.to_string());
+
+        assert_eq!(parse("round(up, var(--w), 8px)").as_deref(), Ok("round(up, var(--w), 8px)"));
+        assert_eq!(parse("round(nearest, 13px, 8px)").as_deref(), Ok("calc(16px)"));
+        assert_eq!(parse("round(2.5)").as_deref(), Ok("calc(3)"));
+        assert_eq!(parse("calc(10px * sin(30deg))").as_deref(), Ok("calc(5px)"));
+        assert_eq!(parse("atan2(var(--y), 1px)").as_deref(), Ok("atan2(var(--y), 1px)"));
+        assert_eq!(parse("calc(90deg - 0.25turn)").as_deref(), Ok("calc(90deg - 0.25turn)"));
+        assert_eq!(parse("mod(370deg, 360deg)").as_deref(), Ok("calc(10deg)"));
+        assert_eq!(parse("calc(1rem * pow(1.25, var(--step)))").as_deref(), Ok("calc(1rem * pow(1.25, var(--step)))"));
+        assert_eq!(parse("log(8, 2)").as_deref(), Ok("calc(3)"));
+
+        assert!(parse("round(up)").is_err());
+        assert!(parse("round(1px)").is_err());
+        assert!(parse("round(up 1px, 2px)").is_err());
+        assert!(parse("calc(up)").is_err());
+        assert!(parse("sin(1px)").is_err());
+        assert!(parse("asin(1deg)").is_err());
+        assert!(parse("pow(2px, 2)").is_err());
+        assert!(parse("mod(1px, 1deg)").is_err());
+        assert!(parse("calc(1px + 1deg)").is_err());
+        assert!(parse("calc(1px * 1deg)").is_err());
+        assert!(parse("calc(1px / 1deg)").is_err());
+        assert!(parse("abs(1px, 2px)").is_err());
+
+        assert_eq!("calc(100% * sin(30deg))".parse::<Size>(), Ok(Size::Percent(50.0)));
+        assert!("calc(sin(30deg))".parse::<Size>().is_err());
+        assert!("atan(1)".parse::<FontSize>().is_err());
+        assert!("calc(1deg * 2)".parse::<LineHeight>().is_err());
+        assert_eq!("calc(sign(var(--x)) + 1)".parse::<LineHeight>().map(|lh| lh.to_string()).as_deref(), Ok("calc(sign(var(--x)) + 1)"));
+    }
+}
@@ -0,0 +1,2159 @@
+//! # Color Module
+//!
+//! This module resolves `Color` values to concrete sRGB components and provides color
+//! operations, so that colors can be inspected and computed in Rust before being
+//! written as CSS.
+//!
+//! The `Rgba` type holds the numeric components of a color. Any `Color` that does not
+//! depend on the document (named colors, hex codes, RGB and HSL values) can be resolved
+//! with `Color::to_rgba`, and converted back with `From<Rgba> for Color`.
+//!
+//! Colors can also be transformed with operations such as `lighten`, `mix` or
+//! `with_alpha`. Colors that cannot be resolved, such as CSS variables, are transformed
+//! by the browser instead, through a relative color (`RelativeColor`) or a `color-mix()`
+//! function (`ColorMix`). These functions, as well as `light-dark()`, can also be created
+//! directly with `Color::relative`, `Color::color_mix` and `Color::light_dark`, for
+//! instance to derive colors from design tokens stored in CSS variables.
+//!
+//! Colors of the modern color spaces (`Lab`, `Lch`, `Oklab`, `Oklch`, `Hwb`, `Srgb` and
+//! `DisplayP3`) are converted with `Color::to_space`. Colors outside of the sRGB gamut
+//! are mapped into it by reducing their chroma, as specified by CSS Color 4, so that
+//! palettes designed in a perceptual space can be written with sRGB fallbacks.
+//!
+//! Finally, `Color::contrast_ratio` and `ContrastAudit` check the contrast between text
+//! and background colors against the WCAG levels (see also `Style::audit_contrast`).
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::color::Rgba;
+//! use mew_css::values::Color;
+//! use mew_css::var;
+//!
+//! assert_eq!(Color::CornflowerBlue.to_rgba(), Some(Rgba::new(100, 149, 237, 1.0)));
+//! assert_eq!(Color::Hsl(120, 100, 25).to_hex(), Some("#008000".to_string()));
+//! assert_eq!(Color::Hex("#ff0000".to_string()).to_hsl(), Some(Color::Hsl(0, 100, 50)));
+//! assert_eq!(Color::Var(var("brand")).to_rgba(), None);
+//! assert_eq!(Color::Oklch(0.628, 0.2577, 29.23, 1.0).to_hex(), Some("#ff0000".to_string()));
+//!
+//! let color: Color = Rgba::new(255, 0, 0, 0.5).into();
+//! assert_eq!(color, Color::Rgba(255, 0, 0, 0.5));
+//!
+//! assert_eq!(Color::Black.lighten(20.0), Color::Rgb(51, 51, 51));
+//! assert_eq!(
+//!     Color::Var(var("primary")).with_alpha(0.8).to_string(),
+//!     "rgb(from var(--primary) r g b / 0.8)"
+//! );
+//! ```
+
+use crate::error::MewError;
+use crate::values::{Angle, Color, Number, ParseValueError};
+use std::fmt;
+use std::str::FromStr;
+
+/// The red, green, blue and alpha components of an sRGB color.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::color::Rgba;
+///
+/// let color = Rgba::new(30, 144, 255, 1.0);
+/// assert_eq!(color.to_string(), "rgb(30, 144, 255)");
+/// assert_eq!(Rgba::new(30, 144, 255, 0.5).to_string(), "rgba(30, 144, 255, 0.5)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgba {
+    /// The red channel, from 0 to 255
+    pub r: u8,
+    /// The green channel, from 0 to 255
+    pub g: u8,
+    /// The blue channel, from 0 to 255
+    pub b: u8,
+    /// The alpha value, from 0.0 (transparent) to 1.0 (opaque)
+    pub a: f32,
+}
+
+impl Rgba {
+    /// Creates a new color from its components.
+    ///
+    /// # Arguments
+    ///
+    /// * `r`, `g`, `b` - The red, green and blue channels
+    /// * `a` - The alpha value, clamped between 0.0 and 1.0
+    ///
+    /// # Returns
+    ///
+    /// A new `Rgba` instance
+    pub fn new(r: u8, g: u8, b: u8, a: f32) -> Self {
+        Self { r, g, b, a: clamp_alpha(a) }
+    }
+
+    /// Creates a color from HSL components.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - The hue in degrees (any value, wrapped into 0-360)
+    /// * `s` - The saturation, from 0.0 to 100.0
+    /// * `l` - The lightness, from 0.0 to 100.0
+    /// * `a` - The alpha value, from 0.0 to 1.0
+    ///
+    /// # Returns
+    ///
+    /// A new `Rgba` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::Rgba;
+    ///
+    /// assert_eq!(Rgba::from_hsl(240.0, 100.0, 50.0, 1.0), Rgba::new(0, 0, 255, 1.0));
+    /// ```
+    pub fn from_hsl(h: f32, s: f32, l: f32, a: f32) -> Self {
+        let h = h.rem_euclid(360.0);
+        let s = (s / 100.0).clamp(0.0, 1.0);
+        let l = (l / 100.0).clamp(0.0, 1.0);
+
+        let channel = |n: f32| {
+            let k = (n + h / 30.0) % 12.0;
+            let amount = s * l.min(1.0 - l);
+            l - amount * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+
+        Self::from_unit(channel(0.0), channel(8.0), channel(4.0), a)
+    }
+
+    /// Creates a color from red, green and blue channels between 0.0 and 1.0.
+    pub(crate) fn from_unit(r: f32, g: f32, b: f32, a: f32) -> Self {
+        let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self::new(channel(r), channel(g), channel(b), a)
+    }
+
+    /// Returns the red, green and blue channels between 0.0 and 1.0.
+    pub(crate) fn to_unit(self) -> (f32, f32, f32) {
+        (self.r as f32 / 255.0, self.g as f32 / 255.0, self.b as f32 / 255.0)
+    }
+
+    /// Returns the hue (in degrees, from 0.0 to 360.0), the saturation and the lightness
+    /// (from 0.0 to 100.0) of the color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::Rgba;
+    ///
+    /// assert_eq!(Rgba::new(255, 0, 0, 1.0).to_hsl(), (0.0, 100.0, 50.0));
+    /// ```
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_unit();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let l = (max + min) / 2.0;
+
+        if delta == 0.0 {
+            return (0.0, 0.0, l * 100.0);
+        }
+
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+
+        ((h * 60.0).rem_euclid(360.0), s * 100.0, l * 100.0)
+    }
+
+    /// Returns `true` if the color is fully opaque.
+    pub fn is_opaque(&self) -> bool {
+        self.a >= 1.0
+    }
+
+    /// Returns the color as a hex code, with an alpha byte only if the color is not opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::Rgba;
+    ///
+    /// assert_eq!(Rgba::new(255, 99, 71, 1.0).to_hex(), "#ff6347");
+    /// assert_eq!(Rgba::new(255, 99, 71, 0.5).to_hex(), "#ff634780");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        if self.is_opaque() {
+            format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        } else {
+            let alpha = (self.a * 255.0).round() as u8;
+            format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, alpha)
+        }
+    }
+}
+
+impl fmt::Display for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Color::from(*self))
+    }
+}
+
+// Implement From<Rgba> for Color to turn computed colors back into CSS values
+impl From<Rgba> for Color {
+    fn from(rgba: Rgba) -> Self {
+        if rgba.is_opaque() {
+            Color::Rgb(rgba.r, rgba.g, rgba.b)
+        } else {
+            Color::Rgba(rgba.r, rgba.g, rgba.b, rgba.a)
+        }
+    }
+}
+
+/// Clamps an alpha value between 0.0 and 1.0, and rounds it to three decimals
+/// to keep the CSS output short.
+pub(crate) fn clamp_alpha(a: f32) -> f32 {
+    if a.is_nan() {
+        return 1.0;
+    }
+    (a.clamp(0.0, 1.0) * 1000.0).round() / 1000.0
+}
+
+impl Color {
+    /// Resolves the color to its sRGB components.
+    ///
+    /// Named colors, `Transparent`, hex codes, RGB and HSL colors are resolved. Colors
+    /// that depend on the document (`Var`, `CurrentColor` and `Inherit`), the colors
+    /// computed by the browser (`Mix` and `Relative`) and invalid hex codes cannot be resolved.
+    ///
+    /// # Returns
+    ///
+    /// The components of the color, or `None` if the color cannot be resolved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::Rgba;
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::RebeccaPurple.to_rgba(), Some(Rgba::new(102, 51, 153, 1.0)));
+    /// assert_eq!(Color::Hex("#0f08".to_string()).to_rgba(), Some(Rgba::new(0, 255, 0, 0.533)));
+    /// assert_eq!(Color::Hsla(0, 100, 50, 0.25).to_rgba(), Some(Rgba::new(255, 0, 0, 0.25)));
+    /// assert_eq!(Color::CurrentColor.to_rgba(), None);
+    /// ```
+    pub fn to_rgba(&self) -> Option<Rgba> {
+        let (r, g, b) = match self {
+            Color::Transparent => return Some(Rgba::new(0, 0, 0, 0.0)),
+            Color::Rgb(r, g, b) => (*r, *g, *b),
+            Color::Rgba(r, g, b, a) => return Some(Rgba::new(*r, *g, *b, *a)),
+            Color::Hex(hex) => {
+                return match Color::parse(&format!("#{}", hex.strip_prefix('#').unwrap_or(hex))) {
+                    Ok(color @ (Color::Rgb(..) | Color::Rgba(..))) => color.to_rgba(),
+                    _ => None,
+                };
+            }
+            Color::Hsl(h, s, l) => return Some(Rgba::from_hsl(*h as f32, *s as f32, *l as f32, 1.0)),
+            Color::Hsla(h, s, l, a) => return Some(Rgba::from_hsl(*h as f32, *s as f32, *l as f32, *a)),
+            Color::Hwb(..)
+            | Color::Lab(..)
+            | Color::Lch(..)
+            | Color::Oklab(..)
+            | Color::Oklch(..)
+            | Color::Srgb(..)
+            | Color::DisplayP3(..) => return self.modern_to_rgba(),
+            Color::CurrentColor
+            | Color::Inherit
+            | Color::Var(_)
+            | Color::Mix(_)
+            | Color::Relative(_)
+            | Color::LightDark(..) => return None,
+            Color::AliceBlue => (240, 248, 255),
+            Color::AntiqueWhite => (250, 235, 215),
+            Color::Aqua => (0, 255, 255),
+            Color::Aquamarine => (127, 255, 212),
+            Color::Azure => (240, 255, 255),
+            Color::Beige => (245, 245, 220),
+            Color::Bisque => (255, 228, 196),
+            Color::Black => (0, 0, 0),
+            Color::BlanchedAlmond => (255, 235, 205),
+            Color::Blue => (0, 0, 255),
+            Color::BlueViolet => (138, 43, 226),
+            Color::Brown => (165, 42, 42),
+            Color::BurlyWood => (222, 184, 135),
+            Color::CadetBlue => (95, 158, 160),
+            Color::Chartreuse => (127, 255, 0),
+            Color::Chocolate => (210, 105, 30),
+            Color::Coral => (255, 127, 80),
+            Color::CornflowerBlue => (100, 149, 237),
+            Color::Cornsilk => (255, 248, 220),
+            Color::Crimson => (220, 20, 60),
+            Color::Cyan => (0, 255, 255),
+            Color::DarkBlue => (0, 0, 139),
+            Color::DarkCyan => (0, 139, 139),
+            Color::DarkGoldenRod => (184, 134, 11),
+            Color::DarkGray => (169, 169, 169),
+            Color::DarkGrey => (169, 169, 169),
+            Color::DarkGreen => (0, 100, 0),
+            Color::DarkKhaki => (189, 183, 107),
+            Color::DarkMagenta => (139, 0, 139),
+            Color::DarkOliveGreen => (85, 107, 47),
+            Color::DarkOrange => (255, 140, 0),
+            Color::DarkOrchid => (153, 50, 204),
+            Color::DarkRed => (139, 0, 0),
+            Color::DarkSalmon => (233, 150, 122),
+            Color::DarkSeaGreen => (143, 188, 143),
+            Color::DarkSlateBlue => (72, 61, 139),
+            Color::DarkSlateGray => (47, 79, 79),
+            Color::DarkSlateGrey => (47, 79, 79),
+            Color::DarkTurquoise => (0, 206, 209),
+            Color::DarkViolet => (148, 0, 211),
+            Color::DeepPink => (255, 20, 147),
+            Color::DeepSkyBlue => (0, 191, 255),
+            Color::DimGray => (105, 105, 105),
+            Color::DimGrey => (105, 105, 105),
+            Color::DodgerBlue => (30, 144, 255),
+            Color::FireBrick => (178, 34, 34),
+            Color::FloralWhite => (255, 250, 240),
+            Color::ForestGreen => (34, 139, 34),
+            Color::Fuchsia => (255, 0, 255),
+            Color::Gainsboro => (220, 220, 220),
+            Color::GhostWhite => (248, 248, 255),
+            Color::Gold => (255, 215, 0),
+            Color::GoldenRod => (218, 165, 32),
+            Color::Gray => (128, 128, 128),
+            Color::Grey => (128, 128, 128),
+            Color::Green => (0, 128, 0),
+            Color::GreenYellow => (173, 255, 47),
+            Color::HoneyDew => (240, 255, 240),
+            Color::HotPink => (255, 105, 180),
+            Color::IndianRed => (205, 92, 92),
+            Color::Indigo => (75, 0, 130),
+            Color::Ivory => (255, 255, 240),
+            Color::Khaki => (240, 230, 140),
+            Color::Lavender => (230, 230, 250),
+            Color::LavenderBlush => (255, 240, 245),
+            Color::LawnGreen => (124, 252, 0),
+            Color::LemonChiffon => (255, 250, 205),
+            Color::LightBlue => (173, 216, 230),
+            Color::LightCoral => (240, 128, 128),
+            Color::LightCyan => (224, 255, 255),
+            Color::LightGoldenRodYellow => (250, 250, 210),
+            Color::LightGray => (211, 211, 211),
+            Color::LightGrey => (211, 211, 211),
+            Color::LightGreen => (144, 238, 144),
+            Color::LightPink => (255, 182, 193),
+            Color::LightSalmon => (255, 160, 122),
+            Color::LightSeaGreen => (32, 178, 170),
+            Color::LightSkyBlue => (135, 206, 250),
+            Color::LightSlateGray => (119, 136, 153),
+            Color::LightSlateGrey => (119, 136, 153),
+            Color::LightSteelBlue => (176, 196, 222),
+            Color::LightYellow => (255, 255, 224),
+            Color::Lime => (0, 255, 0),
+            Color::LimeGreen => (50, 205, 50),
+            Color::Linen => (250, 240, 230),
+            Color::Magenta => (255, 0, 255),
+            Color::Maroon => (128, 0, 0),
+            Color::MediumAquaMarine => (102, 205, 170),
+            Color::MediumBlue => (0, 0, 205),
+            Color::MediumOrchid => (186, 85, 211),
+            Color::MediumPurple => (147, 112, 219),
+            Color::MediumSeaGreen => (60, 179, 113),
+            Color::MediumSlateBlue => (123, 104, 238),
+            Color::MediumSpringGreen => (0, 250, 154),
+            Color::MediumTurquoise => (72, 209, 204),
+            Color::MediumVioletRed => (199, 21, 133),
+            Color::MidnightBlue => (25, 25, 112),
+            Color::MintCream => (245, 255, 250),
+            Color::MistyRose => (255, 228, 225),
+            Color::Moccasin => (255, 228, 181),
+            Color::NavajoWhite => (255, 222, 173),
+            Color::Navy => (0, 0, 128),
+            Color::OldLace => (253, 245, 230),
+            Color::Olive => (128, 128, 0),
+            Color::OliveDrab => (107, 142, 35),
+            Color::Orange => (255, 165, 0),
+            Color::OrangeRed => (255, 69, 0),
+            Color::Orchid => (218, 112, 214),
+            Color::PaleGoldenRod => (238, 232, 170),
+            Color::PaleGreen => (152, 251, 152),
+            Color::PaleTurquoise => (175, 238, 238),
+            Color::PaleVioletRed => (219, 112, 147),
+            Color::PapayaWhip => (255, 239, 213),
+            Color::PeachPuff => (255, 218, 185),
+            Color::Peru => (205, 133, 63),
+            Color::Pink => (255, 192, 203),
+            Color::Plum => (221, 160, 221),
+            Color::PowderBlue => (176, 224, 230),
+            Color::Purple => (128, 0, 128),
+            Color::RebeccaPurple => (102, 51, 153),
+            Color::Red => (255, 0, 0),
+            Color::RosyBrown => (188, 143, 143),
+            Color::RoyalBlue => (65, 105, 225),
+            Color::SaddleBrown => (139, 69, 19),
+            Color::Salmon => (250, 128, 114),
+            Color::SandyBrown => (244, 164, 96),
+            Color::SeaGreen => (46, 139, 87),
+            Color::SeaShell => (255, 245, 238),
+            Color::Sienna => (160, 82, 45),
+            Color::Silver => (192, 192, 192),
+            Color::SkyBlue => (135, 206, 235),
+            Color::SlateBlue => (106, 90, 205),
+            Color::SlateGray => (112, 128, 144),
+            Color::SlateGrey => (112, 128, 144),
+            Color::Snow => (255, 250, 250),
+            Color::SpringGreen => (0, 255, 127),
+            Color::SteelBlue => (70, 130, 180),
+            Color::Tan => (210, 180, 140),
+            Color::Teal => (0, 128, 128),
+            Color::Thistle => (216, 191, 216),
+            Color::Tomato => (255, 99, 71),
+            Color::Turquoise => (64, 224, 208),
+            Color::Violet => (238, 130, 238),
+            Color::Wheat => (245, 222, 179),
+            Color::White => (255, 255, 255),
+            Color::WhiteSmoke => (245, 245, 245),
+            Color::Yellow => (255, 255, 0),
+            Color::YellowGreen => (154, 205, 50),
+        };
+        Some(Rgba::new(r, g, b, 1.0))
+    }
+
+    /// Returns the hex code of the color, with an alpha byte only if the color is not opaque.
+    ///
+    /// # Returns
+    ///
+    /// The lowercase hex code (e.g., `#1e90ff`), or `None` if the color cannot be resolved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::DodgerBlue.to_hex(), Some("#1e90ff".to_string()));
+    /// assert_eq!(Color::Transparent.to_hex(), Some("#00000000".to_string()));
+    /// ```
+    pub fn to_hex(&self) -> Option<String> {
+        self.to_rgba().map(|rgba| rgba.to_hex())
+    }
+
+    /// Converts the color to an HSL color, rounding each component to a whole number.
+    ///
+    /// # Returns
+    ///
+    /// A `Color::Hsl`, or a `Color::Hsla` if the color is not opaque, or `None` if the
+    /// color cannot be resolved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::Teal.to_hsl(), Some(Color::Hsl(180, 100, 25)));
+    /// assert_eq!(Color::Rgba(0, 0, 255, 0.5).to_hsl(), Some(Color::Hsla(240, 100, 50, 0.5)));
+    /// ```
+    pub fn to_hsl(&self) -> Option<Color> {
+        let rgba = self.to_rgba()?;
+        let (h, s, l) = rgba.to_hsl();
+        let (h, s, l) = ((h.round() as u16) % 360, s.round() as u8, l.round() as u8);
+
+        Some(if rgba.is_opaque() {
+            Color::Hsl(h, s, l)
+        } else {
+            Color::Hsla(h, s, l, rgba.a)
+        })
+    }
+}
+
+/// A color space, used to interpolate colors in `color-mix()` and to derive relative colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorSpace {
+    /// The sRGB color space (`srgb`)
+    Srgb,
+    /// The linear-light sRGB color space (`srgb-linear`)
+    SrgbLinear,
+    /// The Display P3 color space (`display-p3`)
+    DisplayP3,
+    /// The CIE Lab color space (`lab`)
+    Lab,
+    /// The Oklab color space (`oklab`)
+    Oklab,
+    /// The CIE LCH color space (`lch`)
+    Lch,
+    /// The Oklch color space (`oklch`)
+    Oklch,
+    /// The HSL color space (`hsl`)
+    Hsl,
+    /// The HWB color space (`hwb`)
+    Hwb,
+    /// The CIE XYZ color space (`xyz`)
+    Xyz,
+}
+
+impl fmt::Display for ColorSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorSpace::Srgb => write!(f, "srgb"),
+            ColorSpace::SrgbLinear => write!(f, "srgb-linear"),
+            ColorSpace::DisplayP3 => write!(f, "display-p3"),
+            ColorSpace::Lab => write!(f, "lab"),
+            ColorSpace::Oklab => write!(f, "oklab"),
+            ColorSpace::Lch => write!(f, "lch"),
+            ColorSpace::Oklch => write!(f, "oklch"),
+            ColorSpace::Hsl => write!(f, "hsl"),
+            ColorSpace::Hwb => write!(f, "hwb"),
+            ColorSpace::Xyz => write!(f, "xyz"),
+        }
+    }
+}
+
+impl FromStr for ColorSpace {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "srgb" => Ok(ColorSpace::Srgb),
+            "srgb-linear" => Ok(ColorSpace::SrgbLinear),
+            "display-p3" => Ok(ColorSpace::DisplayP3),
+            "lab" => Ok(ColorSpace::Lab),
+            "oklab" => Ok(ColorSpace::Oklab),
+            "lch" => Ok(ColorSpace::Lch),
+            "oklch" => Ok(ColorSpace::Oklch),
+            "hsl" => Ok(ColorSpace::Hsl),
+            "hwb" => Ok(ColorSpace::Hwb),
+            // `xyz` is an alias of `xyz-d65`
+            "xyz" | "xyz-d65" => Ok(ColorSpace::Xyz),
+            _ => Err(ParseValueError::new("ColorSpace", s)),
+        }
+    }
+}
+
+impl ColorSpace {
+    /// Returns the names of the three channels of the space, which the channel
+    /// expressions of a relative color can refer to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::ColorSpace;
+    ///
+    /// assert_eq!(ColorSpace::Oklch.channels(), ["l", "c", "h"]);
+    /// assert_eq!(ColorSpace::DisplayP3.channels(), ["r", "g", "b"]);
+    /// ```
+    pub fn channels(&self) -> [&'static str; 3] {
+        match self {
+            ColorSpace::Srgb | ColorSpace::SrgbLinear | ColorSpace::DisplayP3 => ["r", "g", "b"],
+            ColorSpace::Lab | ColorSpace::Oklab => ["l", "a", "b"],
+            ColorSpace::Lch | ColorSpace::Oklch => ["l", "c", "h"],
+            ColorSpace::Hsl => ["h", "s", "l"],
+            ColorSpace::Hwb => ["h", "w", "b"],
+            ColorSpace::Xyz => ["x", "y", "z"],
+        }
+    }
+
+    /// Returns `true` if the space has a hue channel, so that colors mixed in it can
+    /// use a `HueInterpolation`.
+    pub fn is_polar(&self) -> bool {
+        matches!(self, ColorSpace::Hsl | ColorSpace::Hwb | ColorSpace::Lch | ColorSpace::Oklch)
+    }
+}
+
+/// The way hues are interpolated when mixing colors in a polar color space
+/// (`shorter hue`, `longer hue`, ...).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HueInterpolation {
+    /// Takes the shorter arc between the hues (the default)
+    Shorter,
+    /// Takes the longer arc between the hues
+    Longer,
+    /// Goes around the hue circle in increasing hue order
+    Increasing,
+    /// Goes around the hue circle in decreasing hue order
+    Decreasing,
+}
+
+impl fmt::Display for HueInterpolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HueInterpolation::Shorter => write!(f, "shorter hue"),
+            HueInterpolation::Longer => write!(f, "longer hue"),
+            HueInterpolation::Increasing => write!(f, "increasing hue"),
+            HueInterpolation::Decreasing => write!(f, "decreasing hue"),
+        }
+    }
+}
+
+impl FromStr for HueInterpolation {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_whitespace().collect::<Vec<_>>().join(" ").to_ascii_lowercase().as_str() {
+            "shorter hue" => Ok(HueInterpolation::Shorter),
+            "longer hue" => Ok(HueInterpolation::Longer),
+            "increasing hue" => Ok(HueInterpolation::Increasing),
+            "decreasing hue" => Ok(HueInterpolation::Decreasing),
+            _ => Err(ParseValueError::new("HueInterpolation", s)),
+        }
+    }
+}
+
+/// A `color-mix()` function, mixing two colors in a color space.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::color::{ColorMix, ColorSpace};
+/// use mew_css::values::Color;
+/// use mew_css::var;
+///
+/// let mix = ColorMix {
+///     space: ColorSpace::Oklch,
+///     hue: None,
+///     first: Color::Var(var("brand")),
+///     first_percentage: Some(30.0),
+///     second: Color::White,
+///     second_percentage: None,
+/// };
+///
+/// assert_eq!(mix.to_string(), "color-mix(in oklch, var(--brand) 30%, white)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorMix {
+    /// The color space the colors are mixed in
+    pub space: ColorSpace,
+    /// The way hues are interpolated, only allowed in polar spaces (see `ColorSpace::is_polar`)
+    pub hue: Option<HueInterpolation>,
+    /// The first color
+    pub first: Color,
+    /// The proportion of the first color, from 0 to 100
+    pub first_percentage: Option<f32>,
+    /// The second color
+    pub second: Color,
+    /// The proportion of the second color, from 0 to 100
+    pub second_percentage: Option<f32>,
+}
+
+impl ColorMix {
+    /// Creates a new `color-mix()` function mixing two colors in equal proportions.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - The color space the colors are mixed in
+    /// * `first` - The first color
+    /// * `second` - The second color
+    ///
+    /// # Returns
+    ///
+    /// A new `ColorMix` instance
+    pub fn new(space: ColorSpace, first: Color, second: Color) -> Self {
+        Self {
+            space,
+            hue: None,
+            first,
+            first_percentage: None,
+            second,
+            second_percentage: None,
+        }
+    }
+
+    /// Sets the way hues are interpolated.
+    ///
+    /// # Arguments
+    ///
+    /// * `hue` - The hue interpolation method, only allowed in polar spaces
+    ///
+    /// # Returns
+    ///
+    /// The updated `ColorMix` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::{ColorMix, ColorSpace, HueInterpolation};
+    /// use mew_css::values::Color;
+    ///
+    /// let mix = ColorMix::new(ColorSpace::Oklch, Color::Red, Color::Blue).hue(HueInterpolation::Longer);
+    /// assert_eq!(mix.to_string(), "color-mix(in oklch longer hue, red, blue)");
+    /// assert!(mix.validate().is_ok());
+    ///
+    /// let mix = ColorMix::new(ColorSpace::Oklab, Color::Red, Color::Blue).hue(HueInterpolation::Longer);
+    /// assert!(mix.validate().is_err());
+    /// ```
+    pub fn hue(mut self, hue: HueInterpolation) -> Self {
+        self.hue = Some(hue);
+        self
+    }
+
+    /// Sets the proportion of the first color.
+    ///
+    /// # Arguments
+    ///
+    /// * `percentage` - The proportion of the first color, from 0 to 100
+    ///
+    /// # Returns
+    ///
+    /// The updated `ColorMix` instance
+    pub fn first_percentage(mut self, percentage: f32) -> Self {
+        self.first_percentage = Some(percentage);
+        self
+    }
+
+    /// Sets the proportion of the second color.
+    ///
+    /// # Arguments
+    ///
+    /// * `percentage` - The proportion of the second color, from 0 to 100
+    ///
+    /// # Returns
+    ///
+    /// The updated `ColorMix` instance
+    pub fn second_percentage(mut self, percentage: f32) -> Self {
+        self.second_percentage = Some(percentage);
+        self
+    }
+
+    /// Checks that the hue interpolation, if any, is used with a polar space and that
+    /// the mixed colors are valid.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the function is valid, or the `MewError` describing the problem
+    pub fn validate(&self) -> Result<(), MewError> {
+        if let Some(hue) = self.hue
+            && !self.space.is_polar()
+        {
+            return Err(ParseValueError::new("ColorMix", &format!("in {} {}", self.space, hue)).into());
+        }
+        self.first.validate()?;
+        self.second.validate()
+    }
+}
+
+impl fmt::Display for ColorMix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "color-mix(in {}", self.space)?;
+        if let Some(hue) = self.hue {
+            write!(f, " {}", hue)?;
+        }
+        write!(f, ", {}", self.first)?;
+        if let Some(percentage) = self.first_percentage {
+            write!(f, " {}%", Number::new(percentage))?;
+        }
+        write!(f, ", {}", self.second)?;
+        if let Some(percentage) = self.second_percentage {
+            write!(f, " {}%", Number::new(percentage))?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// A relative color, deriving a color from an origin color by rewriting its channels.
+///
+/// Each channel is a CSS expression that can refer to the channels of the origin color
+/// by their names (e.g., `r`, `g` and `b` in sRGB, or `h`, `s` and `l` in HSL, see
+/// `ColorSpace::channels`). The names used by the expressions are checked by `validate`
+/// and `try_new`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::color::{ColorSpace, RelativeColor};
+/// use mew_css::values::Color;
+/// use mew_css::var;
+///
+/// let lighter = RelativeColor {
+///     space: ColorSpace::Hsl,
+///     origin: Color::Var(var("brand")),
+///     channels: ["h".to_string(), "s".to_string(), "calc(l + 10)".to_string()],
+///     alpha: None,
+/// };
+///
+/// assert_eq!(lighter.to_string(), "hsl(from var(--brand) h s calc(l + 10))");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelativeColor {
+    /// The color space the channels are expressed in
+    pub space: ColorSpace,
+    /// The color the channels are taken from
+    pub origin: Color,
+    /// The expressions of the three channels
+    pub channels: [String; 3],
+    /// The expression of the alpha value, or `None` to keep the alpha of the origin color
+    pub alpha: Option<String>,
+}
+
+impl RelativeColor {
+    /// Creates a new relative color keeping the alpha value of the origin color.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - The color space the channels are expressed in
+    /// * `origin` - The color the channels are taken from
+    /// * `channels` - The expressions of the three channels
+    ///
+    /// # Returns
+    ///
+    /// A new `RelativeColor` instance
+    pub fn new(space: ColorSpace, origin: Color, channels: [&str; 3]) -> Self {
+        Self {
+            space,
+            origin,
+            channels: channels.map(str::to_string),
+            alpha: None,
+        }
+    }
+
+    /// Creates a new relative color, checking that the channel expressions only refer
+    /// to the channels of the space.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - The color space the channels are expressed in
+    /// * `origin` - The color the channels are taken from
+    /// * `channels` - The expressions of the three channels
+    ///
+    /// # Returns
+    ///
+    /// The new `RelativeColor`, or a `MewError` if the color is invalid (see `validate`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::{ColorSpace, RelativeColor};
+    /// use mew_css::values::Color;
+    ///
+    /// assert!(RelativeColor::try_new(ColorSpace::Oklch, Color::Red, ["calc(l * 0.9)", "c", "h"]).is_ok());
+    ///
+    /// // `s` is not a channel of Oklch
+    /// assert!(RelativeColor::try_new(ColorSpace::Oklch, Color::Red, ["l", "s", "h"]).is_err());
+    /// ```
+    pub fn try_new(space: ColorSpace, origin: Color, channels: [&str; 3]) -> Result<Self, MewError> {
+        let relative = Self::new(space, origin, channels);
+        relative.validate().map(|_| relative)
+    }
+
+    /// Sets the expression of the alpha value.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - The expression of the alpha value (e.g., `0.5` or `calc(alpha / 2)`)
+    ///
+    /// # Returns
+    ///
+    /// The updated `RelativeColor` instance
+    pub fn alpha(mut self, alpha: &str) -> Self {
+        self.alpha = Some(alpha.to_string());
+        self
+    }
+
+    /// Checks that the channel and alpha expressions are not empty and only refer to
+    /// the channels of the space, `alpha`, `none` and math constants such as `pi`, and
+    /// that the origin color is valid.
+    ///
+    /// Function names, numbers with their units and the arguments of `var()` and `env()`
+    /// are not checked.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the color is valid, or the `MewError` describing the problem
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::{ColorSpace, RelativeColor};
+    /// use mew_css::values::Color;
+    /// use mew_css::var;
+    ///
+    /// let relative = RelativeColor::new(ColorSpace::Hsl, Color::Var(var("brand")), ["h", "s", "calc(l + 10%)"]);
+    /// assert!(relative.validate().is_ok());
+    /// assert!(relative.clone().alpha("calc(alpha / 2)").validate().is_ok());
+    /// assert!(relative.alpha("calc(alhpa / 2)").validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), MewError> {
+        for expression in self.channels.iter().chain(&self.alpha) {
+            self.validate_expression(expression)?;
+        }
+        self.origin.validate()
+    }
+
+    /// Checks the keywords of a channel or alpha expression.
+    fn validate_expression(&self, expression: &str) -> Result<(), MewError> {
+        let error = || ParseValueError::new("RelativeColor", expression).into();
+        let channels = self.space.channels();
+        let is_keyword = |word: &str| {
+            let word = word.to_ascii_lowercase();
+            channels.contains(&word.as_str())
+                || ["alpha", "none", "e", "pi", "infinity", "-infinity", "nan"].contains(&word.as_str())
+        };
+
+        if expression.trim().is_empty() {
+            return Err(error());
+        }
+
+        let is_word_char = |c: char| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '%');
+        let chars: Vec<(usize, char)> = expression.char_indices().collect();
+        let mut i = 0;
+        // The depth of the `var()` or `env()` call being skipped, if any
+        let mut skipped = 0usize;
+
+        while i < chars.len() {
+            let (start, c) = chars[i];
+            if !is_word_char(c) {
+                match c {
+                    '(' if skipped > 0 => skipped += 1,
+                    ')' if skipped > 0 => skipped -= 1,
+                    _ => {}
+                }
+                i += 1;
+                continue;
+            }
+
+            // Read a whole word, such as `calc`, `10deg`, `--gap` or `l`
+            let mut j = i;
+            while j < chars.len() && is_word_char(chars[j].1) {
+                j += 1;
+            }
+            let end = chars.get(j).map_or(expression.len(), |&(end, _)| end);
+            let word = &expression[start..end];
+            let is_function = chars.get(j).is_some_and(|&(_, c)| c == '(');
+            i = j;
+
+            if skipped > 0 {
+                continue;
+            }
+            if is_function {
+                if word.eq_ignore_ascii_case("var") || word.eq_ignore_ascii_case("env") {
+                    skipped = 1;
+                    i += 1;
+                }
+                continue;
+            }
+
+            let number = word.trim_start_matches(['-', '+']);
+            if word == "-" || number.starts_with(|c: char| c.is_ascii_digit() || c == '.') || is_keyword(word) {
+                continue;
+            }
+            return Err(error());
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RelativeColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [c1, c2, c3] = &self.channels;
+
+        // Spaces without a function of their own use the generic `color()` function
+        match self.space {
+            ColorSpace::Srgb => write!(f, "rgb(from {}", self.origin)?,
+            ColorSpace::Hsl | ColorSpace::Hwb | ColorSpace::Lab | ColorSpace::Lch | ColorSpace::Oklab | ColorSpace::Oklch => {
+                write!(f, "{}(from {}", self.space, self.origin)?
+            }
+            ColorSpace::SrgbLinear | ColorSpace::DisplayP3 | ColorSpace::Xyz => {
+                write!(f, "color(from {} {}", self.origin, self.space)?
+            }
+        }
+
+        write!(f, " {} {} {}", c1, c2, c3)?;
+        if let Some(alpha) = &self.alpha {
+            write!(f, " / {}", alpha)?;
+        }
+        write!(f, ")")
+    }
+}
+
+// Implement From<ColorMix> for Color to allow using a ColorMix where a Color is expected
+impl From<ColorMix> for Color {
+    fn from(mix: ColorMix) -> Self {
+        Color::Mix(Box::new(mix))
+    }
+}
+
+// Implement From<RelativeColor> for Color to allow using a RelativeColor where a Color is expected
+impl From<RelativeColor> for Color {
+    fn from(relative: RelativeColor) -> Self {
+        Color::Relative(Box::new(relative))
+    }
+}
+
+// Unlike the color operations below, these functions are never computed in Rust: they
+// are written as CSS and resolved by the browser, so that their colors can be CSS
+// variables such as design tokens.
+impl Color {
+    /// Creates a `color-mix()` function.
+    ///
+    /// Use `ColorMix` directly to set the proportion of the second color instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - The color space the colors are mixed in
+    /// * `first` - The first color
+    /// * `percentage` - The proportion of the first color, from 0 to 100
+    /// * `second` - The second color
+    ///
+    /// # Returns
+    ///
+    /// A new `Color::Mix`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::ColorSpace;
+    /// use mew_css::values::Color;
+    /// use mew_css::var;
+    ///
+    /// let color = Color::color_mix(ColorSpace::Oklch, Color::Var(var("brand")), 40.0, Color::Var(var("surface")));
+    /// assert_eq!(color.to_string(), "color-mix(in oklch, var(--brand) 40%, var(--surface))");
+    /// ```
+    pub fn color_mix(space: ColorSpace, first: Color, percentage: f32, second: Color) -> Color {
+        ColorMix::new(space, first, second).first_percentage(percentage).into()
+    }
+
+    /// Creates a relative color, keeping the alpha value of the origin color.
+    ///
+    /// Use `RelativeColor` directly to also set the alpha value.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - The color space the channels are expressed in
+    /// * `origin` - The color the channels are taken from
+    /// * `channels` - The expressions of the three channels, which can refer to the
+    ///   channels of the origin color by their names (e.g., `l`, `c` and `h` in Oklch)
+    ///
+    /// # Returns
+    ///
+    /// A new `Color::Relative`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::ColorSpace;
+    /// use mew_css::values::Color;
+    /// use mew_css::var;
+    ///
+    /// let color = Color::relative(ColorSpace::Oklch, Color::Var(var("brand")), ["calc(l + 0.1)", "c", "h"]);
+    /// assert_eq!(color.to_string(), "oklch(from var(--brand) calc(l + 0.1) c h)");
+    /// ```
+    pub fn relative(space: ColorSpace, origin: Color, channels: [&str; 3]) -> Color {
+        RelativeColor::new(space, origin, channels).into()
+    }
+
+    /// Creates a `light-dark()` function, which is resolved to the first color with a
+    /// light color scheme and to the second one with a dark color scheme.
+    ///
+    /// # Arguments
+    ///
+    /// * `light` - The color used with a light color scheme
+    /// * `dark` - The color used with a dark color scheme
+    ///
+    /// # Returns
+    ///
+    /// A new `Color::LightDark`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    /// use mew_css::var;
+    ///
+    /// let color = Color::light_dark(Color::Var(var("ink")), Color::White);
+    /// assert_eq!(color.to_string(), "light-dark(var(--ink), white)");
+    /// ```
+    pub fn light_dark(light: Color, dark: Color) -> Color {
+        Color::LightDark(Box::new(light), Box::new(dark))
+    }
+}
+
+/// Returns a `calc()` expression adding `amount` to a channel.
+fn offset(channel: &str, amount: f32) -> String {
+    if amount < 0.0 {
+        format!("calc({} - {})", channel, -amount)
+    } else {
+        format!("calc({} + {})", channel, amount)
+    }
+}
+
+/// Mixes two colors in sRGB with premultiplied alpha, like `color-mix(in srgb, ...)`.
+fn mix_srgb(first: Rgba, second: Rgba, first_weight: f32) -> Rgba {
+    let second_weight = 1.0 - first_weight;
+    let alpha = first.a * first_weight + second.a * second_weight;
+    if alpha == 0.0 {
+        return Rgba::new(0, 0, 0, 0.0);
+    }
+
+    let (r1, g1, b1) = first.to_unit();
+    let (r2, g2, b2) = second.to_unit();
+    let channel = |c1: f32, c2: f32| (c1 * first.a * first_weight + c2 * second.a * second_weight) / alpha;
+
+    Rgba::from_unit(channel(r1, r2), channel(g1, g2), channel(b1, b2), alpha)
+}
+
+// Color operations compute a new color in Rust when the color can be resolved (see
+// `to_rgba`). Otherwise, as for `Var` and `CurrentColor`, they return a relative color
+// or a `color-mix()` function, so that the browser computes the color instead.
+impl Color {
+    /// Applies a change to the HSL components of the color.
+    fn map_hsl(&self, change: impl Fn(f32, f32, f32) -> (f32, f32, f32), fallback: [&str; 3]) -> Color {
+        match self.to_rgba() {
+            Some(rgba) => {
+                let (h, s, l) = rgba.to_hsl();
+                let (h, s, l) = change(h, s, l);
+                Rgba::from_hsl(h, s, l, rgba.a).into()
+            }
+            None => RelativeColor::new(ColorSpace::Hsl, self.clone(), fallback).into(),
+        }
+    }
+
+    /// Increases the lightness of the color.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The lightness to add, in percentage points (e.g., `10.0`)
+    ///
+    /// # Returns
+    ///
+    /// The lighter color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    /// use mew_css::var;
+    ///
+    /// assert_eq!(Color::Hsl(0, 100, 40).lighten(10.0), Color::Rgb(255, 0, 0));
+    /// assert_eq!(
+    ///     Color::Var(var("brand")).lighten(10.0).to_string(),
+    ///     "hsl(from var(--brand) h s calc(l + 10))"
+    /// );
+    /// ```
+    pub fn lighten(&self, amount: f32) -> Color {
+        self.map_hsl(|h, s, l| (h, s, l + amount), ["h", "s", &offset("l", amount)])
+    }
+
+    /// Decreases the lightness of the color.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The lightness to remove, in percentage points (e.g., `10.0`)
+    ///
+    /// # Returns
+    ///
+    /// The darker color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    /// use mew_css::var;
+    ///
+    /// assert_eq!(Color::White.darken(50.0), Color::Rgb(128, 128, 128));
+    /// assert_eq!(
+    ///     Color::Var(var("border")).darken(10.0).to_string(),
+    ///     "hsl(from var(--border) h s calc(l - 10))"
+    /// );
+    /// ```
+    pub fn darken(&self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Increases the saturation of the color.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The saturation to add, in percentage points
+    ///
+    /// # Returns
+    ///
+    /// The more saturated color
+    pub fn saturate(&self, amount: f32) -> Color {
+        self.map_hsl(|h, s, l| (h, s + amount, l), ["h", &offset("s", amount), "l"])
+    }
+
+    /// Decreases the saturation of the color.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The saturation to remove, in percentage points
+    ///
+    /// # Returns
+    ///
+    /// The less saturated color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::Hsl(120, 100, 50).desaturate(100.0), Color::Rgb(128, 128, 128));
+    /// ```
+    pub fn desaturate(&self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// Rotates the hue of the color.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle to add to the hue
+    ///
+    /// # Returns
+    ///
+    /// The color with its hue rotated. An angle held in a CSS variable always gives a
+    /// relative color, dividing the variable by `1deg` since the `h` channel is a number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::{Angle, Color};
+    /// use mew_css::var;
+    ///
+    /// assert_eq!(Color::Red.rotate_hue(Angle::Deg(120.0)), Color::Rgb(0, 255, 0));
+    /// assert_eq!(Color::Red.rotate_hue(Angle::Turn(0.5)), Color::Rgb(0, 255, 255));
+    /// assert_eq!(
+    ///     Color::Var(var("accent")).rotate_hue(Angle::Deg(-30.0)).to_string(),
+    ///     "hsl(from var(--accent) calc(h - 30) s l)"
+    /// );
+    /// assert_eq!(
+    ///     Color::Red.rotate_hue(Angle::Var(var("spin"))).to_string(),
+    ///     "hsl(from red calc(h + var(--spin) / 1deg) s l)"
+    /// );
+    /// ```
+    pub fn rotate_hue(&self, angle: Angle) -> Color {
+        match angle.to_deg() {
+            Some(degrees) => self.map_hsl(|h, s, l| (h + degrees, s, l), [&offset("h", degrees), "s", "l"]),
+            None => {
+                let hue = format!("calc(h + {} / 1deg)", angle);
+                RelativeColor::new(ColorSpace::Hsl, self.clone(), [&hue, "s", "l"]).into()
+            }
+        }
+    }
+
+    /// Removes the saturation of the color, keeping its lightness.
+    ///
+    /// # Returns
+    ///
+    /// The gray color with the same lightness
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::Blue.grayscale(), Color::Rgb(128, 128, 128));
+    /// ```
+    pub fn grayscale(&self) -> Color {
+        self.map_hsl(|h, _, l| (h, 0.0, l), ["h", "0", "l"])
+    }
+
+    /// Mixes the color with another color in sRGB.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color to mix with
+    /// * `weight` - The proportion of this color in the mix, from 0 to 100
+    ///
+    /// # Returns
+    ///
+    /// The mixed color, or a `color-mix()` function if either color cannot be resolved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    /// use mew_css::var;
+    ///
+    /// assert_eq!(Color::Red.mix(&Color::Blue, 50.0), Color::Rgb(128, 0, 128));
+    /// assert_eq!(
+    ///     Color::Var(var("primary")).mix(&Color::White, 80.0).to_string(),
+    ///     "color-mix(in srgb, var(--primary) 80%, white)"
+    /// );
+    /// ```
+    pub fn mix(&self, other: &Color, weight: f32) -> Color {
+        let weight = weight.clamp(0.0, 100.0);
+
+        match (self.to_rgba(), other.to_rgba()) {
+            (Some(first), Some(second)) => mix_srgb(first, second, weight / 100.0).into(),
+            _ => Color::color_mix(ColorSpace::Srgb, self.clone(), weight, other.clone()),
+        }
+    }
+
+    /// Replaces the alpha value of the color.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - The new alpha value, from 0.0 (transparent) to 1.0 (opaque)
+    ///
+    /// # Returns
+    ///
+    /// The color with the new alpha value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    /// use mew_css::var;
+    ///
+    /// assert_eq!(Color::Navy.with_alpha(0.8), Color::Rgba(0, 0, 128, 0.8));
+    /// assert_eq!(
+    ///     Color::Var(var("primary")).with_alpha(0.8).to_string(),
+    ///     "rgb(from var(--primary) r g b / 0.8)"
+    /// );
+    /// ```
+    pub fn with_alpha(&self, alpha: f32) -> Color {
+        let alpha = clamp_alpha(alpha);
+
+        match self.to_rgba() {
+            Some(rgba) => Rgba { a: alpha, ..rgba }.into(),
+            None => RelativeColor::new(ColorSpace::Srgb, self.clone(), ["r", "g", "b"])
+                .alpha(&alpha.to_string())
+                .into(),
+        }
+    }
+
+    /// Inverts the red, green and blue channels of the color, keeping its alpha value.
+    ///
+    /// # Returns
+    ///
+    /// The inverted color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    /// use mew_css::var;
+    ///
+    /// assert_eq!(Color::Rgba(255, 200, 0, 0.5).invert(), Color::Rgba(0, 55, 255, 0.5));
+    /// assert_eq!(
+    ///     Color::Var(var("text")).invert().to_string(),
+    ///     "rgb(from var(--text) calc(255 - r) calc(255 - g) calc(255 - b))"
+    /// );
+    /// ```
+    pub fn invert(&self) -> Color {
+        match self.to_rgba() {
+            Some(rgba) => Rgba::new(255 - rgba.r, 255 - rgba.g, 255 - rgba.b, rgba.a).into(),
+            None => Color::relative(ColorSpace::Srgb, self.clone(), ["calc(255 - r)", "calc(255 - g)", "calc(255 - b)"]),
+        }
+    }
+}
+
+/// A WCAG conformance level for the contrast between text and its background.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WcagLevel {
+    /// Level AA: a contrast ratio of at least 4.5:1 for normal text and 3:1 for large text
+    AA,
+    /// Level AAA: a contrast ratio of at least 7:1 for normal text and 4.5:1 for large text
+    AAA,
+}
+
+impl WcagLevel {
+    /// Returns the minimum contrast ratio for normal text.
+    pub fn normal_text_ratio(&self) -> f32 {
+        match self {
+            WcagLevel::AA => 4.5,
+            WcagLevel::AAA => 7.0,
+        }
+    }
+
+    /// Returns the minimum contrast ratio for large text (at least 18pt, or 14pt bold).
+    pub fn large_text_ratio(&self) -> f32 {
+        match self {
+            WcagLevel::AA => 3.0,
+            WcagLevel::AAA => 4.5,
+        }
+    }
+}
+
+/// The result of a contrast check between a foreground and a background color.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::color::{ContrastAudit, WcagLevel};
+/// use mew_css::values::Color;
+///
+/// let audit = ContrastAudit::new(&Color::Gray, &Color::White, WcagLevel::AA).unwrap();
+/// assert_eq!(format!("{:.2}", audit.ratio()), "3.95");
+/// assert!(!audit.passes_normal_text());
+/// assert!(audit.passes_large_text());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastAudit {
+    /// The contrast ratio, from 1.0 to 21.0
+    ratio: f32,
+    /// The level the ratio is checked against
+    level: WcagLevel,
+}
+
+impl ContrastAudit {
+    /// Checks the contrast between a foreground and a background color.
+    ///
+    /// # Arguments
+    ///
+    /// * `foreground` - The text color
+    /// * `background` - The background color
+    /// * `level` - The level to check against
+    ///
+    /// # Returns
+    ///
+    /// The result of the check, or `None` if either color cannot be resolved
+    pub fn new(foreground: &Color, background: &Color, level: WcagLevel) -> Option<Self> {
+        Some(Self {
+            ratio: foreground.contrast_ratio(background)?,
+            level,
+        })
+    }
+
+    /// Returns the contrast ratio, from 1.0 to 21.0.
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Returns the level the ratio is checked against.
+    pub fn level(&self) -> WcagLevel {
+        self.level
+    }
+
+    /// Returns `true` if the contrast is enough for normal text at the checked level.
+    pub fn passes_normal_text(&self) -> bool {
+        self.ratio >= self.level.normal_text_ratio()
+    }
+
+    /// Returns `true` if the contrast is enough for large text at the checked level.
+    pub fn passes_large_text(&self) -> bool {
+        self.ratio >= self.level.large_text_ratio()
+    }
+}
+
+/// Converts an sRGB channel between 0.0 and 1.0 to linear light.
+fn linearize(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Composites a translucent color over an opaque one.
+fn composite(top: Rgba, bottom: Rgba) -> Rgba {
+    let (r1, g1, b1) = top.to_unit();
+    let (r2, g2, b2) = bottom.to_unit();
+    let channel = |c1: f32, c2: f32| c1 * top.a + c2 * (1.0 - top.a);
+    Rgba::from_unit(channel(r1, r2), channel(g1, g2), channel(b1, b2), 1.0)
+}
+
+impl Rgba {
+    /// Returns the relative luminance of the color as defined by WCAG, from 0.0 (black)
+    /// to 1.0 (white), ignoring its alpha value.
+    pub fn relative_luminance(&self) -> f32 {
+        let (r, g, b) = self.to_unit();
+        0.2126 * linearize(r) + 0.7152 * linearize(g) + 0.0722 * linearize(b)
+    }
+}
+
+impl Color {
+    /// Returns the relative luminance of the color as defined by WCAG, from 0.0 (black)
+    /// to 1.0 (white), ignoring its alpha value.
+    ///
+    /// # Returns
+    ///
+    /// The relative luminance, or `None` if the color cannot be resolved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::White.relative_luminance(), Some(1.0));
+    /// assert_eq!(Color::Black.relative_luminance(), Some(0.0));
+    /// ```
+    pub fn relative_luminance(&self) -> Option<f32> {
+        self.to_rgba().map(|rgba| rgba.relative_luminance())
+    }
+
+    /// Returns the WCAG contrast ratio between this color, used as the text color,
+    /// and a background color.
+    ///
+    /// A translucent text color is composited over the background, and a translucent
+    /// background over white.
+    ///
+    /// # Arguments
+    ///
+    /// * `background` - The background color
+    ///
+    /// # Returns
+    ///
+    /// The contrast ratio, from 1.0 to 21.0, or `None` if either color cannot be resolved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    ///
+    /// let ratio = Color::Black.contrast_ratio(&Color::White).unwrap();
+    /// assert_eq!(format!("{:.1}", ratio), "21.0");
+    ///
+    /// assert_eq!(Color::Red.contrast_ratio(&Color::Red), Some(1.0));
+    /// ```
+    pub fn contrast_ratio(&self, background: &Color) -> Option<f32> {
+        let white = Rgba::new(255, 255, 255, 1.0);
+        let background = composite(background.to_rgba()?, white);
+        let foreground = composite(self.to_rgba()?, background);
+
+        let (l1, l2) = (foreground.relative_luminance(), background.relative_luminance());
+        Some((l1.max(l2) + 0.05) / (l1.min(l2) + 0.05))
+    }
+}
+
+/// A color as three channels, used by the color space conversions.
+type Vec3 = [f64; 3];
+
+/// Converts linear sRGB to CIE XYZ (D65).
+const SRGB_TO_XYZ: [Vec3; 3] = [
+    [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+    [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+    [0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+];
+
+/// Converts CIE XYZ (D65) to linear sRGB.
+const XYZ_TO_SRGB: [Vec3; 3] = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+    [0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+];
+
+/// Converts linear Display P3 to CIE XYZ (D65).
+const P3_TO_XYZ: [Vec3; 3] = [
+    [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+    [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+    [0.0, 0.04511338185890264, 1.043944368900976],
+];
+
+/// Converts CIE XYZ (D65) to linear Display P3.
+const XYZ_TO_P3: [Vec3; 3] = [
+    [2.493496911941425, -0.9313836179191239, -0.40271078445071684],
+    [-0.8294889695615747, 1.7626640603183463, 0.023624685841943577],
+    [0.03584583024378447, -0.07617238926804182, 0.9568845240076872],
+];
+
+/// Adapts CIE XYZ from the D65 white point to D50 (Bradford method), for CIE Lab.
+const D65_TO_D50: [Vec3; 3] = [
+    [1.0479298208405488, 0.022946793341019088, -0.05019222954313557],
+    [0.029627815688159344, 0.990434484573249, -0.01707382502938514],
+    [-0.009243058152591178, 0.015055144896577895, 0.7518742899580008],
+];
+
+/// Adapts CIE XYZ from the D50 white point to D65.
+const D50_TO_D65: [Vec3; 3] = [
+    [0.9554734527042182, -0.023098536874261423, 0.0632593086610217],
+    [-0.028369706963208136, 1.009995458010663, 0.021041398966943008],
+    [0.012314001688319899, -0.020507696433477912, 1.3303659366080753],
+];
+
+/// The D50 white point in CIE XYZ.
+const D50_WHITE: Vec3 = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+/// Converts CIE XYZ (D65) to the cone responses used by Oklab.
+const XYZ_TO_LMS: [Vec3; 3] = [
+    [0.819022437996703, 0.3619062600528904, -0.1288737815209879],
+    [0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+    [0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+
+/// Converts the cone responses used by Oklab to CIE XYZ (D65).
+const LMS_TO_XYZ: [Vec3; 3] = [
+    [1.2268798758459243, -0.5578149944602171, 0.2813910456659647],
+    [-0.0405757452148008, 1.112286803280317, -0.0717110580655164],
+    [-0.0763729366746601, -0.4214933324022432, 1.5869240198367816],
+];
+
+/// Converts the non-linear cone responses to Oklab.
+const LMS_TO_OKLAB: [Vec3; 3] = [
+    [0.210454268309314, 0.7936177747023054, -0.0040720430116193],
+    [1.9779985324311684, -2.42859224204858, 0.450593709617411],
+    [0.0259040424655478, 0.7827717124575296, -0.8086757548809269],
+];
+
+/// Converts Oklab to the non-linear cone responses.
+const OKLAB_TO_LMS: [Vec3; 3] = [
+    [1.0, 0.3963377773761749, 0.2158037573099136],
+    [1.0, -0.1055613458156586, -0.0638541728258133],
+    [1.0, -0.0894841775298119, -1.2914855480194092],
+];
+
+fn multiply(matrix: &[Vec3; 3], v: Vec3) -> Vec3 {
+    matrix.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+/// Removes the sRGB transfer function (also used by Display P3), keeping the sign of
+/// out-of-gamut values.
+fn to_linear(v: Vec3) -> Vec3 {
+    v.map(|c| {
+        let abs = c.abs();
+        if abs <= 0.04045 { c / 12.92 } else { c.signum() * ((abs + 0.055) / 1.055).powf(2.4) }
+    })
+}
+
+/// Applies the sRGB transfer function (also used by Display P3).
+fn to_gamma(v: Vec3) -> Vec3 {
+    v.map(|c| {
+        let abs = c.abs();
+        if abs <= 0.0031308 { c * 12.92 } else { c.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055) }
+    })
+}
+
+fn xyz_to_lab(xyz: Vec3) -> Vec3 {
+    const EPSILON: f64 = 216.0 / 24389.0;
+    const KAPPA: f64 = 24389.0 / 27.0;
+
+    let d50 = multiply(&D65_TO_D50, xyz);
+    let [fx, fy, fz] = [0, 1, 2].map(|i| {
+        let v = d50[i] / D50_WHITE[i];
+        if v > EPSILON { v.cbrt() } else { (KAPPA * v + 16.0) / 116.0 }
+    });
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+fn lab_to_xyz([l, a, b]: Vec3) -> Vec3 {
+    const EPSILON: f64 = 216.0 / 24389.0;
+    const KAPPA: f64 = 24389.0 / 27.0;
+
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+    let inverse = |f: f64| if f.powi(3) > EPSILON { f.powi(3) } else { (116.0 * f - 16.0) / KAPPA };
+    let y = if l > KAPPA * EPSILON { fy.powi(3) } else { l / KAPPA };
+
+    let d50 = [inverse(fx) * D50_WHITE[0], y * D50_WHITE[1], inverse(fz) * D50_WHITE[2]];
+    multiply(&D50_TO_D65, d50)
+}
+
+fn xyz_to_oklab(xyz: Vec3) -> Vec3 {
+    multiply(&LMS_TO_OKLAB, multiply(&XYZ_TO_LMS, xyz).map(f64::cbrt))
+}
+
+fn oklab_to_xyz(oklab: Vec3) -> Vec3 {
+    multiply(&LMS_TO_XYZ, multiply(&OKLAB_TO_LMS, oklab).map(|c| c.powi(3)))
+}
+
+/// Converts rectangular coordinates (Lab, Oklab) to polar ones (LCH, Oklch).
+fn to_polar([l, a, b]: Vec3) -> Vec3 {
+    let chroma = (a * a + b * b).sqrt();
+    // The hue of a gray is meaningless, and written as zero
+    let hue = if chroma < 1e-6 { 0.0 } else { b.atan2(a).to_degrees().rem_euclid(360.0) };
+    [l, chroma, hue]
+}
+
+/// Converts polar coordinates (LCH, Oklch) to rectangular ones (Lab, Oklab).
+fn to_rectangular([l, c, h]: Vec3) -> Vec3 {
+    let h = h.to_radians();
+    [l, c * h.cos(), c * h.sin()]
+}
+
+fn hwb_to_srgb(h: f64, w: f64, b: f64) -> Vec3 {
+    if w + b >= 1.0 {
+        return [w / (w + b); 3];
+    }
+
+    // The fully saturated color of the hue, as with `hsl(h 100% 50%)`
+    let h = h.rem_euclid(360.0);
+    let channel = |n: f64| {
+        let k = (n + h / 30.0) % 12.0;
+        0.5 - 0.5 * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [channel(0.0), channel(8.0), channel(4.0)].map(|c| c * (1.0 - w - b) + w)
+}
+
+fn srgb_to_hwb([r, g, b]: Vec3) -> Vec3 {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    [hue, min, 1.0 - max]
+}
+
+/// An RGB color space with a bounded gamut.
+#[derive(Clone, Copy)]
+enum Gamut {
+    Srgb,
+    DisplayP3,
+}
+
+impl Gamut {
+    /// Converts CIE XYZ (D65) to gamma-encoded channels, which can be out of the 0-1 range.
+    fn encode(self, xyz: Vec3) -> Vec3 {
+        let matrix = match self {
+            Gamut::Srgb => &XYZ_TO_SRGB,
+            Gamut::DisplayP3 => &XYZ_TO_P3,
+        };
+        to_gamma(multiply(matrix, xyz))
+    }
+
+    /// Converts gamma-encoded channels to CIE XYZ (D65).
+    fn decode(self, rgb: Vec3) -> Vec3 {
+        let matrix = match self {
+            Gamut::Srgb => &SRGB_TO_XYZ,
+            Gamut::DisplayP3 => &P3_TO_XYZ,
+        };
+        multiply(matrix, to_linear(rgb))
+    }
+
+    /// Converts a color to gamma-encoded channels within the gamut, reducing its chroma
+    /// in Oklch until it fits, as specified by CSS Color 4.
+    fn map(self, xyz: Vec3) -> Vec3 {
+        const JND: f64 = 0.02;
+        const EPSILON: f64 = 0.0001;
+
+        let in_gamut = |rgb: Vec3| rgb.iter().all(|c| (-EPSILON..=1.0 + EPSILON).contains(c));
+        let clip = |rgb: Vec3| rgb.map(|c| c.clamp(0.0, 1.0));
+        let delta = |a: Vec3, b: Vec3| ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt();
+
+        let rgb = self.encode(xyz);
+        if in_gamut(rgb) {
+            return clip(rgb);
+        }
+
+        let [l, c, h] = to_polar(xyz_to_oklab(xyz));
+        if l >= 1.0 {
+            return [1.0; 3];
+        }
+        if l <= 0.0 {
+            return [0.0; 3];
+        }
+
+        let oklab = |chroma: f64| to_rectangular([l, chroma, h]);
+        let clipped_delta = |chroma: f64| {
+            let clipped = clip(self.encode(oklab_to_xyz(oklab(chroma))));
+            (clipped, delta(xyz_to_oklab(self.decode(clipped)), oklab(chroma)))
+        };
+
+        let (mut clipped, error) = clipped_delta(c);
+        if error < JND {
+            return clipped;
+        }
+
+        let (mut min, mut max) = (0.0, c);
+        let mut min_in_gamut = true;
+        while max - min > EPSILON {
+            let chroma = (min + max) / 2.0;
+            if min_in_gamut && in_gamut(self.encode(oklab_to_xyz(oklab(chroma)))) {
+                min = chroma;
+                continue;
+            }
+
+            let (candidate, error) = clipped_delta(chroma);
+            clipped = candidate;
+            if error < JND {
+                if JND - error < EPSILON {
+                    return clipped;
+                }
+                min_in_gamut = false;
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+        clipped
+    }
+}
+
+/// Rounds a component to the given number of decimals, avoiding `-0`.
+fn round(value: f64, decimals: i32) -> f32 {
+    let factor = 10f64.powi(decimals);
+    let rounded = (value * factor).round() / factor;
+    if rounded == 0.0 { 0.0 } else { rounded as f32 }
+}
+
+/// Rounds a hue to the given number of decimals, wrapping a hue rounded up to 360 back to 0.
+fn round_hue(value: f64, decimals: i32) -> f32 {
+    let rounded = round(value, decimals);
+    if rounded >= 360.0 { 0.0 } else { rounded }
+}
+
+impl Color {
+    /// Converts the color to CIE XYZ (D65), along with its alpha value.
+    fn to_xyz(&self) -> Option<(Vec3, f32)> {
+        let v = |a: &f32, b: &f32, c: &f32| [*a as f64, *b as f64, *c as f64];
+
+        let xyz = match self {
+            Color::Hwb(h, w, b, _) => Gamut::Srgb.decode(hwb_to_srgb(*h as f64, *w as f64 / 100.0, *b as f64 / 100.0)),
+            Color::Lab(l, a, b, _) => lab_to_xyz(v(l, a, b)),
+            Color::Lch(l, c, h, _) => lab_to_xyz(to_rectangular(v(l, c, h))),
+            Color::Oklab(l, a, b, _) => oklab_to_xyz(v(l, a, b)),
+            Color::Oklch(l, c, h, _) => oklab_to_xyz(to_rectangular(v(l, c, h))),
+            Color::Srgb(r, g, b, _) => Gamut::Srgb.decode(v(r, g, b)),
+            Color::DisplayP3(r, g, b, _) => Gamut::DisplayP3.decode(v(r, g, b)),
+            _ => {
+                let rgba = self.to_rgba()?;
+                let (r, g, b) = rgba.to_unit();
+                return Some((Gamut::Srgb.decode(v(&r, &g, &b)), rgba.a));
+            }
+        };
+
+        match self {
+            Color::Hwb(.., a)
+            | Color::Lab(.., a)
+            | Color::Lch(.., a)
+            | Color::Oklab(.., a)
+            | Color::Oklch(.., a)
+            | Color::Srgb(.., a)
+            | Color::DisplayP3(.., a) => Some((xyz, clamp_alpha(*a))),
+            _ => None,
+        }
+    }
+
+    /// Converts a color of a modern color space to sRGB, mapping it into the sRGB gamut.
+    fn modern_to_rgba(&self) -> Option<Rgba> {
+        let (xyz, alpha) = self.to_xyz()?;
+        let [r, g, b] = Gamut::Srgb.map(xyz);
+        Some(Rgba::from_unit(r as f32, g as f32, b as f32, alpha))
+    }
+
+    /// Converts the color to another color space.
+    ///
+    /// Colors converted to a space with a bounded gamut (`Srgb`, `DisplayP3`, `Hsl` and
+    /// `Hwb`) are mapped into that gamut by reducing their Oklch chroma, as browsers do.
+    /// Colors converted to `Lab`, `Lch`, `Oklab` and `Oklch` keep their exact value.
+    /// The components are rounded to keep the CSS output short.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - The color space to convert to
+    ///
+    /// # Returns
+    ///
+    /// The converted color, or `None` if the color cannot be resolved (see `to_rgba`) or
+    /// if the space has no color variant (`SrgbLinear` and `Xyz`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::ColorSpace;
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::Red.to_space(ColorSpace::Oklch), Some(Color::Oklch(0.628, 0.2577, 29.23, 1.0)));
+    /// assert_eq!(Color::Red.to_space(ColorSpace::Lab), Some(Color::Lab(54.29, 80.8, 69.89, 1.0)));
+    ///
+    /// // Display P3 red is outside of the sRGB gamut
+    /// let p3_red = Color::DisplayP3(1.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(p3_red.to_space(ColorSpace::Srgb), Some(Color::Srgb(1.0, 0.0446, 0.0459, 1.0)));
+    /// assert_eq!(p3_red.to_hex(), Some("#ff0b0c".to_string()));
+    /// ```
+    pub fn to_space(&self, space: ColorSpace) -> Option<Color> {
+        let (xyz, alpha) = self.to_xyz()?;
+
+        let color = match space {
+            ColorSpace::Srgb => {
+                let [r, g, b] = Gamut::Srgb.map(xyz);
+                Color::Srgb(round(r, 4), round(g, 4), round(b, 4), alpha)
+            }
+            ColorSpace::DisplayP3 => {
+                let [r, g, b] = Gamut::DisplayP3.map(xyz);
+                Color::DisplayP3(round(r, 4), round(g, 4), round(b, 4), alpha)
+            }
+            ColorSpace::Hsl => {
+                let [r, g, b] = Gamut::Srgb.map(xyz);
+                return Color::from(Rgba::from_unit(r as f32, g as f32, b as f32, alpha)).to_hsl();
+            }
+            ColorSpace::Hwb => {
+                let [h, w, b] = srgb_to_hwb(Gamut::Srgb.map(xyz));
+                Color::Hwb(round_hue(h, 2), round(w * 100.0, 2), round(b * 100.0, 2), alpha)
+            }
+            ColorSpace::Lab => {
+                let [l, a, b] = xyz_to_lab(xyz);
+                Color::Lab(round(l, 2), round(a, 2), round(b, 2), alpha)
+            }
+            ColorSpace::Lch => {
+                let [l, c, h] = to_polar(xyz_to_lab(xyz));
+                Color::Lch(round(l, 2), round(c, 2), round_hue(h, 2), alpha)
+            }
+            ColorSpace::Oklab => {
+                let [l, a, b] = xyz_to_oklab(xyz);
+                Color::Oklab(round(l, 4), round(a, 4), round(b, 4), alpha)
+            }
+            ColorSpace::Oklch => {
+                let [l, c, h] = to_polar(xyz_to_oklab(xyz));
+                Color::Oklch(round(l, 4), round(c, 4), round_hue(h, 2), alpha)
+            }
+            ColorSpace::SrgbLinear | ColorSpace::Xyz => return None,
+        };
+        Some(color)
+    }
+
+    /// Returns `true` if the color can be displayed in sRGB without gamut mapping.
+    ///
+    /// # Returns
+    ///
+    /// Whether the color is in the sRGB gamut, or `None` if the color cannot be resolved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::Oklch(0.7, 0.1, 150.0, 1.0).is_in_srgb_gamut(), Some(true));
+    /// assert_eq!(Color::Oklch(0.7, 0.3, 150.0, 1.0).is_in_srgb_gamut(), Some(false));
+    /// ```
+    pub fn is_in_srgb_gamut(&self) -> Option<bool> {
+        let (xyz, _) = self.to_xyz()?;
+        Some(Gamut::Srgb.encode(xyz).iter().all(|c| (-0.0001..=1.0001).contains(c)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variable::var;
+
+    #[test]
+    fn test_named_colors() {
+        assert_eq!(Color::Black.to_hex(), Some("#000000".to_string()));
+        assert_eq!(Color::White.to_hex(), Some("#ffffff".to_string()));
+        assert_eq!(Color::LightGoldenRodYellow.to_hex(), Some("#fafad2".to_string()));
+        assert_eq!(Color::MediumAquaMarine.to_hex(), Some("#66cdaa".to_string()));
+        assert_eq!(Color::SlateGrey.to_rgba(), Color::SlateGray.to_rgba());
+        assert_eq!(Color::Transparent.to_rgba(), Some(Rgba::new(0, 0, 0, 0.0)));
+    }
+
+    #[test]
+    fn test_unresolvable_colors() {
+        assert_eq!(Color::Var(var("brand")).to_rgba(), None);
+        assert_eq!(Color::CurrentColor.to_hex(), None);
+        assert_eq!(Color::Inherit.to_hsl(), None);
+        assert_eq!(Color::Hex("#12345".to_string()).to_rgba(), None);
+    }
+
+    #[test]
+    fn test_hex_colors() {
+        assert_eq!(Color::Hex("f33".to_string()).to_rgba(), Some(Rgba::new(255, 51, 51, 1.0)));
+        assert_eq!(Color::Hex("#FF000080".to_string()).to_hex(), Some("#ff000080".to_string()));
+    }
+
+    #[test]
+    fn test_hsl_round_trips() {
+        for (h, s, l) in [(0, 0, 0), (0, 0, 100), (210, 50, 40), (45, 90, 60), (300, 50, 75)] {
+            let rgba = Color::Hsl(h, s, l).to_rgba().unwrap();
+            assert_eq!(Color::from(rgba).to_hsl(), Some(Color::Hsl(h, s, l)), "hsl({}, {}%, {}%)", h, s, l);
+        }
+
+        assert_eq!(Rgba::from_hsl(-120.0, 100.0, 50.0, 1.0), Rgba::new(0, 0, 255, 1.0));
+        assert_eq!(Color::Gray.to_hsl(), Some(Color::Hsl(0, 0, 50)));
+    }
+
+    #[test]
+    fn test_from_rgba() {
+        assert_eq!(Color::from(Rgba::new(1, 2, 3, 1.0)), Color::Rgb(1, 2, 3));
+        assert_eq!(Color::from(Rgba::new(1, 2, 3, 0.4)), Color::Rgba(1, 2, 3, 0.4));
+        assert_eq!(Rgba::new(0, 0, 0, 7.0).a, 1.0);
+    }
+
+    #[test]
+    fn test_lightness_and_saturation() {
+        assert_eq!(Color::Hsl(210, 50, 40).lighten(20.0).to_hsl(), Some(Color::Hsl(210, 50, 60)));
+        assert_eq!(Color::Hsl(210, 50, 40).darken(100.0), Color::Rgb(0, 0, 0));
+        assert_eq!(Color::Hsl(210, 50, 40).saturate(30.0).to_hsl(), Some(Color::Hsl(210, 80, 40)));
+        assert_eq!(Color::Hsla(0, 100, 50, 0.5).lighten(50.0), Color::Rgba(255, 255, 255, 0.5));
+
+        assert_eq!(
+            Color::CurrentColor.desaturate(25.0).to_string(),
+            "hsl(from currentColor h calc(s - 25) l)"
+        );
+        assert_eq!(Color::Var(var("a")).grayscale().to_string(), "hsl(from var(--a) h 0 l)");
+    }
+
+    #[test]
+    fn test_mix() {
+        assert_eq!(Color::White.mix(&Color::Black, 100.0), Color::Rgb(255, 255, 255));
+        assert_eq!(Color::White.mix(&Color::Black, 25.0), Color::Rgb(64, 64, 64));
+        // Premultiplied alpha: a transparent color does not darken the other one
+        assert_eq!(Color::Red.mix(&Color::Transparent, 50.0), Color::Rgba(255, 0, 0, 0.5));
+
+        assert_eq!(
+            Color::Red.mix(&Color::Var(var("b")), 150.0).to_string(),
+            "color-mix(in srgb, red 100%, var(--b))"
+        );
+    }
+
+    #[test]
+    fn test_alpha_and_invert() {
+        assert_eq!(Color::Rgba(10, 20, 30, 0.2).with_alpha(1.0), Color::Rgb(10, 20, 30));
+        assert_eq!(Color::Black.invert(), Color::Rgb(255, 255, 255));
+        assert_eq!(
+            Color::Var(var("a")).lighten(5.0).with_alpha(0.5).to_string(),
+            "rgb(from hsl(from var(--a) h s calc(l + 5)) r g b / 0.5)"
+        );
+    }
+
+    #[test]
+    fn test_relative_color_spaces() {
+        let relative = |space| RelativeColor::new(space, Color::Red, ["a", "b", "c"]).alpha("0.5").to_string();
+
+        assert_eq!(relative(ColorSpace::Oklch), "oklch(from red a b c / 0.5)");
+        assert_eq!(relative(ColorSpace::DisplayP3), "color(from red display-p3 a b c / 0.5)");
+    }
+
+    #[test]
+    fn test_relative_color_validation() {
+        let check = |space, channels: [&str; 3]| RelativeColor::new(space, Color::Red, channels).validate();
+
+        assert!(check(ColorSpace::Srgb, ["r", "g", "b"]).is_ok());
+        assert!(check(ColorSpace::Lch, ["calc(l + 10)", "C", "calc(h + 180deg)"]).is_ok());
+        assert!(check(ColorSpace::Oklab, ["0.5", "calc(-1 * a)", "none"]).is_ok());
+        assert!(check(ColorSpace::Hsl, ["calc(h + pi * 1rad)", "s", "var(--lightness, fifty)"]).is_ok());
+        assert!(check(ColorSpace::Xyz, ["x", "min(y, 0.5)", "z"]).is_ok());
+
+        assert_eq!(
+            check(ColorSpace::Oklch, ["l", "c", "hue"]),
+            Err(MewError::InvalidValue(ParseValueError::new("RelativeColor", "hue")))
+        );
+        assert!(check(ColorSpace::Srgb, ["h", "s", "l"]).is_err());
+        assert!(check(ColorSpace::Hwb, ["h", "w", ""]).is_err());
+        assert!(RelativeColor::new(ColorSpace::Srgb, Color::Red, ["r", "g", "b"]).alpha("opacity").validate().is_err());
+        assert!(RelativeColor::try_new(ColorSpace::Hsl, Color::Hex("#zz".to_string()), ["h", "s", "l"]).is_err());
+
+        // Invalid relative colors are reported by `Color::validate`, and are not parsed
+        assert!(Color::relative(ColorSpace::Oklch, Color::Red, ["l", "c", "hh"]).validate().is_err());
+        assert!(Color::parse("oklch(from red l c hh)").is_err());
+        assert!(Color::parse("oklch(from red l c calc(h + 90))").is_ok());
+    }
+
+    #[test]
+    fn test_color_mix_hue_interpolation() {
+        let mix = ColorMix::new(ColorSpace::Hsl, Color::Red, Color::Blue).hue(HueInterpolation::Increasing);
+        assert_eq!(mix.to_string(), "color-mix(in hsl increasing hue, red, blue)");
+        assert_eq!(Color::parse(&mix.to_string()), Ok(Color::from(mix)));
+
+        let mix = ColorMix::new(ColorSpace::Srgb, Color::Red, Color::Blue).hue(HueInterpolation::Shorter);
+        assert!(Color::from(mix).validate().is_err());
+        assert!(Color::parse("color-mix(in srgb shorter hue, red, blue)").is_err());
+        assert!(Color::parse("color-mix(in oklch wider hue, red, blue)").is_err());
+
+        assert_eq!("Decreasing  Hue".parse(), Ok(HueInterpolation::Decreasing));
+    }
+
+    #[test]
+    fn test_css_functions() {
+        let brand = || Color::Var(var("brand"));
+
+        // The functions are written as given, even if their colors can be resolved
+        let mix = Color::color_mix(ColorSpace::Oklab, Color::Red, 30.0, Color::Blue);
+        assert_eq!(mix.to_string(), "color-mix(in oklab, red 30%, blue)");
+        assert_eq!(mix.to_rgba(), None);
+
+        let mix = ColorMix::new(ColorSpace::Hsl, brand(), Color::White).second_percentage(25.0);
+        assert_eq!(Color::from(mix).to_string(), "color-mix(in hsl, var(--brand), white 25%)");
+
+        let relative = RelativeColor::new(ColorSpace::Lch, brand(), ["l", "calc(c * 1.2)", "h"]).alpha("calc(alpha / 2)");
+        assert_eq!(Color::from(relative).to_string(), "lch(from var(--brand) l calc(c * 1.2) h / calc(alpha / 2))");
+
+        let theme = Color::light_dark(Color::relative(ColorSpace::Oklch, brand(), ["calc(l - 0.2)", "c", "h"]), brand());
+        assert_eq!(theme.to_string(), "light-dark(oklch(from var(--brand) calc(l - 0.2) c h), var(--brand))");
+        assert_eq!(theme.to_hex(), None);
+
+        // Nested colors are validated
+        assert!(Color::light_dark(Color::White, Color::Rgba(0, 0, 0, 2.0)).validate().is_err());
+        assert!(Color::color_mix(ColorSpace::Srgb, Color::Hex("#zz".to_string()), 50.0, brand()).validate().is_err());
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let ratio = |fg: Color, bg: Color| (fg.contrast_ratio(&bg).unwrap() * 100.0).round() / 100.0;
+
+        assert_eq!(ratio(Color::White, Color::Black), 21.0);
+        assert_eq!(ratio(Color::Black, Color::White), 21.0);
+        assert_eq!(ratio(Color::Hex("#767676".to_string()), Color::White), 4.54);
+        assert_eq!(ratio(Color::Blue, Color::White), 8.59);
+        // Translucent text is composited over the background
+        assert_eq!(ratio(Color::Rgba(0, 0, 0, 0.5), Color::White), 3.95);
+        // Translucent backgrounds are composited over white
+        assert_eq!(ratio(Color::White, Color::Transparent), 1.0);
+
+        assert_eq!(Color::Var(var("text")).contrast_ratio(&Color::White), None);
+        assert_eq!(Color::White.contrast_ratio(&Color::CurrentColor), None);
+    }
+
+    #[test]
+    fn test_contrast_audit() {
+        let audit = ContrastAudit::new(&Color::Hex("#767676".to_string()), &Color::White, WcagLevel::AA).unwrap();
+        assert!(audit.passes_normal_text());
+        assert!(audit.passes_large_text());
+
+        let audit = ContrastAudit::new(&Color::Hex("#767676".to_string()), &Color::White, WcagLevel::AAA).unwrap();
+        assert_eq!(audit.level(), WcagLevel::AAA);
+        assert!(!audit.passes_normal_text());
+        assert!(audit.passes_large_text());
+    }
+
+    #[test]
+    fn test_modern_space_conversions() {
+        assert_eq!(Color::Red.to_space(ColorSpace::Oklab), Some(Color::Oklab(0.628, 0.2249, 0.1258, 1.0)));
+        assert_eq!(Color::Red.to_space(ColorSpace::Lch), Some(Color::Lch(54.29, 106.84, 40.86, 1.0)));
+        assert_eq!(Color::Rgb(0, 255, 0).to_space(ColorSpace::Hwb), Some(Color::Hwb(120.0, 0.0, 0.0, 1.0)));
+        assert_eq!(Color::Gray.to_space(ColorSpace::Oklch), Some(Color::Oklch(0.5999, 0.0, 0.0, 1.0)));
+        assert_eq!(Color::Rgba(0, 0, 255, 0.5).to_space(ColorSpace::Srgb), Some(Color::Srgb(0.0, 0.0, 1.0, 0.5)));
+        assert_eq!(Color::White.to_space(ColorSpace::Xyz), None);
+        assert_eq!(Color::Var(var("brand")).to_space(ColorSpace::Oklch), None);
+    }
+
+    #[test]
+    fn test_hwb_primary_colors() {
+        let hwb = |color: Color| color.to_space(ColorSpace::Hwb).unwrap().to_string();
+        assert_eq!(hwb(Color::Rgb(255, 0, 0)), "hwb(0 0% 0%)");
+        assert_eq!(hwb(Color::Rgb(0, 255, 0)), "hwb(120 0% 0%)");
+        assert_eq!(hwb(Color::Rgb(0, 0, 255)), "hwb(240 0% 0%)");
+        assert_eq!(hwb(Color::Rgb(255, 0, 255)), "hwb(300 0% 0%)");
+        assert_eq!(hwb(Color::Hex("#ff0001".to_string())), "hwb(359.76 0% 0%)");
+    }
+
+    #[test]
+    fn test_modern_space_round_trips() {
+        let colors = [
+            Color::Oklch(0.7, 0.1, 150.0, 1.0),
+            Color::Oklab(0.4, -0.05, 0.08, 0.5),
+            Color::Lab(60.0, 20.0, -30.0, 1.0),
+            Color::Lch(75.0, 30.0, 90.0, 1.0),
+            Color::Hwb(200.0, 10.0, 30.0, 1.0),
+            Color::Srgb(0.2, 0.4, 0.6, 1.0),
+        ];
+        for color in colors {
+            let rgba = color.to_rgba().unwrap();
+            let back = color.to_space(ColorSpace::Srgb).unwrap().to_rgba().unwrap();
+            assert_eq!(rgba, back, "{}", color);
+        }
+
+        assert_eq!(Color::Hwb(0.0, 60.0, 60.0, 1.0).to_hex(), Some("#808080".to_string()));
+        assert_eq!(Color::Lab(100.0, 0.0, 0.0, 1.0).to_hex(), Some("#ffffff".to_string()));
+        assert_eq!(Color::Oklch(0.0, 0.0, 0.0, 1.0).to_hex(), Some("#000000".to_string()));
+    }
+
+    #[test]
+    fn test_gamut_mapping() {
+        let vivid_green = Color::Oklch(0.8, 0.35, 145.0, 1.0);
+        assert_eq!(vivid_green.is_in_srgb_gamut(), Some(false));
+
+        // The chroma is reduced while the lightness and hue are kept
+        let Some(Color::Oklch(l, c, h, _)) = vivid_green.to_space(ColorSpace::Srgb).unwrap().to_space(ColorSpace::Oklch)
+        else {
+            panic!("expected an oklch color");
+        };
+        assert!((l - 0.8).abs() < 0.02, "lightness {}", l);
+        assert!(c < 0.35, "chroma {}", c);
+        assert!((h - 145.0).abs() < 3.0, "hue {}", h);
+
+        // Display P3 colors within the sRGB gamut are kept
+        let p3 = Color::Rgb(51, 102, 153).to_space(ColorSpace::DisplayP3).unwrap();
+        assert_eq!(p3.is_in_srgb_gamut(), Some(true));
+        assert_eq!(p3.to_hex(), Some("#336699".to_string()));
+
+        assert_eq!(Color::Oklch(1.2, 0.1, 30.0, 1.0).to_hex(), Some("#ffffff".to_string()));
+    }
+
+}
@@ -0,0 +1,621 @@
+//! # Container Query Module
+//!
+//! This module provides the `ContainerQuery` type, a typed builder for the conditions used
+//! by `@container` rules, and `ContainerPrelude`, which pairs a condition with the optional
+//! name of the container it targets. Container queries test the size of the nearest query container
+//! (an ancestor with a `container-type`), or the value of a custom property on it with
+//! style queries, and can be combined with `and`, `or` and `not`.
+//!
+//! Size queries reuse the `Size` type and the `Comparison` operators of the `media` module,
+//! and style queries reference custom properties through `CssVar`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::container::ContainerQuery;
+//! use mew_css::media::Comparison;
+//! use mew_css::values::Size;
+//! use mew_css::var;
+//!
+//! let query = ContainerQuery::min_inline_size(Size::Px(400.0));
+//! assert_eq!(query.to_string(), "(min-inline-size: 400px)");
+//!
+//! let query = ContainerQuery::aspect_ratio(Comparison::Ge, 16, 9)
+//!     .and(ContainerQuery::style(var("variant"), "compact"));
+//! assert_eq!(query.to_string(), "(aspect-ratio >= 16 / 9) and style(--variant: compact)");
+//!
+//! let prelude = ContainerQuery::inline_size(Comparison::Gt, Size::Px(600.0)).named("sidebar");
+//! assert_eq!(prelude.to_string(), "sidebar (inline-size > 600px)");
+//! ```
+//!
+//! Container queries are usually attached to a style with `Style::container`:
+//!
+//! ```rust
+//! use mew_css::{rule, style};
+//! use mew_css::container::ContainerQuery;
+//! use mew_css::values::{Display, Size};
+//!
+//! let css = rule(".card", style()
+//!     .display(Display::Block)
+//!     .container(ContainerQuery::min_width(Size::Px(500.0)), |s| s.display(Display::Flex)))
+//!     .to_string();
+//!
+//! assert_eq!(css, ".card { display: block; } \
+//!                  @container (min-width: 500px) { .card { display: flex; } }");
+//! ```
+
+use crate::error::MewError;
+use crate::media::{Comparison, Orientation};
+use crate::values::{ParseValueError, Size};
+use crate::variable::CssVar;
+use std::fmt;
+use std::ops::Not;
+
+/// Size features of a query container.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SizeFeature {
+    /// The width of the container's content box
+    Width,
+    /// The height of the container's content box
+    Height,
+    /// The size of the container's content box in the inline axis
+    InlineSize,
+    /// The size of the container's content box in the block axis
+    BlockSize,
+}
+
+impl fmt::Display for SizeFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SizeFeature::Width => write!(f, "width"),
+            SizeFeature::Height => write!(f, "height"),
+            SizeFeature::InlineSize => write!(f, "inline-size"),
+            SizeFeature::BlockSize => write!(f, "block-size"),
+        }
+    }
+}
+
+/// A single container feature test, rendered inside parentheses.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContainerFeature {
+    /// Minimum value of a size feature (e.g., `min-inline-size`)
+    Min(SizeFeature, Size),
+    /// Maximum value of a size feature (e.g., `max-width`)
+    Max(SizeFeature, Size),
+    /// Range syntax test (e.g., `inline-size >= 600px` or `400px <= width <= 700px`)
+    Range {
+        /// The feature being compared
+        feature: SizeFeature,
+        /// Value and operator written before the feature name
+        lower: Option<(Size, Comparison)>,
+        /// Operator and value written after the feature name
+        upper: Option<(Comparison, Size)>,
+    },
+    /// Comparison of the container's aspect ratio with a `width / height` ratio
+    AspectRatio(Comparison, u32, u32),
+    /// Orientation of the container (`orientation`)
+    Orientation(Orientation),
+    /// Any other feature, written as is without the surrounding parentheses
+    Custom(String),
+}
+
+impl fmt::Display for ContainerFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContainerFeature::Min(feature, size) => write!(f, "(min-{}: {})", feature, size),
+            ContainerFeature::Max(feature, size) => write!(f, "(max-{}: {})", feature, size),
+            ContainerFeature::Range { feature, lower, upper } => {
+                write!(f, "(")?;
+                if let Some((value, op)) = lower {
+                    write!(f, "{} {} ", value, op)?;
+                }
+                write!(f, "{}", feature)?;
+                if let Some((op, value)) = upper {
+                    write!(f, " {} {}", op, value)?;
+                }
+                write!(f, ")")
+            }
+            ContainerFeature::AspectRatio(op, width, height) => {
+                write!(f, "(aspect-ratio {} {} / {})", op, width, height)
+            }
+            ContainerFeature::Orientation(value) => write!(f, "(orientation: {})", value),
+            ContainerFeature::Custom(value) => write!(f, "({})", value),
+        }
+    }
+}
+
+/// A container query, composed of size features and style queries.
+///
+/// Container queries are built with the constructor functions (`min_inline_size`,
+/// `style`, ...) and combined with `and`, `or` and `!` (negation). When rendered,
+/// parentheses are added where the grammar requires them.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::container::ContainerQuery;
+/// use mew_css::values::Size;
+///
+/// let query = !ContainerQuery::min_width(Size::Px(300.0));
+/// assert_eq!(query.to_string(), "not (min-width: 300px)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContainerQuery {
+    /// A size feature test such as `(min-inline-size: 400px)`
+    Feature(ContainerFeature),
+    /// A style query testing the computed value of a custom property on the container
+    Style(CssVar, String),
+    /// All the queries must match
+    And(Vec<ContainerQuery>),
+    /// At least one of the queries must match
+    Or(Vec<ContainerQuery>),
+    /// The query must not match
+    Not(Box<ContainerQuery>),
+}
+
+impl ContainerQuery {
+    /// Creates a query matching containers at least as wide as `size`.
+    pub fn min_width(size: Size) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Min(SizeFeature::Width, size))
+    }
+
+    /// Creates a query matching containers at most as wide as `size`.
+    pub fn max_width(size: Size) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Max(SizeFeature::Width, size))
+    }
+
+    /// Creates a query matching containers at least as tall as `size`.
+    pub fn min_height(size: Size) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Min(SizeFeature::Height, size))
+    }
+
+    /// Creates a query matching containers at most as tall as `size`.
+    pub fn max_height(size: Size) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Max(SizeFeature::Height, size))
+    }
+
+    /// Creates a query matching containers whose inline size is at least `size`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::container::ContainerQuery;
+    /// use mew_css::values::Size;
+    ///
+    /// let query = ContainerQuery::min_inline_size(Size::Rem(30.0));
+    /// assert_eq!(query.to_string(), "(min-inline-size: 30rem)");
+    /// ```
+    pub fn min_inline_size(size: Size) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Min(SizeFeature::InlineSize, size))
+    }
+
+    /// Creates a query matching containers whose inline size is at most `size`.
+    pub fn max_inline_size(size: Size) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Max(SizeFeature::InlineSize, size))
+    }
+
+    /// Creates a query matching containers whose block size is at least `size`.
+    pub fn min_block_size(size: Size) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Min(SizeFeature::BlockSize, size))
+    }
+
+    /// Creates a query matching containers whose block size is at most `size`.
+    pub fn max_block_size(size: Size) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Max(SizeFeature::BlockSize, size))
+    }
+
+    /// Creates a range query comparing the container width to a value.
+    pub fn width(op: Comparison, size: Size) -> Self {
+        ContainerQuery::range(SizeFeature::Width, None, Some((op, size)))
+    }
+
+    /// Creates a range query comparing the container height to a value.
+    pub fn height(op: Comparison, size: Size) -> Self {
+        ContainerQuery::range(SizeFeature::Height, None, Some((op, size)))
+    }
+
+    /// Creates a range query comparing the container inline size to a value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::container::ContainerQuery;
+    /// use mew_css::media::Comparison;
+    /// use mew_css::values::Size;
+    ///
+    /// let query = ContainerQuery::inline_size(Comparison::Lt, Size::Px(320.0));
+    /// assert_eq!(query.to_string(), "(inline-size < 320px)");
+    /// ```
+    pub fn inline_size(op: Comparison, size: Size) -> Self {
+        ContainerQuery::range(SizeFeature::InlineSize, None, Some((op, size)))
+    }
+
+    /// Creates a range query comparing the container block size to a value.
+    pub fn block_size(op: Comparison, size: Size) -> Self {
+        ContainerQuery::range(SizeFeature::BlockSize, None, Some((op, size)))
+    }
+
+    /// Creates a range query matching container inline sizes between `min` and `max`, inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::container::ContainerQuery;
+    /// use mew_css::values::Size;
+    ///
+    /// let query = ContainerQuery::inline_size_between(Size::Px(400.0), Size::Px(700.0));
+    /// assert_eq!(query.to_string(), "(400px <= inline-size <= 700px)");
+    /// ```
+    pub fn inline_size_between(min: Size, max: Size) -> Self {
+        ContainerQuery::range(SizeFeature::InlineSize, Some((min, Comparison::Le)), Some((Comparison::Le, max)))
+    }
+
+    /// Creates a range query with explicit bounds on either side of the feature name.
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - The feature being compared
+    /// * `lower` - Value and operator written before the feature name
+    /// * `upper` - Operator and value written after the feature name
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::container::{ContainerQuery, SizeFeature};
+    /// use mew_css::media::Comparison;
+    /// use mew_css::values::Size;
+    ///
+    /// let query = ContainerQuery::range(
+    ///     SizeFeature::BlockSize,
+    ///     Some((Size::Px(200.0), Comparison::Lt)),
+    ///     None,
+    /// );
+    /// assert_eq!(query.to_string(), "(200px < block-size)");
+    /// ```
+    pub fn range(feature: SizeFeature, lower: Option<(Size, Comparison)>, upper: Option<(Comparison, Size)>) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Range { feature, lower, upper })
+    }
+
+    /// Creates a query comparing the aspect ratio of the container to `width / height`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::container::ContainerQuery;
+    /// use mew_css::media::Comparison;
+    ///
+    /// let query = ContainerQuery::aspect_ratio(Comparison::Gt, 1, 1);
+    /// assert_eq!(query.to_string(), "(aspect-ratio > 1 / 1)");
+    /// ```
+    pub fn aspect_ratio(op: Comparison, width: u32, height: u32) -> Self {
+        ContainerQuery::Feature(ContainerFeature::AspectRatio(op, width, height))
+    }
+
+    /// Creates a query on the orientation of the container.
+    pub fn orientation(value: Orientation) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Orientation(value))
+    }
+
+    /// Creates a style query testing the computed value of a custom property on the container.
+    ///
+    /// # Arguments
+    ///
+    /// * `property` - The custom property to test
+    /// * `value` - The value the custom property must have
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::container::ContainerQuery;
+    /// use mew_css::var;
+    ///
+    /// let query = ContainerQuery::style(var("variant"), "compact");
+    /// assert_eq!(query.to_string(), "style(--variant: compact)");
+    /// ```
+    pub fn style<T: fmt::Display>(property: CssVar, value: T) -> Self {
+        ContainerQuery::Style(property, value.to_string().trim().to_string())
+    }
+
+    /// Creates a query on a feature not covered by the typed constructors.
+    pub fn feature(value: &str) -> Self {
+        ContainerQuery::Feature(ContainerFeature::Custom(value.trim().to_string()))
+    }
+
+    /// Restricts this query to the nearest ancestor container with the given name.
+    ///
+    /// The name belongs to the `@container` prelude rather than to the condition, so
+    /// this returns a `ContainerPrelude`, which can no longer be combined with other queries.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the container, as set by `container-name`
+    ///
+    /// # Returns
+    ///
+    /// A `ContainerPrelude` with the name written before this query
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::container::ContainerQuery;
+    /// use mew_css::values::Size;
+    ///
+    /// let prelude = ContainerQuery::min_width(Size::Px(400.0))
+    ///     .or(ContainerQuery::min_height(Size::Px(400.0)))
+    ///     .named("card");
+    /// assert_eq!(prelude.to_string(), "card (min-width: 400px) or (min-height: 400px)");
+    /// ```
+    pub fn named(self, name: &str) -> ContainerPrelude {
+        ContainerPrelude::new(Some(name), self)
+    }
+
+    /// Combines this query with another one; both must match.
+    pub fn and(self, other: ContainerQuery) -> Self {
+        match self {
+            ContainerQuery::And(mut queries) => {
+                queries.push(other);
+                ContainerQuery::And(queries)
+            }
+            query => ContainerQuery::And(vec![query, other]),
+        }
+    }
+
+    /// Combines this query with another one; at least one must match.
+    pub fn or(self, other: ContainerQuery) -> Self {
+        match self {
+            ContainerQuery::Or(mut queries) => {
+                queries.push(other);
+                ContainerQuery::Or(queries)
+            }
+            query => ContainerQuery::Or(vec![query, other]),
+        }
+    }
+
+    /// Negates this query. This is equivalent to the `!` operator.
+    pub fn negate(self) -> Self {
+        ContainerQuery::Not(Box::new(self))
+    }
+
+    /// Checks that this query can be written as a valid `@container` condition.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the query is valid, or a `MewError` if a custom feature or the value of
+    /// a style query is empty, a size is `auto` or an invalid `calc()` expression, or
+    /// `and`/`or` combine no queries
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::container::ContainerQuery;
+    /// use mew_css::values::Size;
+    ///
+    /// assert!(ContainerQuery::min_width(Size::Px(400.0)).validate().is_ok());
+    /// assert!(ContainerQuery::feature("").validate().is_err());
+    /// assert!(ContainerQuery::Or(vec![]).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), MewError> {
+        let error = || ParseValueError::new("ContainerQuery", &self.to_string()).into();
+        let check_size = |size: &Size| match size {
+            Size::Auto => Err(error()),
+            size => size.validate(),
+        };
+
+        match self {
+            ContainerQuery::Feature(ContainerFeature::Min(_, size) | ContainerFeature::Max(_, size)) => {
+                check_size(size)
+            }
+            ContainerQuery::Feature(ContainerFeature::Range { lower, upper, .. }) => {
+                lower.iter().map(|(size, _)| size).chain(upper.iter().map(|(_, size)| size)).try_for_each(check_size)
+            }
+            ContainerQuery::Feature(ContainerFeature::Custom(value)) | ContainerQuery::Style(_, value)
+                if value.is_empty() =>
+            {
+                Err(error())
+            }
+            ContainerQuery::Feature(_) | ContainerQuery::Style(..) => Ok(()),
+            ContainerQuery::And(queries) | ContainerQuery::Or(queries) if queries.is_empty() => Err(error()),
+            ContainerQuery::And(queries) | ContainerQuery::Or(queries) => {
+                queries.iter().try_for_each(ContainerQuery::validate)
+            }
+            ContainerQuery::Not(query) => query.validate(),
+        }
+    }
+
+    /// Writes this query as an operand of `and`, `or` or `not`, wrapping it in
+    /// parentheses when it is a compound condition.
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContainerQuery::Feature(_) | ContainerQuery::Style(..) => write!(f, "{}", self),
+            _ => write!(f, "({})", self),
+        }
+    }
+}
+
+impl Not for ContainerQuery {
+    type Output = ContainerQuery;
+
+    fn not(self) -> Self::Output {
+        self.negate()
+    }
+}
+
+impl fmt::Display for ContainerQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContainerQuery::Feature(feature) => write!(f, "{}", feature),
+            ContainerQuery::Style(property, value) => write!(f, "style({}: {})", property.name(), value),
+            ContainerQuery::And(queries) | ContainerQuery::Or(queries) => {
+                let separator = if matches!(self, ContainerQuery::And(_)) { " and " } else { " or " };
+                for (i, query) in queries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "{}", separator)?;
+                    }
+                    query.write_operand(f)?;
+                }
+                Ok(())
+            }
+            ContainerQuery::Not(query) => {
+                write!(f, "not ")?;
+                query.write_operand(f)
+            }
+        }
+    }
+}
+
+/// The prelude of a `@container` rule: an optional container name followed by a query.
+///
+/// A `ContainerQuery` converts into a prelude without a name, so the functions that
+/// create `@container` blocks accept both.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::container::{ContainerPrelude, ContainerQuery};
+/// use mew_css::values::Size;
+///
+/// let prelude = ContainerPrelude::new(Some("sidebar"), ContainerQuery::min_width(Size::Px(300.0)));
+/// assert_eq!(prelude.name(), Some("sidebar"));
+/// assert_eq!(prelude.to_string(), "sidebar (min-width: 300px)");
+///
+/// let prelude = ContainerPrelude::from(ContainerQuery::min_width(Size::Px(300.0)));
+/// assert_eq!(prelude.to_string(), "(min-width: 300px)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerPrelude {
+    name: Option<String>,
+    query: ContainerQuery,
+}
+
+impl ContainerPrelude {
+    /// Creates a new prelude from an optional container name and a query.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the container to query, or `None` for the nearest container
+    /// * `query` - The condition the container must match
+    ///
+    /// # Returns
+    ///
+    /// A new `ContainerPrelude` instance
+    pub fn new(name: Option<&str>, query: ContainerQuery) -> Self {
+        let name = name.map(str::trim).filter(|name| !name.is_empty()).map(str::to_string);
+        Self { name, query }
+    }
+
+    /// Returns the name of the queried container, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the condition the container must match.
+    pub fn query(&self) -> &ContainerQuery {
+        &self.query
+    }
+
+    /// Checks that the query of this prelude is valid (see `ContainerQuery::validate`).
+    pub fn validate(&self) -> Result<(), MewError> {
+        self.query.validate()
+    }
+}
+
+// Implement From<ContainerQuery> for ContainerPrelude to query the nearest container
+impl From<ContainerQuery> for ContainerPrelude {
+    fn from(query: ContainerQuery) -> Self {
+        Self { name: None, query }
+    }
+}
+
+// Implement From<&ContainerQuery> for ContainerPrelude to query the nearest container
+impl From<&ContainerQuery> for ContainerPrelude {
+    fn from(query: &ContainerQuery) -> Self {
+        query.clone().into()
+    }
+}
+
+impl fmt::Display for ContainerPrelude {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "{} ", name)?;
+        }
+        write!(f, "{}", self.query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variable::var;
+
+    #[test]
+    fn test_min_max_features() {
+        assert_eq!(ContainerQuery::min_width(Size::Px(400.0)).to_string(), "(min-width: 400px)");
+        assert_eq!(ContainerQuery::max_height(Size::Em(20.0)).to_string(), "(max-height: 20em)");
+        assert_eq!(ContainerQuery::min_inline_size(Size::Px(320.0)).to_string(), "(min-inline-size: 320px)");
+        assert_eq!(ContainerQuery::max_block_size(Size::Vh(50.0)).to_string(), "(max-block-size: 50vh)");
+    }
+
+    #[test]
+    fn test_range_syntax() {
+        assert_eq!(
+            ContainerQuery::width(Comparison::Ge, Size::Px(600.0)).to_string(),
+            "(width >= 600px)"
+        );
+        assert_eq!(
+            ContainerQuery::inline_size_between(Size::Px(300.0), Size::Px(600.0)).to_string(),
+            "(300px <= inline-size <= 600px)"
+        );
+        assert_eq!(
+            ContainerQuery::aspect_ratio(Comparison::Eq, 16, 9).to_string(),
+            "(aspect-ratio = 16 / 9)"
+        );
+        assert_eq!(
+            ContainerQuery::orientation(Orientation::Portrait).to_string(),
+            "(orientation: portrait)"
+        );
+    }
+
+    #[test]
+    fn test_style_query() {
+        let query = ContainerQuery::style(var("--theme"), "dark");
+        assert_eq!(query.to_string(), "style(--theme: dark)");
+
+        let query = !ContainerQuery::style(var("variant"), "compact");
+        assert_eq!(query.to_string(), "not style(--variant: compact)");
+    }
+
+    #[test]
+    fn test_combinations() {
+        let query = ContainerQuery::min_width(Size::Px(400.0))
+            .and(ContainerQuery::style(var("variant"), "compact"))
+            .or(ContainerQuery::min_width(Size::Px(800.0)));
+        assert_eq!(
+            query.to_string(),
+            "((min-width: 400px) and style(--variant: compact)) or (min-width: 800px)"
+        );
+    }
+
+    #[test]
+    fn test_named() {
+        let query = ContainerQuery::min_inline_size(Size::Px(400.0)).named(" sidebar ");
+        assert_eq!(query.to_string(), "sidebar (min-inline-size: 400px)");
+
+        let query = (!ContainerQuery::max_width(Size::Px(200.0))).named("card");
+        assert_eq!(query.to_string(), "card not (max-width: 200px)");
+
+        // The name is written once, before the whole condition
+        let query = ContainerQuery::min_width(Size::Px(100.0))
+            .and(ContainerQuery::max_width(Size::Px(900.0)))
+            .or(!ContainerQuery::style(var("compact"), "true"))
+            .named("card");
+        assert_eq!(query.name(), Some("card"));
+        assert_eq!(
+            query.to_string(),
+            "card ((min-width: 100px) and (max-width: 900px)) or (not style(--compact: true))"
+        );
+
+        let query = ContainerPrelude::new(Some("  "), ContainerQuery::min_width(Size::Px(100.0)));
+        assert_eq!(query.name(), None);
+        assert_eq!(query.to_string(), "(min-width: 100px)");
+    }
+}
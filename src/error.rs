@@ -0,0 +1,187 @@
+//! # Error Module
+//!
+//! This module provides `MewError`, the error returned by the fallible (`try_`)
+//! constructors of value types, and `ValidationIssue`, which describes an invalid
+//! value found in a `Style`.
+//!
+//! Most value types accept any input and render it as given, which can produce invalid
+//! CSS (e.g., `Color::Hex("#zz")`), and `FontWeight::Weight` silently renders `400` for
+//! invalid weights. The `try_` constructors reject such inputs up front, while
+//! `Style::validate` reports the ones that were already added to a style.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::error::MewError;
+//! use mew_css::style;
+//! use mew_css::values::{Color, FontWeight};
+//!
+//! assert_eq!(FontWeight::try_weight(1200), Err(MewError::InvalidFontWeight(1200)));
+//! assert_eq!(Color::try_hex("1e90ff"), Ok(Color::Hex("#1e90ff".to_string())));
+//!
+//! let mut style = style();
+//! style.background_color(Color::Rgba(0, 0, 0, 1.5));
+//!
+//! let issues = style.validate();
+//! assert_eq!(issues.len(), 1);
+//! assert_eq!(issues[0].property(), "background-color");
+//! assert_eq!(issues[0].error(), &MewError::InvalidAlpha(1.5));
+//! ```
+
+use crate::parser::ParseError;
+use crate::values::ParseValueError;
+use std::fmt;
+
+/// An error raised when a value is invalid.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MewError {
+    /// A font weight that is not between 1 and 1000
+    InvalidFontWeight(u16),
+    /// A hex color that is not made of 3, 4, 6 or 8 hexadecimal digits
+    InvalidHex(String),
+    /// An alpha value outside of the 0.0 to 1.0 range
+    InvalidAlpha(f32),
+    /// A media query that cannot be written as a media query list, such as a negated
+    /// media type nested inside a condition
+    InvalidMediaQuery(String),
+    /// A value that could not be parsed
+    InvalidValue(ParseValueError),
+    /// A declaration block that could not be parsed
+    Parse(ParseError),
+}
+
+impl fmt::Display for MewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MewError::InvalidFontWeight(weight) => {
+                write!(f, "invalid font weight {}: expected a number between 1 and 1000", weight)
+            }
+            MewError::InvalidHex(hex) => {
+                write!(f, "invalid hex color \"{}\": expected 3, 4, 6 or 8 hexadecimal digits", hex)
+            }
+            MewError::InvalidAlpha(alpha) => write!(f, "invalid alpha {}: expected a value between 0 and 1", alpha),
+            MewError::InvalidMediaQuery(query) => write!(
+                f,
+                "invalid media query \"{}\": media types and `not` can only be used at the top level",
+                query
+            ),
+            MewError::InvalidValue(error) => write!(f, "{}", error),
+            MewError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for MewError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MewError::InvalidValue(error) => Some(error),
+            MewError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// Implement From<ParseValueError> for MewError to allow the `?` operator
+impl From<ParseValueError> for MewError {
+    fn from(error: ParseValueError) -> Self {
+        MewError::InvalidValue(error)
+    }
+}
+
+// Implement From<ParseError> for MewError to allow the `?` operator
+impl From<ParseError> for MewError {
+    fn from(error: ParseError) -> Self {
+        MewError::Parse(error)
+    }
+}
+
+/// An invalid value found in a style, as returned by `Style::validate`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    /// The name of the property the value was given to
+    property: String,
+    /// The value as it was given, before it is written
+    value: String,
+    /// The reason the value is invalid
+    error: MewError,
+}
+
+impl ValidationIssue {
+    /// Creates a new issue for the given property.
+    ///
+    /// # Arguments
+    ///
+    /// * `property` - The name of the property the value was given to
+    /// * `value` - The value as it was given, before it is written
+    /// * `error` - The reason the value is invalid
+    ///
+    /// # Returns
+    ///
+    /// A new `ValidationIssue` instance
+    pub fn new(property: &str, value: &str, error: MewError) -> Self {
+        Self {
+            property: property.to_string(),
+            value: value.to_string(),
+            error,
+        }
+    }
+
+    /// Returns the name of the property the value was given to.
+    pub fn property(&self) -> &str {
+        &self.property
+    }
+
+    /// Returns the value as it was given, even if the property was not added to the
+    /// style (see `Style::strict`) or the value is rewritten when written (an invalid
+    /// `FontWeight::Weight` is written as `400`).
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the reason the value is invalid.
+    pub fn error(&self) -> &MewError {
+        &self.error
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.property, self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!(
+            MewError::InvalidFontWeight(1200).to_string(),
+            "invalid font weight 1200: expected a number between 1 and 1000"
+        );
+        assert_eq!(
+            MewError::InvalidHex("#12".to_string()).to_string(),
+            "invalid hex color \"#12\": expected 3, 4, 6 or 8 hexadecimal digits"
+        );
+        assert_eq!(MewError::InvalidAlpha(2.0).to_string(), "invalid alpha 2: expected a value between 0 and 1");
+
+        let issue = ValidationIssue::new("font-weight", "1200", MewError::InvalidFontWeight(1200));
+        assert_eq!(issue.value(), "1200");
+        assert_eq!(
+            issue.to_string(),
+            "font-weight: invalid font weight 1200: expected a number between 1 and 1000"
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let error: MewError = "wavy".parse::<crate::values::BorderStyle>().unwrap_err().into();
+        assert_eq!(error.to_string(), "invalid BorderStyle value: \"wavy\"");
+
+        let error: MewError = Style::parse("color red").unwrap_err().into();
+        assert!(matches!(error, MewError::Parse(_)));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+}
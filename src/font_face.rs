@@ -0,0 +1,760 @@
+//! # Font Face Module
+//!
+//! This module provides the `FontFace` type, a builder for `@font-face` at-rules used to
+//! declare self-hosted fonts.
+//!
+//! The `src` descriptor is built from typed `FontSource` values (`url()` with optional
+//! `format()` and `tech()` hints, or `local()`), and the other descriptors reuse the value
+//! types of the library where possible. Weights use `FontFaceWeight`, which accepts any
+//! weight from 1 to 1000 as variable fonts do, and can be converted from the absolute
+//! `FontWeight` values used by `Style::font_weight`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::stylesheet;
+//! use mew_css::font_face::{FontDisplay, FontFace, FontFaceWeight, FontFormat, FontSource, FontTech};
+//!
+//! let mut inter = FontFace::new("Inter");
+//! inter
+//!     .src(FontSource::local("Inter"))
+//!     .src(FontSource::url("/fonts/inter.woff2").format(FontFormat::Woff2).tech(FontTech::Variations))
+//!     .weight_range(FontFaceWeight::Weight(100), FontFaceWeight::Weight(900))
+//!     .display(FontDisplay::Swap);
+//!
+//! assert_eq!(
+//!     stylesheet().add(inter).apply(),
+//!     "@font-face { font-family: \"Inter\"; \
+//!      src: local(\"Inter\"), url(\"/fonts/inter.woff2\") format(\"woff2\") tech(variations); \
+//!      font-weight: 100 900; font-display: swap; }"
+//! );
+//! ```
+
+use crate::at_rule::AtRule;
+use crate::error::MewError;
+use crate::format::OutputOptions;
+use crate::style::Style;
+use crate::stylesheet::StylesheetItem;
+use crate::values::{Angle, FontWeight, Number, ParseValueError};
+use std::fmt;
+
+/// Writes a string as a CSS string literal, escaping quotes and backslashes.
+fn write_quoted(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => write!(f, "\\{}", c)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+/// Font file formats used in the `format()` hint of a font source.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontFormat {
+    /// Web Open Font Format 2 (`woff2`)
+    Woff2,
+    /// Web Open Font Format (`woff`)
+    Woff,
+    /// TrueType (`truetype`)
+    TrueType,
+    /// OpenType (`opentype`)
+    OpenType,
+    /// OpenType collection (`collection`)
+    Collection,
+    /// Embedded OpenType (`embedded-opentype`)
+    EmbeddedOpenType,
+    /// SVG font (`svg`)
+    Svg,
+}
+
+impl fmt::Display for FontFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontFormat::Woff2 => write!(f, "woff2"),
+            FontFormat::Woff => write!(f, "woff"),
+            FontFormat::TrueType => write!(f, "truetype"),
+            FontFormat::OpenType => write!(f, "opentype"),
+            FontFormat::Collection => write!(f, "collection"),
+            FontFormat::EmbeddedOpenType => write!(f, "embedded-opentype"),
+            FontFormat::Svg => write!(f, "svg"),
+        }
+    }
+}
+
+/// Font technologies used in the `tech()` hint of a font source.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontTech {
+    /// Variable font axes (`variations`)
+    Variations,
+    /// Color palettes (`palettes`)
+    Palettes,
+    /// Incremental font transfer (`incremental`)
+    Incremental,
+    /// OpenType layout features (`features-opentype`)
+    FeaturesOpenType,
+    /// Apple Advanced Typography features (`features-aat`)
+    FeaturesAat,
+    /// Graphite features (`features-graphite`)
+    FeaturesGraphite,
+    /// COLR version 0 color glyphs (`color-COLRv0`)
+    ColorColrV0,
+    /// COLR version 1 color glyphs (`color-COLRv1`)
+    ColorColrV1,
+    /// SVG color glyphs (`color-SVG`)
+    ColorSvg,
+    /// Standard bitmap graphics color glyphs (`color-sbix`)
+    ColorSbix,
+    /// Color bitmap data color glyphs (`color-CBDT`)
+    ColorCbdt,
+}
+
+impl fmt::Display for FontTech {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontTech::Variations => write!(f, "variations"),
+            FontTech::Palettes => write!(f, "palettes"),
+            FontTech::Incremental => write!(f, "incremental"),
+            FontTech::FeaturesOpenType => write!(f, "features-opentype"),
+            FontTech::FeaturesAat => write!(f, "features-aat"),
+            FontTech::FeaturesGraphite => write!(f, "features-graphite"),
+            FontTech::ColorColrV0 => write!(f, "color-COLRv0"),
+            FontTech::ColorColrV1 => write!(f, "color-COLRv1"),
+            FontTech::ColorSvg => write!(f, "color-SVG"),
+            FontTech::ColorSbix => write!(f, "color-sbix"),
+            FontTech::ColorCbdt => write!(f, "color-CBDT"),
+        }
+    }
+}
+
+/// A single entry of the `src` descriptor.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::font_face::{FontFormat, FontSource};
+///
+/// let source = FontSource::url("inter.woff2").format(FontFormat::Woff2);
+/// assert_eq!(source.to_string(), "url(\"inter.woff2\") format(\"woff2\")");
+///
+/// let source = FontSource::local("Inter Regular");
+/// assert_eq!(source.to_string(), "local(\"Inter Regular\")");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSource {
+    /// A remote font file with optional format and technology hints
+    Url {
+        /// The location of the font file
+        url: String,
+        /// The format of the font file
+        format: Option<FontFormat>,
+        /// The font technologies required to use the file
+        tech: Vec<FontTech>,
+    },
+    /// A font installed on the user's device, looked up by its full name
+    Local(String),
+}
+
+impl FontSource {
+    /// Creates a source pointing to a remote font file.
+    pub fn url(url: &str) -> Self {
+        FontSource::Url {
+            url: url.trim().to_string(),
+            format: None,
+            tech: Vec::new(),
+        }
+    }
+
+    /// Creates a source pointing to a font installed on the user's device.
+    pub fn local(name: &str) -> Self {
+        FontSource::Local(name.trim().to_string())
+    }
+
+    /// Sets the format hint of a `url()` source. Has no effect on `local()` sources.
+    pub fn format(mut self, value: FontFormat) -> Self {
+        if let FontSource::Url { format, .. } = &mut self {
+            *format = Some(value);
+        }
+        self
+    }
+
+    /// Adds a technology hint to a `url()` source. Has no effect on `local()` sources.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::font_face::{FontSource, FontTech};
+    ///
+    /// let source = FontSource::url("emoji.woff2")
+    ///     .tech(FontTech::ColorColrV1)
+    ///     .tech(FontTech::Palettes);
+    /// assert_eq!(source.to_string(), "url(\"emoji.woff2\") tech(color-COLRv1, palettes)");
+    /// ```
+    pub fn tech(mut self, value: FontTech) -> Self {
+        if let FontSource::Url { tech, .. } = &mut self {
+            tech.push(value);
+        }
+        self
+    }
+}
+
+impl fmt::Display for FontSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontSource::Url { url, format, tech } => {
+                write!(f, "url(")?;
+                write_quoted(f, url)?;
+                write!(f, ")")?;
+
+                if let Some(format) = format {
+                    write!(f, " format(\"{}\")", format)?;
+                }
+
+                if !tech.is_empty() {
+                    let tech: Vec<String> = tech.iter().map(|t| t.to_string()).collect();
+                    write!(f, " tech({})", tech.join(", "))?;
+                }
+
+                Ok(())
+            }
+            FontSource::Local(name) => {
+                write!(f, "local(")?;
+                write_quoted(f, name)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// Values of the `font-display` descriptor, controlling how a font is displayed while loading.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontDisplay {
+    /// The display strategy is defined by the user agent
+    Auto,
+    /// Short block period and infinite swap period
+    Block,
+    /// Extremely small block period and infinite swap period
+    Swap,
+    /// Extremely small block period and short swap period
+    Fallback,
+    /// Extremely small block period and no swap period
+    Optional,
+}
+
+impl fmt::Display for FontDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontDisplay::Auto => write!(f, "auto"),
+            FontDisplay::Block => write!(f, "block"),
+            FontDisplay::Swap => write!(f, "swap"),
+            FontDisplay::Fallback => write!(f, "fallback"),
+            FontDisplay::Optional => write!(f, "optional"),
+        }
+    }
+}
+
+/// Values of the `font-weight` descriptor.
+///
+/// Unlike the `font-weight` property, the descriptor has no relative weights (`bolder`
+/// and `lighter`), and any weight from 1 to 1000 can be used, as variable fonts cover
+/// continuous ranges of weights.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::font_face::FontFaceWeight;
+///
+/// assert_eq!(FontFaceWeight::Weight(350).to_string(), "350");
+/// assert_eq!(FontFaceWeight::Bold.to_string(), "bold");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontFaceWeight {
+    /// The normal weight (400)
+    Normal,
+    /// The bold weight (700)
+    Bold,
+    /// A numeric weight from 1 to 1000, written clamped to that range
+    Weight(u16),
+}
+
+impl FontFaceWeight {
+    /// Creates a numeric weight, checking that it is between 1 and 1000.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - The numeric weight
+    ///
+    /// # Returns
+    ///
+    /// A `FontFaceWeight::Weight`, or `MewError::InvalidFontWeight` if the weight is out of
+    /// range (the same rule as `FontWeight::try_weight`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::error::MewError;
+    /// use mew_css::font_face::FontFaceWeight;
+    ///
+    /// assert_eq!(FontFaceWeight::try_weight(1000), Ok(FontFaceWeight::Weight(1000)));
+    /// assert_eq!(FontFaceWeight::try_weight(0), Err(MewError::InvalidFontWeight(0)));
+    /// ```
+    pub fn try_weight(weight: u16) -> Result<Self, MewError> {
+        if (1..=1000).contains(&weight) {
+            Ok(FontFaceWeight::Weight(weight))
+        } else {
+            Err(MewError::InvalidFontWeight(weight))
+        }
+    }
+}
+
+// Implement TryFrom<FontWeight> for FontFaceWeight to reuse the weights of the `font-weight`
+// property. Relative weights and CSS variables have no meaning in a descriptor, so they
+// cannot be converted.
+impl TryFrom<FontWeight> for FontFaceWeight {
+    type Error = MewError;
+
+    fn try_from(weight: FontWeight) -> Result<Self, Self::Error> {
+        match weight {
+            FontWeight::Normal => Ok(FontFaceWeight::Normal),
+            FontWeight::Bold => Ok(FontFaceWeight::Bold),
+            FontWeight::Weight(weight) => FontFaceWeight::try_weight(weight),
+            weight @ (FontWeight::Bolder | FontWeight::Lighter | FontWeight::Var(_)) => {
+                Err(ParseValueError::new("FontFaceWeight", &weight.to_string()).into())
+            }
+        }
+    }
+}
+
+impl fmt::Display for FontFaceWeight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontFaceWeight::Normal => write!(f, "normal"),
+            FontFaceWeight::Bold => write!(f, "bold"),
+            FontFaceWeight::Weight(weight) => write!(f, "{}", weight.clamp(&1, &1000)),
+        }
+    }
+}
+
+/// Values of the `font-style` descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontFaceStyle {
+    /// Upright glyphs
+    Normal,
+    /// Italic glyphs
+    Italic,
+    /// Oblique glyphs with the default slant
+    Oblique,
+    /// Oblique glyphs covering a range of slant angles (for variable fonts)
+    ObliqueRange(Angle, Angle),
+}
+
+impl fmt::Display for FontFaceStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontFaceStyle::Normal => write!(f, "normal"),
+            FontFaceStyle::Italic => write!(f, "italic"),
+            FontFaceStyle::Oblique => write!(f, "oblique"),
+            FontFaceStyle::ObliqueRange(from, to) => write!(f, "oblique {} {}", from, to),
+        }
+    }
+}
+
+/// Values of the metric override descriptors (`ascent-override`, `descent-override`
+/// and `line-gap-override`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetricOverride {
+    /// Use the metric from the font file
+    Normal,
+    /// Percentage of the font size
+    Percent(f32),
+}
+
+impl fmt::Display for MetricOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricOverride::Normal => write!(f, "normal"),
+            MetricOverride::Percent(val) => write!(f, "{}%", Number::new(*val)),
+        }
+    }
+}
+
+/// A range of Unicode code points for the `unicode-range` descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnicodeRange {
+    /// The first code point of the range
+    pub start: u32,
+    /// The last code point of the range (inclusive)
+    pub end: u32,
+}
+
+impl fmt::Display for UnicodeRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "U+{:04X}", self.start)
+        } else {
+            write!(f, "U+{:04X}-{:04X}", self.start, self.end)
+        }
+    }
+}
+
+/// A `@font-face` at-rule declaring a font family and where to load it from.
+///
+/// Like `Style`, the `FontFace` struct uses a fluent API where each method returns a
+/// mutable reference to self. Descriptors are rendered in a fixed order, and only the
+/// ones that have been set are emitted.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::font_face::{FontFace, FontFaceStyle, FontSource};
+/// use mew_css::font_face::FontFaceWeight;
+///
+/// let mut face = FontFace::new("Lora");
+/// face.src(FontSource::url("lora-italic.woff2"))
+///     .weight(FontFaceWeight::Bold)
+///     .style(FontFaceStyle::Italic);
+///
+/// assert_eq!(
+///     face.to_string(),
+///     "@font-face { font-family: \"Lora\"; src: url(\"lora-italic.woff2\"); font-weight: bold; font-style: italic; }"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FontFace {
+    family: String,
+    sources: Vec<FontSource>,
+    weight: Option<(FontFaceWeight, Option<FontFaceWeight>)>,
+    style: Option<FontFaceStyle>,
+    display: Option<FontDisplay>,
+    unicode_ranges: Vec<UnicodeRange>,
+    size_adjust: Option<f32>,
+    ascent_override: Option<MetricOverride>,
+    descent_override: Option<MetricOverride>,
+    line_gap_override: Option<MetricOverride>,
+}
+
+impl FontFace {
+    /// Creates a new `@font-face` rule for the given font family.
+    ///
+    /// # Arguments
+    ///
+    /// * `family` - The font family name, as referenced by `font-family` declarations
+    ///
+    /// # Returns
+    ///
+    /// A new `FontFace` instance
+    pub fn new(family: &str) -> Self {
+        Self {
+            family: family.trim().to_string(),
+            sources: Vec::new(),
+            weight: None,
+            style: None,
+            display: None,
+            unicode_ranges: Vec::new(),
+            size_adjust: None,
+            ascent_override: None,
+            descent_override: None,
+            line_gap_override: None,
+        }
+    }
+
+    /// Returns the font family name declared by this rule.
+    pub fn family(&self) -> &str {
+        &self.family
+    }
+
+    /// Adds a source to the `src` descriptor. Sources are tried in insertion order.
+    pub fn src(&mut self, source: FontSource) -> &mut Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Sets the `font-weight` descriptor to a single weight.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::font_face::FontFace;
+    /// use mew_css::values::FontWeight;
+    ///
+    /// // The weights of the `font-weight` property can be reused
+    /// let heading = FontWeight::Weight(650);
+    /// let mut face = FontFace::new("Inter");
+    /// face.weight(heading.try_into().unwrap());
+    ///
+    /// assert_eq!(face.to_string(), "@font-face { font-family: \"Inter\"; font-weight: 650; }");
+    /// ```
+    pub fn weight(&mut self, weight: FontFaceWeight) -> &mut Self {
+        self.weight = Some((weight, None));
+        self
+    }
+
+    /// Sets the `font-weight` descriptor to a range of weights, for variable fonts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::font_face::{FontFace, FontFaceWeight};
+    ///
+    /// let mut face = FontFace::new("Inter");
+    /// face.weight_range(FontFaceWeight::Weight(100), FontFaceWeight::Weight(900));
+    ///
+    /// assert_eq!(face.to_string(), "@font-face { font-family: \"Inter\"; font-weight: 100 900; }");
+    /// ```
+    pub fn weight_range(&mut self, min: FontFaceWeight, max: FontFaceWeight) -> &mut Self {
+        self.weight = Some((min, Some(max)));
+        self
+    }
+
+    /// Sets the `font-style` descriptor.
+    pub fn style(&mut self, style: FontFaceStyle) -> &mut Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Sets the `font-display` descriptor.
+    pub fn display(&mut self, display: FontDisplay) -> &mut Self {
+        self.display = Some(display);
+        self
+    }
+
+    /// Adds a range of code points to the `unicode-range` descriptor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::font_face::FontFace;
+    ///
+    /// let mut face = FontFace::new("Latin");
+    /// face.unicode_range(0x0000, 0x00FF).unicode_range(0x2019, 0x2019);
+    ///
+    /// assert_eq!(
+    ///     face.to_string(),
+    ///     "@font-face { font-family: \"Latin\"; unicode-range: U+0000-00FF, U+2019; }"
+    /// );
+    /// ```
+    pub fn unicode_range(&mut self, start: u32, end: u32) -> &mut Self {
+        self.unicode_ranges.push(UnicodeRange { start, end });
+        self
+    }
+
+    /// Sets the `size-adjust` descriptor, a percentage scaling the glyph outlines and metrics.
+    pub fn size_adjust(&mut self, percent: f32) -> &mut Self {
+        self.size_adjust = Some(percent);
+        self
+    }
+
+    /// Sets the `ascent-override` descriptor.
+    pub fn ascent_override(&mut self, value: MetricOverride) -> &mut Self {
+        self.ascent_override = Some(value);
+        self
+    }
+
+    /// Sets the `descent-override` descriptor.
+    pub fn descent_override(&mut self, value: MetricOverride) -> &mut Self {
+        self.descent_override = Some(value);
+        self
+    }
+
+    /// Sets the `line-gap-override` descriptor.
+    pub fn line_gap_override(&mut self, value: MetricOverride) -> &mut Self {
+        self.line_gap_override = Some(value);
+        self
+    }
+
+    /// Generates the CSS text of this `@font-face` rule with the given output options.
+    pub fn to_css_with(&self, options: &OutputOptions) -> String {
+        self.to_at_rule().to_css_with(options)
+    }
+
+    /// Converts this `@font-face` rule into a generic `AtRule`.
+    fn to_at_rule(&self) -> AtRule {
+        let mut style = Style::new();
+        style.custom_property("font-family", QuotedString(&self.family));
+
+        if !self.sources.is_empty() {
+            let sources: Vec<String> = self.sources.iter().map(|s| s.to_string()).collect();
+            style.custom_property("src", sources.join(", "));
+        }
+
+        match &self.weight {
+            Some((weight, None)) => {
+                style.custom_property("font-weight", weight);
+            }
+            Some((min, Some(max))) => {
+                style.custom_property("font-weight", format!("{} {}", min, max));
+            }
+            None => {}
+        }
+
+        if let Some(value) = &self.style {
+            style.custom_property("font-style", value);
+        }
+
+        if let Some(value) = &self.display {
+            style.custom_property("font-display", value);
+        }
+
+        if !self.unicode_ranges.is_empty() {
+            let ranges: Vec<String> = self.unicode_ranges.iter().map(|r| r.to_string()).collect();
+            style.custom_property("unicode-range", ranges.join(", "));
+        }
+
+        if let Some(value) = self.size_adjust {
+            style.custom_property("size-adjust", format!("{}%", Number::new(value)));
+        }
+
+        if let Some(value) = &self.ascent_override {
+            style.custom_property("ascent-override", value);
+        }
+
+        if let Some(value) = &self.descent_override {
+            style.custom_property("descent-override", value);
+        }
+
+        if let Some(value) = &self.line_gap_override {
+            style.custom_property("line-gap-override", value);
+        }
+
+        AtRule::with_declarations("font-face", "", &style)
+    }
+}
+
+/// Helper rendering a string as a quoted CSS string literal.
+struct QuotedString<'a>(&'a str);
+
+impl fmt::Display for QuotedString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_quoted(f, self.0)
+    }
+}
+
+impl fmt::Display for FontFace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_at_rule())
+    }
+}
+
+// Implement From<FontFace> for AtRule to allow automatic conversion
+impl From<FontFace> for AtRule {
+    fn from(font_face: FontFace) -> Self {
+        font_face.to_at_rule()
+    }
+}
+
+// Implement From<FontFace> for StylesheetItem to allow automatic conversion
+impl From<FontFace> for StylesheetItem {
+    fn from(font_face: FontFace) -> Self {
+        StylesheetItem::AtRule(font_face.to_at_rule())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_family_only() {
+        let face = FontFace::new("Inter");
+        assert_eq!(face.to_string(), "@font-face { font-family: \"Inter\"; }");
+    }
+
+    #[test]
+    fn test_family_escaping() {
+        let face = FontFace::new("My \"Quoted\" Font");
+        assert_eq!(face.to_string(), "@font-face { font-family: \"My \\\"Quoted\\\" Font\"; }");
+    }
+
+    #[test]
+    fn test_sources() {
+        let mut face = FontFace::new("Inter");
+        face.src(FontSource::local("Inter"))
+            .src(FontSource::url("inter.woff2").format(FontFormat::Woff2).tech(FontTech::Variations))
+            .src(FontSource::url("inter.ttf").format(FontFormat::TrueType));
+
+        assert_eq!(
+            face.to_string(),
+            "@font-face { font-family: \"Inter\"; src: local(\"Inter\"), \
+             url(\"inter.woff2\") format(\"woff2\") tech(variations), \
+             url(\"inter.ttf\") format(\"truetype\"); }"
+        );
+    }
+
+    #[test]
+    fn test_local_ignores_hints() {
+        let source = FontSource::local("Arial").format(FontFormat::Woff).tech(FontTech::Palettes);
+        assert_eq!(source.to_string(), "local(\"Arial\")");
+    }
+
+    #[test]
+    fn test_weight_and_style() {
+        let mut face = FontFace::new("Roboto Flex");
+        face.weight_range(FontFaceWeight::Weight(200), FontFaceWeight::Weight(800))
+            .style(FontFaceStyle::ObliqueRange(Angle::Deg(-10.0), Angle::Deg(0.0)));
+
+        assert_eq!(
+            face.to_string(),
+            "@font-face { font-family: \"Roboto Flex\"; font-weight: 200 800; font-style: oblique -10deg 0deg; }"
+        );
+
+        let mut face = FontFace::new("Roboto");
+        face.weight(FontFaceWeight::Normal).style(FontFaceStyle::Normal);
+        assert_eq!(
+            face.to_string(),
+            "@font-face { font-family: \"Roboto\"; font-weight: normal; font-style: normal; }"
+        );
+
+        // Variable fonts use weights that are not multiples of 100
+        let mut face = FontFace::new("Recursive");
+        face.weight_range(FontFaceWeight::Weight(300), FontFaceWeight::Weight(350));
+        assert_eq!(face.to_string(), "@font-face { font-family: \"Recursive\"; font-weight: 300 350; }");
+
+        face.weight_range(FontFaceWeight::Weight(0), FontFaceWeight::Weight(1200));
+        assert_eq!(face.to_string(), "@font-face { font-family: \"Recursive\"; font-weight: 1 1000; }");
+        assert!(FontFaceWeight::try_weight(1001).is_err());
+    }
+
+    #[test]
+    fn test_weight_from_font_weight() {
+        assert_eq!(FontFaceWeight::try_from(FontWeight::Normal), Ok(FontFaceWeight::Normal));
+        assert_eq!(FontFaceWeight::try_from(FontWeight::Bold), Ok(FontFaceWeight::Bold));
+        assert_eq!(FontFaceWeight::try_from(FontWeight::Weight(350)), Ok(FontFaceWeight::Weight(350)));
+        assert_eq!(FontFaceWeight::try_from(FontWeight::Weight(1200)), Err(MewError::InvalidFontWeight(1200)));
+        assert!(FontFaceWeight::try_from(FontWeight::Bolder).is_err());
+        assert!(FontFaceWeight::try_from(FontWeight::Var(crate::variable::var("weight"))).is_err());
+
+        let mut face = FontFace::new("Inter");
+        face.weight_range(FontWeight::Weight(300).try_into().unwrap(), FontWeight::Bold.try_into().unwrap());
+        assert_eq!(face.to_string(), "@font-face { font-family: \"Inter\"; font-weight: 300 bold; }");
+    }
+
+    #[test]
+    fn test_display_and_unicode_range() {
+        let mut face = FontFace::new("Icons");
+        face.display(FontDisplay::Block)
+            .unicode_range(0xE000, 0xF8FF)
+            .unicode_range(0x20AC, 0x20AC);
+
+        assert_eq!(
+            face.to_string(),
+            "@font-face { font-family: \"Icons\"; font-display: block; unicode-range: U+E000-F8FF, U+20AC; }"
+        );
+    }
+
+    #[test]
+    fn test_metric_overrides() {
+        let mut face = FontFace::new("Fallback");
+        face.src(FontSource::local("Arial"))
+            .size_adjust(105.5)
+            .ascent_override(MetricOverride::Percent(90.0))
+            .descent_override(MetricOverride::Percent(22.5))
+            .line_gap_override(MetricOverride::Normal);
+
+        assert_eq!(
+            face.to_string(),
+            "@font-face { font-family: \"Fallback\"; src: local(\"Arial\"); size-adjust: 105.5%; \
+             ascent-override: 90%; descent-override: 22.5%; line-gap-override: normal; }"
+        );
+    }
+}
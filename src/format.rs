@@ -0,0 +1,431 @@
+//! # Output Formatting Module
+//!
+//! This module provides the `OutputOptions` type, which controls how CSS is written
+//! by the `to_css_with` methods of `Property`, `Style`, `Rule`, `AtRule` and `Stylesheet`.
+//!
+//! Three output styles are available:
+//!
+//! - **Compact**: every block on one line, with single spaces between declarations.
+//!   This is the default, and what the `Display` implementations produce.
+//! - **Pretty**: one declaration per line, with nested blocks indented.
+//! - **Minified**: no optional whitespace and no final semicolon in blocks.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::{rule, style};
+//! use mew_css::format::OutputOptions;
+//! use mew_css::values::{AlignItems, Color};
+//!
+//! let nav = rule(".nav", style()
+//!     .align_items(AlignItems::Center)
+//!     .background_color(Color::White));
+//!
+//! assert_eq!(
+//!     nav.to_css_with(&OutputOptions::compact()),
+//!     ".nav { align-items: center; background-color: white; }"
+//! );
+//! assert_eq!(
+//!     nav.to_css_with(&OutputOptions::pretty()),
+//!     ".nav {\n  align-items: center;\n  background-color: white;\n}"
+//! );
+//! assert_eq!(
+//!     nav.to_css_with(&OutputOptions::minified()),
+//!     ".nav{align-items:center;background-color:white}"
+//! );
+//! ```
+
+use crate::at_rule::AtRuleBody;
+use crate::properties::Property;
+use crate::style::Style;
+use crate::stylesheet::StylesheetItem;
+
+/// The overall layout of the generated CSS.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OutputStyle {
+    /// One declaration per line and indented nested blocks
+    Pretty,
+    /// Every block on a single line (the `Display` output)
+    #[default]
+    Compact,
+    /// No optional whitespace and no final semicolon in blocks
+    Minified,
+}
+
+/// Options controlling how CSS is written by the `to_css_with` methods.
+///
+/// Like `Animation`, `OutputOptions` uses consuming builder methods, so options
+/// can be created and configured in a single expression.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::{rule, style};
+/// use mew_css::format::OutputOptions;
+/// use mew_css::values::Color;
+///
+/// let options = OutputOptions::pretty().indent("\t");
+/// let css = rule("a", style().background_color(Color::Red)).to_css_with(&options);
+///
+/// assert_eq!(css, "a {\n\tbackground-color: red;\n}");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputOptions {
+    /// The overall layout of the output
+    style: OutputStyle,
+    /// The string used for one level of indentation in pretty output
+    indent: String,
+}
+
+impl Default for OutputOptions {
+    fn default() -> Self {
+        Self::new(OutputStyle::default())
+    }
+}
+
+impl OutputOptions {
+    /// Creates options for the given output style, indenting pretty output with two spaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The overall layout of the output
+    ///
+    /// # Returns
+    ///
+    /// A new `OutputOptions` instance
+    pub fn new(style: OutputStyle) -> Self {
+        Self {
+            style,
+            indent: "  ".to_string(),
+        }
+    }
+
+    /// Creates options for multi-line output with indented blocks.
+    pub fn pretty() -> Self {
+        Self::new(OutputStyle::Pretty)
+    }
+
+    /// Creates options for single-line blocks, identical to the `Display` output.
+    pub fn compact() -> Self {
+        Self::new(OutputStyle::Compact)
+    }
+
+    /// Creates options for minified output.
+    pub fn minified() -> Self {
+        Self::new(OutputStyle::Minified)
+    }
+
+    /// Sets the string used for one level of indentation in pretty output.
+    ///
+    /// # Arguments
+    ///
+    /// * `indent` - The indentation string (e.g., four spaces or a tab)
+    ///
+    /// # Returns
+    ///
+    /// The updated options
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::format::OutputOptions;
+    ///
+    /// let options = OutputOptions::pretty().indent("    ");
+    /// assert_eq!(options.indent_str(), "    ");
+    /// ```
+    pub fn indent(mut self, indent: &str) -> Self {
+        self.indent = indent.to_string();
+        self
+    }
+
+    /// Returns the overall layout of the output.
+    pub fn style(&self) -> OutputStyle {
+        self.style
+    }
+
+    /// Returns the string used for one level of indentation in pretty output.
+    pub fn indent_str(&self) -> &str {
+        &self.indent
+    }
+
+    /// Returns the indentation of a line at the given nesting depth.
+    fn indentation(&self, depth: usize) -> String {
+        match self.style {
+            OutputStyle::Pretty => self.indent.repeat(depth),
+            OutputStyle::Compact | OutputStyle::Minified => String::new(),
+        }
+    }
+
+    /// Writes a single declaration, without indentation.
+    pub(crate) fn declaration(&self, property: &Property) -> String {
+        match self.style {
+            OutputStyle::Pretty | OutputStyle::Compact => format!("{}: {};", property.name(), property.value()),
+            OutputStyle::Minified => format!("{}:{}", property.name(), property.value()),
+        }
+    }
+
+    /// Writes the declarations of a style (ignoring nested styles), each pretty line
+    /// being indented for the given depth.
+    pub(crate) fn declarations(&self, style: &Style, depth: usize) -> String {
+        let declarations = style.properties().iter().map(|property| self.declaration(property));
+
+        match self.style {
+            OutputStyle::Pretty => {
+                let indentation = self.indentation(depth);
+                declarations
+                    .map(|declaration| format!("{}{}", indentation, declaration))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            OutputStyle::Compact => declarations.collect::<Vec<_>>().join(" "),
+            OutputStyle::Minified => declarations.collect::<Vec<_>>().join(";"),
+        }
+    }
+
+    /// Writes a block made of a header (selector or at-rule prelude) and the declarations of a style.
+    fn declaration_block(&self, header: &str, style: &Style, depth: usize) -> String {
+        let indentation = self.indentation(depth);
+
+        if style.properties().is_empty() {
+            return match self.style {
+                OutputStyle::Minified => format!("{}{{}}", header),
+                OutputStyle::Pretty | OutputStyle::Compact => format!("{}{} {{}}", indentation, header),
+            };
+        }
+
+        match self.style {
+            OutputStyle::Pretty => format!(
+                "{}{} {{\n{}\n{}}}",
+                indentation,
+                header,
+                self.declarations(style, depth + 1),
+                indentation
+            ),
+            OutputStyle::Compact => format!("{} {{ {} }}", header, self.declarations(style, depth)),
+            OutputStyle::Minified => format!("{}{{{}}}", header, self.declarations(style, depth)),
+        }
+    }
+
+    /// Writes a block made of a header and child blocks already rendered at `depth + 1`.
+    fn item_block(&self, header: &str, children: Vec<String>, depth: usize) -> String {
+        let indentation = self.indentation(depth);
+
+        if children.is_empty() {
+            return match self.style {
+                OutputStyle::Minified => format!("{}{{}}", header),
+                OutputStyle::Pretty | OutputStyle::Compact => format!("{}{} {{}}", indentation, header),
+            };
+        }
+
+        match self.style {
+            OutputStyle::Pretty => format!("{}{} {{\n{}\n{}}}", indentation, header, children.join("\n"), indentation),
+            OutputStyle::Compact => format!("{} {{ {} }}", header, children.join(" ")),
+            OutputStyle::Minified => format!("{}{{{}}}", header, children.concat()),
+        }
+    }
+
+    /// Writes a single block, which must not contain nested styles (see `Rule::flatten`).
+    pub(crate) fn block(&self, item: &StylesheetItem, depth: usize) -> String {
+        match item {
+            StylesheetItem::Rule(rule) => self.declaration_block(rule.selector(), rule.style(), depth),
+            StylesheetItem::AtRule(at_rule) => {
+                let header = match (at_rule.prelude(), self.style) {
+                    ("", _) => format!("@{}", at_rule.name()),
+                    (prelude, OutputStyle::Minified) if has_condition_prelude(at_rule.name()) => {
+                        format!("@{} {}", at_rule.name(), minify_condition(prelude))
+                    }
+                    (prelude, _) => format!("@{} {}", at_rule.name(), prelude),
+                };
+
+                match at_rule.body() {
+                    AtRuleBody::Statement => format!("{}{};", self.indentation(depth), header),
+                    AtRuleBody::Declarations(style) => self.declaration_block(&header, style, depth),
+                    AtRuleBody::Items(items) => self.item_block(&header, self.blocks(items, depth + 1), depth),
+                }
+            }
+        }
+    }
+
+    /// Writes each block of the given items, expanding the nested styles of rules
+    /// into separate blocks.
+    pub(crate) fn blocks(&self, items: &[StylesheetItem], depth: usize) -> Vec<String> {
+        items
+            .iter()
+            .flat_map(|item| match item {
+                StylesheetItem::Rule(rule) => rule.flatten(),
+                StylesheetItem::AtRule(_) => vec![item.clone()],
+            })
+            .map(|block| self.block(&block, depth))
+            .collect()
+    }
+
+    /// Joins top-level blocks. Compact output uses `compact_separator`, which differs
+    /// between a single rule (spaces) and a stylesheet (line breaks).
+    pub(crate) fn join_blocks(&self, blocks: Vec<String>, compact_separator: &str) -> String {
+        match self.style {
+            OutputStyle::Pretty => blocks.join("\n\n"),
+            OutputStyle::Compact => blocks.join(compact_separator),
+            OutputStyle::Minified => blocks.concat(),
+        }
+    }
+}
+
+/// Returns `true` for the at-rules whose prelude is a condition made of `name: value` features.
+fn has_condition_prelude(name: &str) -> bool {
+    matches!(name, "media" | "supports" | "container")
+}
+
+/// Removes the whitespace following the colons of a condition prelude (e.g.,
+/// `(min-width: 768px)` becomes `(min-width:768px)`), leaving strings untouched.
+fn minify_condition(prelude: &str) -> String {
+    let mut minified = String::with_capacity(prelude.len());
+    let mut quote = None;
+    let mut after_colon = false;
+
+    for c in prelude.chars() {
+        if after_colon && c.is_whitespace() {
+            continue;
+        }
+        after_colon = false;
+
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, ':') => after_colon = true,
+            _ => {}
+        }
+        minified.push(c);
+    }
+    minified
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::at_rule::AtRule;
+    use crate::media::MediaQuery;
+    use crate::rule::rule;
+    use crate::style::style;
+    use crate::stylesheet::{Stylesheet, stylesheet};
+    use crate::values::{AlignItems, Color, Size};
+
+    fn sample() -> Stylesheet {
+        let mut sheet = stylesheet();
+        sheet
+            .add_at_rule(AtRule::statement("import", "url(\"reset.css\")"))
+            .add_rule(rule(
+                ".nav",
+                style()
+                    .align_items(AlignItems::Start)
+                    .background_color(Color::White)
+                    .hover(|s| s.background_color(Color::Gray))
+                    .media(MediaQuery::min_width(Size::Px(768.0)), |s| s.align_items(AlignItems::Center)),
+            ))
+            .add_rule(rule("p", &style()))
+            .add_at_rule(AtRule::with_declarations(
+                "font-face",
+                "",
+                style().custom_property("font-family", "\"Inter\""),
+            ));
+        sheet
+    }
+
+    #[test]
+    fn test_compact_matches_display() {
+        let sheet = sample();
+        assert_eq!(sheet.to_css_with(&OutputOptions::compact()), sheet.to_string());
+        assert_eq!(sheet.to_css_with(&OutputOptions::default()), sheet.to_string());
+    }
+
+    #[test]
+    fn test_pretty() {
+        assert_eq!(
+            sample().to_css_with(&OutputOptions::pretty()),
+            "@import url(\"reset.css\");\n\
+             \n\
+             .nav {\n  align-items: start;\n  background-color: white;\n}\n\
+             \n\
+             .nav:hover {\n  background-color: gray;\n}\n\
+             \n\
+             @media (min-width: 768px) {\n  .nav {\n    align-items: center;\n  }\n}\n\
+             \n\
+             p {}\n\
+             \n\
+             @font-face {\n  font-family: \"Inter\";\n}"
+        );
+    }
+
+    #[test]
+    fn test_pretty_custom_indent() {
+        let options = OutputOptions::pretty().indent("    ");
+        let media = AtRule::media(
+            &MediaQuery::print(),
+            vec![rule("body", style().background_color(Color::White)).into()],
+        );
+
+        assert_eq!(
+            media.to_css_with(&options),
+            "@media print {\n    body {\n        background-color: white;\n    }\n}"
+        );
+    }
+
+    #[test]
+    fn test_minified() {
+        assert_eq!(
+            sample().to_css_with(&OutputOptions::minified()),
+            "@import url(\"reset.css\");\
+             .nav{align-items:start;background-color:white}\
+             .nav:hover{background-color:gray}\
+             @media (min-width:768px){.nav{align-items:center}}\
+             p{}\
+             @font-face{font-family:\"Inter\"}"
+        );
+    }
+
+    #[test]
+    fn test_minified_preludes() {
+        let card = rule(
+            ".card",
+            style()
+                .background_color(Color::Red)
+                .media(MediaQuery::min_width(Size::Px(768.0)), |s| s.background_color(Color::Blue)),
+        );
+        assert_eq!(
+            card.to_css_with(&OutputOptions::minified()),
+            ".card{background-color:red}@media (min-width:768px){.card{background-color:blue}}"
+        );
+
+        let supports = AtRule::with_items(
+            "supports",
+            "(display: grid) and (not (content: \"a: b\"))",
+            vec![AtRule::media(
+                &MediaQuery::screen().and(MediaQuery::max_width(Size::Px(600.0))),
+                vec![rule("a", style().background_color(Color::White)).into()],
+            )
+            .into()],
+        );
+        assert_eq!(
+            supports.to_css_with(&OutputOptions::minified()),
+            "@supports (display:grid) and (not (content:\"a: b\")){\
+             @media screen and (max-width:600px){a{background-color:white}}}"
+        );
+
+        // Other preludes are written as given
+        let page = AtRule::with_declarations("page", ":first", style().custom_property("margin", "2cm"));
+        assert_eq!(page.to_css_with(&OutputOptions::minified()), "@page :first{margin:2cm}");
+    }
+
+    #[test]
+    fn test_style_and_property() {
+        let mut base = style();
+        base.align_items(AlignItems::Center).background_color(Color::Red);
+
+        assert_eq!(base.to_css_with(&OutputOptions::compact()), "align-items: center; background-color: red;");
+        assert_eq!(base.to_css_with(&OutputOptions::pretty()), "align-items: center;\nbackground-color: red;");
+        assert_eq!(base.to_css_with(&OutputOptions::minified()), "align-items:center;background-color:red");
+
+        let property = Property::new("margin", "0 auto");
+        assert_eq!(property.to_css_with(&OutputOptions::pretty()), "margin: 0 auto;");
+        assert_eq!(property.to_css_with(&OutputOptions::minified()), "margin:0 auto");
+    }
+}
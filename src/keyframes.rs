@@ -0,0 +1,309 @@
+//! # Keyframes Module
+//!
+//! This module provides the `Keyframes` type, a builder for `@keyframes` at-rules.
+//! Each keyframe stop (`from`, `to` or a percentage) holds a `Style` describing the
+//! animated properties at that point of the animation.
+//!
+//! A `Keyframes` value is also the handle used to create an `Animation`, so the
+//! animation name used by `Style::animation` always matches the name of the
+//! `@keyframes` rule added to the stylesheet.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::{rule, style, stylesheet};
+//! use mew_css::keyframes::Keyframes;
+//! use mew_css::values::{Animation, Color, IterationCount, Time};
+//!
+//! let mut pulse = Keyframes::new("pulse");
+//! pulse
+//!     .from(|s| s.background_color(Color::White))
+//!     .at(50.0, |s| s.background_color(Color::Red))
+//!     .to(|s| s.background_color(Color::White));
+//!
+//! let css = stylesheet()
+//!     .add(pulse.clone())
+//!     .add_rule(rule(".alert", style()
+//!         .animation(Animation::new(&pulse, Time::S(2.0)).iteration_count(IterationCount::Infinite))))
+//!     .apply();
+//!
+//! assert_eq!(css, "@keyframes pulse { \
+//!                  from { background-color: white; } \
+//!                  50% { background-color: red; } \
+//!                  to { background-color: white; } }\n\
+//!                  .alert { animation: pulse 2s infinite; }");
+//! ```
+
+use crate::at_rule::AtRule;
+use crate::format::OutputOptions;
+use crate::rule::Rule;
+use crate::style::Style;
+use crate::stylesheet::StylesheetItem;
+use crate::values::Number;
+use std::fmt;
+
+/// The position of a keyframe stop within an animation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyframeSelector {
+    /// The start of the animation (equivalent to `0%`)
+    From,
+    /// The end of the animation (equivalent to `100%`)
+    To,
+    /// A percentage of the animation duration
+    Percent(f32),
+}
+
+impl fmt::Display for KeyframeSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyframeSelector::From => write!(f, "from"),
+            KeyframeSelector::To => write!(f, "to"),
+            KeyframeSelector::Percent(val) => write!(f, "{}%", Number::new(*val)),
+        }
+    }
+}
+
+/// A `@keyframes` at-rule, made of a name and an ordered list of keyframe stops.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::keyframes::Keyframes;
+/// use mew_css::values::Color;
+///
+/// let mut fade = Keyframes::new("fade");
+/// fade.from(|s| s.custom_property("opacity", 0))
+///     .to(|s| s.custom_property("opacity", 1));
+///
+/// assert_eq!(fade.to_string(), "@keyframes fade { from { opacity: 0; } to { opacity: 1; } }");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Keyframes {
+    /// The animation name (e.g., "fade-in")
+    name: String,
+    /// The keyframe stops, in insertion order
+    stops: Vec<(KeyframeSelector, Style)>,
+}
+
+impl Keyframes {
+    /// Creates a new `@keyframes` rule with the given name and no stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The animation name
+    ///
+    /// # Returns
+    ///
+    /// A new `Keyframes` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::keyframes::Keyframes;
+    ///
+    /// let spin = Keyframes::new("spin");
+    /// assert_eq!(spin.name(), "spin");
+    /// ```
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            stops: Vec::new(),
+        }
+    }
+
+    /// Returns the animation name of this `@keyframes` rule.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the keyframe stops, in insertion order.
+    pub fn stops(&self) -> &[(KeyframeSelector, Style)] {
+        &self.stops
+    }
+
+    /// Adds a keyframe stop built by the given closure.
+    ///
+    /// This is the low-level method used by `from`, `to` and `at`.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - The position of the stop within the animation
+    /// * `build` - A closure that adds properties to the stop
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn stop<F>(&mut self, selector: KeyframeSelector, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        let mut style = Style::new();
+        build(&mut style);
+        self.stops.push((selector, style));
+        self
+    }
+
+    /// Adds the `from` stop, at the start of the animation.
+    ///
+    /// # Arguments
+    ///
+    /// * `build` - A closure that adds properties to the stop
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn from<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.stop(KeyframeSelector::From, build)
+    }
+
+    /// Adds the `to` stop, at the end of the animation.
+    ///
+    /// # Arguments
+    ///
+    /// * `build` - A closure that adds properties to the stop
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn to<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.stop(KeyframeSelector::To, build)
+    }
+
+    /// Adds a stop at the given percentage of the animation.
+    ///
+    /// # Arguments
+    ///
+    /// * `percent` - The position of the stop, from 0 to 100
+    /// * `build` - A closure that adds properties to the stop
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::keyframes::Keyframes;
+    /// use mew_css::values::Color;
+    ///
+    /// let mut blink = Keyframes::new("blink");
+    /// blink.at(50.0, |s| s.background_color(Color::Transparent));
+    ///
+    /// assert_eq!(blink.to_string(), "@keyframes blink { 50% { background-color: transparent; } }");
+    /// ```
+    pub fn at<F>(&mut self, percent: f32, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.stop(KeyframeSelector::Percent(percent), build)
+    }
+
+    /// Generates the CSS text of this `@keyframes` rule with the given output options.
+    pub fn to_css_with(&self, options: &OutputOptions) -> String {
+        self.to_at_rule().to_css_with(options)
+    }
+
+    /// Converts this `@keyframes` rule into a generic `AtRule`.
+    fn to_at_rule(&self) -> AtRule {
+        let items = self
+            .stops
+            .iter()
+            .map(|(selector, style)| Rule::new(&selector.to_string(), &style.declarations()).into())
+            .collect();
+        AtRule::with_items("keyframes", &self.name, items)
+    }
+}
+
+impl fmt::Display for Keyframes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_at_rule())
+    }
+}
+
+// Implement From<Keyframes> for AtRule to allow automatic conversion
+impl From<Keyframes> for AtRule {
+    fn from(keyframes: Keyframes) -> Self {
+        keyframes.to_at_rule()
+    }
+}
+
+// Implement From<Keyframes> for StylesheetItem to allow automatic conversion
+impl From<Keyframes> for StylesheetItem {
+    fn from(keyframes: Keyframes) -> Self {
+        StylesheetItem::AtRule(keyframes.to_at_rule())
+    }
+}
+
+/// Creates a new `@keyframes` rule with the given name.
+///
+/// This is a convenience function that creates a new `Keyframes` instance.
+/// It's a shorter alternative to calling `Keyframes::new()`.
+///
+/// # Arguments
+///
+/// * `name` - The animation name
+///
+/// # Returns
+///
+/// A new `Keyframes` instance
+pub fn keyframes(name: &str) -> Keyframes {
+    Keyframes::new(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stylesheet::stylesheet;
+    use crate::values::Color;
+
+    #[test]
+    fn test_from_to() {
+        let mut fade = keyframes("fade-in");
+        fade.from(|s| s.custom_property("opacity", 0))
+            .to(|s| s.custom_property("opacity", 1));
+
+        assert_eq!(
+            fade.to_string(),
+            "@keyframes fade-in { from { opacity: 0; } to { opacity: 1; } }"
+        );
+    }
+
+    #[test]
+    fn test_percentages() {
+        let mut pulse = keyframes("pulse");
+        pulse
+            .at(0.0, |s| s.background_color(Color::White))
+            .at(33.3, |s| s.background_color(Color::Red))
+            .at(100.0, |s| s.background_color(Color::White));
+
+        assert_eq!(
+            pulse.to_string(),
+            "@keyframes pulse { 0% { background-color: white; } \
+             33.3% { background-color: red; } \
+             100% { background-color: white; } }"
+        );
+
+        assert_eq!(KeyframeSelector::Percent(100.0 / 3.0).to_string(), "33.3333%");
+        assert_eq!(KeyframeSelector::Percent(f32::NAN).to_string(), "0%");
+    }
+
+    #[test]
+    fn test_empty_keyframes() {
+        assert_eq!(keyframes("noop").to_string(), "@keyframes noop {}");
+    }
+
+    #[test]
+    fn test_keyframes_in_stylesheet() {
+        let mut spin = keyframes("spin");
+        spin.to(|s| s.custom_property("transform", "rotate(360deg)"));
+
+        let css = stylesheet().add(spin).apply();
+        assert_eq!(css, "@keyframes spin { to { transform: rotate(360deg); } }");
+    }
+}
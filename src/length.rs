@@ -0,0 +1,382 @@
+//! # Length Module
+//!
+//! This module resolves `Size` values to pixels, so that lengths can be checked in Rust
+//! (for instance, that a touch target is at least 44px) before being written as CSS.
+//!
+//! Most lengths depend on the document: font-relative units on the font size of the
+//! element and of the root element, viewport units on the size of the viewport, and so
+//! on. A `LengthContext` supplies these values, and `Size::to_px` uses it to resolve
+//! any length, percentage or `calc()` expression (including math functions such as
+//! `min()` or `clamp()`). Values that cannot be resolved, such as `auto` or CSS
+//! variables, resolve to `None`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::calc::Calc;
+//! use mew_css::length::LengthContext;
+//! use mew_css::values::Size;
+//! use mew_css::var;
+//!
+//! let mobile = LengthContext::new(375.0, 667.0);
+//! assert_eq!(Size::Rem(2.75).to_px(&mobile), Some(44.0));
+//! assert_eq!(Size::Vw(10.0).to_px(&mobile), Some(37.5));
+//!
+//! let padding = Size::from(Calc::clamp(Size::Rem(1.0), Size::Vw(5.0), Size::Rem(2.0)).unwrap());
+//! assert_eq!(padding.to_px(&mobile), Some(18.75));
+//! assert_eq!(padding.to_px(&LengthContext::new(1280.0, 800.0)), Some(32.0));
+//!
+//! assert_eq!(Size::Auto.to_px(&mobile), None);
+//! assert_eq!(Size::Var(var("gap")).to_px(&mobile), None);
+//! ```
+
+use crate::calc::{Calc, Kind};
+use crate::values::Size;
+
+/// The number of pixels in an inch, as defined by CSS.
+const PX_PER_IN: f64 = 96.0;
+
+/// The line height used for `lh` and `rlh` units when none is set, relative to the font
+/// size (the usual value of `line-height: normal`).
+const NORMAL_LINE_HEIGHT: f64 = 1.2;
+
+/// The values that relative lengths are resolved against.
+///
+/// Like `OutputOptions`, `LengthContext` uses consuming builder methods, so a context
+/// can be created and configured in a single expression. Font sizes default to 16px,
+/// the container to the viewport, and line heights to 1.2 times the font size. There
+/// is no default percentage basis, since it depends on the property.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::length::LengthContext;
+/// use mew_css::values::Size;
+///
+/// let context = LengthContext::new(1280.0, 800.0)
+///     .font_size(20.0)
+///     .container(600.0, 400.0)
+///     .percentage_basis(600.0);
+///
+/// assert_eq!(Size::Em(1.5).to_px(&context), Some(30.0));
+/// assert_eq!(Size::Rem(1.5).to_px(&context), Some(24.0));
+/// assert_eq!(Size::Cqi(50.0).to_px(&context), Some(300.0));
+/// assert_eq!(Size::Percent(25.0).to_px(&context), Some(150.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LengthContext {
+    /// The font size of the root element, in pixels (`rem`)
+    root_font_size: f32,
+    /// The font size of the element, in pixels (`em`, `ex` and `ch`)
+    font_size: f32,
+    /// The line height of the root element, in pixels (`rlh`)
+    root_line_height: Option<f32>,
+    /// The line height of the element, in pixels (`lh`)
+    line_height: Option<f32>,
+    /// The width and height of the viewport, in pixels
+    viewport: (f32, f32),
+    /// The width and height of the query container, in pixels
+    container: Option<(f32, f32)>,
+    /// The size percentages are relative to, in pixels
+    percentage_basis: Option<f32>,
+}
+
+impl LengthContext {
+    /// Creates a context for a viewport of the given size, with 16px fonts.
+    ///
+    /// # Arguments
+    ///
+    /// * `viewport_width` - The width of the viewport, in pixels
+    /// * `viewport_height` - The height of the viewport, in pixels
+    ///
+    /// # Returns
+    ///
+    /// A new `LengthContext` instance
+    pub fn new(viewport_width: f32, viewport_height: f32) -> Self {
+        Self {
+            root_font_size: 16.0,
+            font_size: 16.0,
+            root_line_height: None,
+            line_height: None,
+            viewport: (viewport_width, viewport_height),
+            container: None,
+            percentage_basis: None,
+        }
+    }
+
+    /// Sets the font size of the root element, which `rem` units are relative to.
+    ///
+    /// # Arguments
+    ///
+    /// * `px` - The root font size, in pixels
+    ///
+    /// # Returns
+    ///
+    /// The updated context
+    pub fn root_font_size(mut self, px: f32) -> Self {
+        self.root_font_size = px;
+        self
+    }
+
+    /// Sets the font size of the element, which `em`, `ex` and `ch` units are relative to.
+    ///
+    /// # Arguments
+    ///
+    /// * `px` - The font size, in pixels
+    ///
+    /// # Returns
+    ///
+    /// The updated context
+    pub fn font_size(mut self, px: f32) -> Self {
+        self.font_size = px;
+        self
+    }
+
+    /// Sets the line height of the root element, which `rlh` units are relative to.
+    ///
+    /// # Arguments
+    ///
+    /// * `px` - The root line height, in pixels
+    ///
+    /// # Returns
+    ///
+    /// The updated context
+    pub fn root_line_height(mut self, px: f32) -> Self {
+        self.root_line_height = Some(px);
+        self
+    }
+
+    /// Sets the line height of the element, which `lh` units are relative to.
+    ///
+    /// # Arguments
+    ///
+    /// * `px` - The line height, in pixels
+    ///
+    /// # Returns
+    ///
+    /// The updated context
+    pub fn line_height(mut self, px: f32) -> Self {
+        self.line_height = Some(px);
+        self
+    }
+
+    /// Sets the size of the query container, which container units (`cqw`, `cqh`, `cqi`
+    /// and `cqb`) are relative to. Without a container, they are relative to the viewport.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the container, in pixels
+    /// * `height` - The height of the container, in pixels
+    ///
+    /// # Returns
+    ///
+    /// The updated context
+    pub fn container(mut self, width: f32, height: f32) -> Self {
+        self.container = Some((width, height));
+        self
+    }
+
+    /// Sets the size percentages are relative to, such as the width of the containing
+    /// block for `width` or `padding`. Without it, percentages cannot be resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `px` - The size a percentage of 100% is equal to, in pixels
+    ///
+    /// # Returns
+    ///
+    /// The updated context
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::length::LengthContext;
+    /// use mew_css::values::Size;
+    ///
+    /// let context = LengthContext::new(1280.0, 800.0);
+    /// assert_eq!(Size::Percent(50.0).to_px(&context), None);
+    /// assert_eq!(Size::Percent(50.0).to_px(&context.percentage_basis(300.0)), Some(150.0));
+    /// ```
+    pub fn percentage_basis(mut self, px: f32) -> Self {
+        self.percentage_basis = Some(px);
+        self
+    }
+
+    /// Returns the number of pixels in one of the given unit, or `None` if the context
+    /// cannot resolve it.
+    fn unit_px(&self, unit: &str) -> Option<f64> {
+        let font_size = self.font_size as f64;
+        let root_font_size = self.root_font_size as f64;
+        let (viewport_width, viewport_height) = (self.viewport.0 as f64, self.viewport.1 as f64);
+        let (container_width, container_height) = self.container.unwrap_or(self.viewport);
+        let (container_width, container_height) = (container_width as f64, container_height as f64);
+
+        let px = match unit {
+            "px" => 1.0,
+            "cm" => PX_PER_IN / 2.54,
+            "mm" => PX_PER_IN / 25.4,
+            "Q" => PX_PER_IN / 101.6,
+            "in" => PX_PER_IN,
+            "pt" => PX_PER_IN / 72.0,
+            "pc" => PX_PER_IN / 6.0,
+            "%" => self.percentage_basis? as f64 / 100.0,
+            "em" => font_size,
+            "rem" => root_font_size,
+            // Without font metrics, CSS resolves `ex` and `ch` to half an `em`
+            "ex" | "ch" => font_size / 2.0,
+            "lh" => self.line_height.map_or(font_size * NORMAL_LINE_HEIGHT, f64::from),
+            "rlh" => self.root_line_height.map_or(root_font_size * NORMAL_LINE_HEIGHT, f64::from),
+            // The small, large and dynamic viewports are the same size outside of a browser
+            "vw" | "dvw" | "svw" | "lvw" => viewport_width / 100.0,
+            "vh" | "dvh" | "svh" | "lvh" => viewport_height / 100.0,
+            "vmin" => viewport_width.min(viewport_height) / 100.0,
+            "vmax" => viewport_width.max(viewport_height) / 100.0,
+            // The inline and block axes are horizontal and vertical in horizontal writing modes
+            "cqw" | "cqi" => container_width / 100.0,
+            "cqh" | "cqb" => container_height / 100.0,
+            _ => return None,
+        };
+        Some(px)
+    }
+
+    /// Resolves an expression to a number, with lengths in pixels and angles in radians.
+    fn resolve(&self, calc: &Calc) -> Option<f64> {
+        match calc {
+            Calc::Number(n) => Some(*n as f64),
+            Calc::Length(size) => size.to_px(self).map(f64::from),
+            Calc::Angle(angle) => angle.radians(),
+            Calc::Var(_) => None,
+            Calc::Sum(terms) => terms.iter().map(|term| self.resolve(term)).sum(),
+            Calc::Negate(term) => self.resolve(term).map(|val| -val),
+            Calc::Product(a, b) => Some(self.resolve(a)? * self.resolve(b)?),
+            Calc::Quotient(a, b) => Some(self.resolve(a)? / self.resolve(b)?),
+            Calc::Function(function, args) => {
+                let values: Vec<f64> = args.iter().map(|arg| self.resolve(arg)).collect::<Option<_>>()?;
+                function.apply(&values)
+            }
+        }
+    }
+}
+
+impl Size {
+    /// Resolves the size to pixels.
+    ///
+    /// `calc()` expressions and math functions are computed with their arguments
+    /// resolved to pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The font sizes, viewport size and other values relative lengths
+    ///   are resolved against
+    ///
+    /// # Returns
+    ///
+    /// The size in pixels, or `None` for `Auto`, CSS variables, and percentages without
+    /// a percentage basis
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::length::LengthContext;
+    /// use mew_css::values::Size;
+    ///
+    /// let context = LengthContext::new(1280.0, 800.0).percentage_basis(1000.0);
+    /// let width = Size::Percent(100.0) - Size::Rem(2.0);
+    ///
+    /// assert_eq!(width.to_px(&context), Some(968.0));
+    /// assert_eq!(Size::In(0.5).to_px(&context), Some(48.0));
+    /// ```
+    pub fn to_px(&self, context: &LengthContext) -> Option<f32> {
+        let px = match self {
+            Size::Zero => 0.0,
+            Size::Auto | Size::Var(_) => return None,
+            // Only lengths can be resolved to pixels
+            Size::Calc(calc) if calc.kind() != Some(Kind::Length) => return None,
+            Size::Calc(calc) => context.resolve(calc)?,
+            size => {
+                let (val, unit) = size.length()?;
+                val as f64 * context.unit_px(unit)?
+            }
+        };
+        // Infinite and NaN results, such as a division by zero, cannot be resolved
+        px.is_finite().then_some(px as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::RoundingStrategy;
+    use crate::values::Angle;
+    use crate::variable::var;
+
+    #[test]
+    fn test_units() {
+        let context = LengthContext::new(1000.0, 500.0)
+            .root_font_size(10.0)
+            .font_size(20.0)
+            .container(300.0, 200.0)
+            .percentage_basis(400.0);
+        let px = |size: Size| size.to_px(&context);
+
+        assert_eq!(px(Size::Zero), Some(0.0));
+        assert_eq!(px(Size::Px(12.5)), Some(12.5));
+        assert_eq!(px(Size::Cm(2.54)), Some(96.0));
+        assert_eq!(px(Size::Mm(25.4)), Some(96.0));
+        assert_eq!(px(Size::Q(101.6)), Some(96.0));
+        assert_eq!(px(Size::Pt(72.0)), Some(96.0));
+        assert_eq!(px(Size::Pc(6.0)), Some(96.0));
+        assert_eq!(px(Size::Percent(50.0)), Some(200.0));
+        assert_eq!(px(Size::Em(2.0)), Some(40.0));
+        assert_eq!(px(Size::Rem(2.0)), Some(20.0));
+        assert_eq!(px(Size::Ex(1.0)), Some(10.0));
+        assert_eq!(px(Size::Ch(2.0)), Some(20.0));
+        assert_eq!(px(Size::Lh(1.0)), Some(24.0));
+        assert_eq!(px(Size::Rlh(1.0)), Some(12.0));
+        assert_eq!(px(Size::Dvw(10.0)), Some(100.0));
+        assert_eq!(px(Size::Svh(10.0)), Some(50.0));
+        assert_eq!(px(Size::Vmin(10.0)), Some(50.0));
+        assert_eq!(px(Size::Vmax(10.0)), Some(100.0));
+        assert_eq!(px(Size::Cqw(10.0)), Some(30.0));
+        assert_eq!(px(Size::Cqb(10.0)), Some(20.0));
+
+        let context = context.line_height(30.0).root_line_height(15.0);
+        assert_eq!(Size::Lh(2.0).to_px(&context), Some(60.0));
+        assert_eq!(Size::Rlh(2.0).to_px(&context), Some(30.0));
+
+        // Container units fall back to the viewport
+        assert_eq!(Size::Cqh(10.0).to_px(&LengthContext::new(1000.0, 500.0)), Some(50.0));
+    }
+
+    #[test]
+    fn test_unresolvable() {
+        let context = LengthContext::new(1280.0, 800.0);
+
+        assert_eq!(Size::Auto.to_px(&context), None);
+        assert_eq!(Size::Var(var("gap")).to_px(&context), None);
+        assert_eq!(Size::Percent(10.0).to_px(&context), None);
+        assert_eq!((Size::Px(10.0) + Size::Var(var("gap"))).to_px(&context), None);
+        assert_eq!((Size::Percent(50.0) + Size::Px(10.0)).to_px(&context), None);
+        assert_eq!(Size::from(Calc::modulo(Size::Px(10.0), Size::Em(0.0)).unwrap()).to_px(&context), None);
+    }
+
+    #[test]
+    fn test_expressions() {
+        let context = LengthContext::new(1280.0, 800.0).percentage_basis(600.0);
+        let px = |calc: Calc| Size::from(calc).to_px(&context);
+
+        assert_eq!((Size::Percent(50.0) - Size::Px(20.0)).to_px(&context), Some(280.0));
+        assert_eq!((Size::Rem(1.0) * 3.0 / 2.0).to_px(&context), Some(24.0));
+        assert_eq!(px(Calc::min([Size::Percent(100.0), Size::Px(480.0)]).unwrap()), Some(480.0));
+        assert_eq!(px(Calc::max([Size::Vw(10.0), Size::Rem(10.0)]).unwrap()), Some(160.0));
+        assert_eq!(px(Calc::fluid(16.0, 24.0, 320.0, 1280.0)), Some(24.0));
+        assert_eq!(px(Calc::round(RoundingStrategy::Up, Size::Vw(1.0), Size::Px(5.0)).unwrap()), Some(15.0));
+        assert_eq!(px(Calc::from(Size::Em(2.0)) * Calc::cos(Angle::Deg(60.0)).unwrap()), Some(16.0));
+        assert_eq!(px(Calc::hypot([Size::Px(30.0), Size::Em(2.5)]).unwrap()), Some(50.0));
+
+        // Expressions parsed from CSS resolve the same way
+        let size: Size = "clamp(1rem, 2.5vw, 2rem)".parse().unwrap();
+        assert_eq!(size.to_px(&context), Some(32.0));
+        assert_eq!(size.to_px(&LengthContext::new(800.0, 600.0)), Some(20.0));
+    }
+}
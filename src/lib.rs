@@ -20,13 +20,43 @@
 //! - `values`: CSS value types (Color, Size, Display, etc.)
 //! - `properties`: CSS property definitions
 //! - `variable`: CSS variables support
+//! - `rule`: Selector-scoped CSS rules
+//! - `at_rule`: Generic CSS at-rules (`@import`, `@media`, ...)
+//! - `stylesheet`: Ordered collections of rules and at-rules rendered as a full CSS file
+//! - `media`: Typed media queries for `@media` rules
+//! - `supports`: Typed feature queries for `@supports` rules
+//! - `container`: Typed container queries for `@container` rules
+//! - `format`: Output options for pretty, compact and minified CSS
+//! - `keyframes`: `@keyframes` rules referenced by the `animation` property
+//! - `font_face`: `@font-face` rules for self-hosted fonts
+//! - `parser`: Parsing of CSS declaration blocks into styles
+//! - `error`: Errors and validation issues for invalid values
+//! - `color`: Resolution of colors to sRGB components, color space conversions and color operations
+//! - `calc`: Typed `calc()` expressions and CSS math functions (`min()`, `clamp()`, `round()`, `sin()`, ...)
+//! - `length`: Resolution of lengths and `calc()` expressions to pixels
 
 // Make modules public
 pub mod style;
 pub mod values;
 pub mod properties;
 pub mod variable;
+pub mod rule;
+pub mod at_rule;
+pub mod stylesheet;
+pub mod media;
+pub mod supports;
+pub mod container;
+pub mod format;
+pub mod keyframes;
+pub mod font_face;
+pub mod parser;
+pub mod error;
+pub mod color;
+pub mod calc;
+pub mod length;
 
 // Re-export the main API entry point
 pub use style::style;
 pub use variable::{CssVar, var};
+pub use rule::{Rule, rule};
+pub use stylesheet::{Stylesheet, stylesheet};
@@ -0,0 +1,1030 @@
+//! # Media Query Module
+//!
+//! This module provides the `MediaQuery` type, a typed builder for the conditions used
+//! by `@media` rules. Media queries are built from media types (`screen`, `print`) and
+//! media features (`min-width`, `prefers-color-scheme`, ...), and can be combined with
+//! `and`, `or` and `not`.
+//!
+//! Lengths reuse the `Size` type from the `values` module, and pixel densities the
+//! `Resolution` type. Note that CSS variables are not allowed inside media queries, so
+//! `Size::Var` and `Resolution::Var` should not be used.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::media::{ColorScheme, MediaQuery};
+//! use mew_css::values::Size;
+//!
+//! let query = MediaQuery::screen().and(MediaQuery::min_width(Size::Px(768.0)));
+//! assert_eq!(query.to_string(), "screen and (min-width: 768px)");
+//!
+//! let query = MediaQuery::width_between(Size::Px(400.0), Size::Px(700.0));
+//! assert_eq!(query.to_string(), "(400px <= width <= 700px)");
+//!
+//! let query = MediaQuery::prefers_color_scheme(ColorScheme::Dark)
+//!     .or(MediaQuery::max_width(Size::Px(600.0)));
+//! assert_eq!(query.to_string(), "(prefers-color-scheme: dark) or (max-width: 600px)");
+//! ```
+//!
+//! Media queries are usually attached to a style with `Style::media`:
+//!
+//! ```rust
+//! use mew_css::{rule, style};
+//! use mew_css::media::MediaQuery;
+//! use mew_css::values::{AlignItems, Size};
+//!
+//! let css = rule(".nav", style()
+//!     .align_items(AlignItems::Start)
+//!     .media(MediaQuery::min_width(Size::Px(768.0)), |s| s.align_items(AlignItems::Center)))
+//!     .to_string();
+//!
+//! assert_eq!(css, ".nav { align-items: start; } \
+//!                  @media (min-width: 768px) { .nav { align-items: center; } }");
+//! ```
+
+use crate::error::MewError;
+use crate::values::{Resolution, Size};
+use std::fmt;
+use std::ops::Not;
+
+/// Media types describing the general category of a device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MediaType {
+    /// Suitable for all devices
+    All,
+    /// Intended primarily for screens
+    Screen,
+    /// Intended for paged material and documents viewed in print preview mode
+    Print,
+}
+
+impl fmt::Display for MediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MediaType::All => write!(f, "all"),
+            MediaType::Screen => write!(f, "screen"),
+            MediaType::Print => write!(f, "print"),
+        }
+    }
+}
+
+/// Comparison operators used by the range syntax of media features.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    /// Strictly less than (`<`)
+    Lt,
+    /// Less than or equal to (`<=`)
+    Le,
+    /// Strictly greater than (`>`)
+    Gt,
+    /// Greater than or equal to (`>=`)
+    Ge,
+    /// Equal to (`=`)
+    Eq,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Comparison::Lt => write!(f, "<"),
+            Comparison::Le => write!(f, "<="),
+            Comparison::Gt => write!(f, ">"),
+            Comparison::Ge => write!(f, ">="),
+            Comparison::Eq => write!(f, "="),
+        }
+    }
+}
+
+/// Media features that can be tested with the range syntax.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RangeFeature {
+    /// The width of the viewport
+    Width,
+    /// The height of the viewport
+    Height,
+}
+
+impl fmt::Display for RangeFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeFeature::Width => write!(f, "width"),
+            RangeFeature::Height => write!(f, "height"),
+        }
+    }
+}
+
+/// Orientation of the viewport.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Orientation {
+    /// The viewport is taller than it is wide
+    Portrait,
+    /// The viewport is wider than it is tall
+    Landscape,
+}
+
+impl fmt::Display for Orientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Orientation::Portrait => write!(f, "portrait"),
+            Orientation::Landscape => write!(f, "landscape"),
+        }
+    }
+}
+
+/// Color scheme requested by the user.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColorScheme {
+    /// The user prefers a light theme
+    Light,
+    /// The user prefers a dark theme
+    Dark,
+}
+
+impl fmt::Display for ColorScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorScheme::Light => write!(f, "light"),
+            ColorScheme::Dark => write!(f, "dark"),
+        }
+    }
+}
+
+/// Motion preference of the user.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReducedMotion {
+    /// The user has made no preference known
+    NoPreference,
+    /// The user prefers less motion
+    Reduce,
+}
+
+impl fmt::Display for ReducedMotion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReducedMotion::NoPreference => write!(f, "no-preference"),
+            ReducedMotion::Reduce => write!(f, "reduce"),
+        }
+    }
+}
+
+/// Hover capability of the primary input mechanism.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Hover {
+    /// The primary input mechanism cannot hover over elements
+    None,
+    /// The primary input mechanism can hover over elements
+    Hover,
+}
+
+impl fmt::Display for Hover {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hover::None => write!(f, "none"),
+            Hover::Hover => write!(f, "hover"),
+        }
+    }
+}
+
+/// Accuracy of the primary pointing device.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pointer {
+    /// The primary input mechanism does not include a pointing device
+    None,
+    /// The primary pointing device is of limited accuracy, such as a finger
+    Coarse,
+    /// The primary pointing device is accurate, such as a mouse
+    Fine,
+}
+
+impl fmt::Display for Pointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Pointer::None => write!(f, "none"),
+            Pointer::Coarse => write!(f, "coarse"),
+            Pointer::Fine => write!(f, "fine"),
+        }
+    }
+}
+
+/// A single media feature test, rendered inside parentheses.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MediaFeature {
+    /// Minimum viewport width (`min-width`)
+    MinWidth(Size),
+    /// Maximum viewport width (`max-width`)
+    MaxWidth(Size),
+    /// Minimum viewport height (`min-height`)
+    MinHeight(Size),
+    /// Maximum viewport height (`max-height`)
+    MaxHeight(Size),
+    /// Minimum pixel density of the output device (`min-resolution`)
+    MinResolution(Resolution),
+    /// Maximum pixel density of the output device (`max-resolution`)
+    MaxResolution(Resolution),
+    /// Range syntax test (e.g., `width >= 600px` or `400px <= width <= 700px`)
+    Range {
+        /// The feature being compared
+        feature: RangeFeature,
+        /// Value and operator written before the feature name
+        lower: Option<(Size, Comparison)>,
+        /// Operator and value written after the feature name
+        upper: Option<(Comparison, Size)>,
+    },
+    /// Orientation of the viewport (`orientation`)
+    Orientation(Orientation),
+    /// Preferred color scheme (`prefers-color-scheme`)
+    PrefersColorScheme(ColorScheme),
+    /// Preferred amount of motion (`prefers-reduced-motion`)
+    PrefersReducedMotion(ReducedMotion),
+    /// Hover capability of the primary input (`hover`)
+    Hover(Hover),
+    /// Accuracy of the primary pointing device (`pointer`)
+    Pointer(Pointer),
+    /// Any other feature, written as is without the surrounding parentheses
+    Custom(String),
+}
+
+impl fmt::Display for MediaFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MediaFeature::MinWidth(size) => write!(f, "(min-width: {})", size),
+            MediaFeature::MaxWidth(size) => write!(f, "(max-width: {})", size),
+            MediaFeature::MinHeight(size) => write!(f, "(min-height: {})", size),
+            MediaFeature::MaxHeight(size) => write!(f, "(max-height: {})", size),
+            MediaFeature::MinResolution(value) => write!(f, "(min-resolution: {})", value),
+            MediaFeature::MaxResolution(value) => write!(f, "(max-resolution: {})", value),
+            MediaFeature::Range { feature, lower, upper } => {
+                write!(f, "(")?;
+                if let Some((value, op)) = lower {
+                    write!(f, "{} {} ", value, op)?;
+                }
+                write!(f, "{}", feature)?;
+                if let Some((op, value)) = upper {
+                    write!(f, " {} {}", op, value)?;
+                }
+                write!(f, ")")
+            }
+            MediaFeature::Orientation(value) => write!(f, "(orientation: {})", value),
+            MediaFeature::PrefersColorScheme(value) => write!(f, "(prefers-color-scheme: {})", value),
+            MediaFeature::PrefersReducedMotion(value) => write!(f, "(prefers-reduced-motion: {})", value),
+            MediaFeature::Hover(value) => write!(f, "(hover: {})", value),
+            MediaFeature::Pointer(value) => write!(f, "(pointer: {})", value),
+            MediaFeature::Custom(value) => write!(f, "({})", value),
+        }
+    }
+}
+
+/// A media query, composed of media types and media features.
+///
+/// Media queries are built with the constructor functions (`screen`, `min_width`, ...)
+/// and combined with `and`, `or` and `!` (negation). When rendered, parentheses are
+/// added where the grammar requires them.
+///
+/// Media types and their negation (`not print`) can only be written at the top level
+/// of a media query list, so some combinations have no CSS equivalent, such as a
+/// negated list of media types or `(min-width: 1px) and not print`. These queries are
+/// reported by `validate` and rejected by `try_and`, `try_or` and `try_negate`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::media::{MediaQuery, Pointer};
+///
+/// let query = !MediaQuery::pointer(Pointer::Fine);
+/// assert_eq!(query.to_string(), "not (pointer: fine)");
+///
+/// let query = MediaQuery::print().or(MediaQuery::screen());
+/// assert_eq!(query.to_string(), "print, screen");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum MediaQuery {
+    /// A media type such as `screen`
+    Type(MediaType),
+    /// A media feature test such as `(min-width: 768px)`
+    Feature(MediaFeature),
+    /// All the queries must match
+    And(Vec<MediaQuery>),
+    /// At least one of the queries must match
+    Or(Vec<MediaQuery>),
+    /// The query must not match
+    Not(Box<MediaQuery>),
+}
+
+impl MediaQuery {
+    /// Creates a query matching all devices (`all`).
+    pub fn all() -> Self {
+        MediaQuery::Type(MediaType::All)
+    }
+
+    /// Creates a query matching screens (`screen`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    ///
+    /// assert_eq!(MediaQuery::screen().to_string(), "screen");
+    /// ```
+    pub fn screen() -> Self {
+        MediaQuery::Type(MediaType::Screen)
+    }
+
+    /// Creates a query matching printed documents (`print`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    ///
+    /// assert_eq!(MediaQuery::print().to_string(), "print");
+    /// ```
+    pub fn print() -> Self {
+        MediaQuery::Type(MediaType::Print)
+    }
+
+    /// Creates a query matching viewports at least as wide as `size`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    /// use mew_css::values::Size;
+    ///
+    /// assert_eq!(MediaQuery::min_width(Size::Em(48.0)).to_string(), "(min-width: 48em)");
+    /// ```
+    pub fn min_width(size: Size) -> Self {
+        MediaQuery::Feature(MediaFeature::MinWidth(size))
+    }
+
+    /// Creates a query matching viewports at most as wide as `size`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    /// use mew_css::values::Size;
+    ///
+    /// assert_eq!(MediaQuery::max_width(Size::Px(600.0)).to_string(), "(max-width: 600px)");
+    /// ```
+    pub fn max_width(size: Size) -> Self {
+        MediaQuery::Feature(MediaFeature::MaxWidth(size))
+    }
+
+    /// Creates a query matching viewports at least as tall as `size`.
+    pub fn min_height(size: Size) -> Self {
+        MediaQuery::Feature(MediaFeature::MinHeight(size))
+    }
+
+    /// Creates a query matching viewports at most as tall as `size`.
+    pub fn max_height(size: Size) -> Self {
+        MediaQuery::Feature(MediaFeature::MaxHeight(size))
+    }
+
+    /// Creates a `min-resolution` query, for instance to serve high-density images.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    /// use mew_css::values::Resolution;
+    ///
+    /// let query = MediaQuery::min_resolution(Resolution::Dppx(2.0));
+    /// assert_eq!(query.to_string(), "(min-resolution: 2dppx)");
+    /// ```
+    pub fn min_resolution(value: Resolution) -> Self {
+        MediaQuery::Feature(MediaFeature::MinResolution(value))
+    }
+
+    /// Creates a `max-resolution` query.
+    pub fn max_resolution(value: Resolution) -> Self {
+        MediaQuery::Feature(MediaFeature::MaxResolution(value))
+    }
+
+    /// Creates a range query comparing the viewport width to a value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::{Comparison, MediaQuery};
+    /// use mew_css::values::Size;
+    ///
+    /// let query = MediaQuery::width(Comparison::Ge, Size::Px(600.0));
+    /// assert_eq!(query.to_string(), "(width >= 600px)");
+    /// ```
+    pub fn width(op: Comparison, size: Size) -> Self {
+        MediaQuery::range(RangeFeature::Width, None, Some((op, size)))
+    }
+
+    /// Creates a range query comparing the viewport height to a value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::{Comparison, MediaQuery};
+    /// use mew_css::values::Size;
+    ///
+    /// let query = MediaQuery::height(Comparison::Lt, Size::Px(500.0));
+    /// assert_eq!(query.to_string(), "(height < 500px)");
+    /// ```
+    pub fn height(op: Comparison, size: Size) -> Self {
+        MediaQuery::range(RangeFeature::Height, None, Some((op, size)))
+    }
+
+    /// Creates a range query matching viewport widths between `min` and `max`, inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    /// use mew_css::values::Size;
+    ///
+    /// let query = MediaQuery::width_between(Size::Px(400.0), Size::Px(700.0));
+    /// assert_eq!(query.to_string(), "(400px <= width <= 700px)");
+    /// ```
+    pub fn width_between(min: Size, max: Size) -> Self {
+        MediaQuery::range(RangeFeature::Width, Some((min, Comparison::Le)), Some((Comparison::Le, max)))
+    }
+
+    /// Creates a range query matching viewport heights between `min` and `max`, inclusive.
+    pub fn height_between(min: Size, max: Size) -> Self {
+        MediaQuery::range(RangeFeature::Height, Some((min, Comparison::Le)), Some((Comparison::Le, max)))
+    }
+
+    /// Creates a range query with explicit bounds on either side of the feature name.
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - The feature being compared
+    /// * `lower` - Value and operator written before the feature name
+    /// * `upper` - Operator and value written after the feature name
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::{Comparison, MediaQuery, RangeFeature};
+    /// use mew_css::values::Size;
+    ///
+    /// let query = MediaQuery::range(
+    ///     RangeFeature::Width,
+    ///     Some((Size::Px(400.0), Comparison::Lt)),
+    ///     Some((Comparison::Le, Size::Px(700.0))),
+    /// );
+    /// assert_eq!(query.to_string(), "(400px < width <= 700px)");
+    /// ```
+    pub fn range(feature: RangeFeature, lower: Option<(Size, Comparison)>, upper: Option<(Comparison, Size)>) -> Self {
+        MediaQuery::Feature(MediaFeature::Range { feature, lower, upper })
+    }
+
+    /// Creates a query on the orientation of the viewport.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::{MediaQuery, Orientation};
+    ///
+    /// let query = MediaQuery::orientation(Orientation::Landscape);
+    /// assert_eq!(query.to_string(), "(orientation: landscape)");
+    /// ```
+    pub fn orientation(value: Orientation) -> Self {
+        MediaQuery::Feature(MediaFeature::Orientation(value))
+    }
+
+    /// Creates a query on the color scheme preferred by the user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::{ColorScheme, MediaQuery};
+    ///
+    /// let query = MediaQuery::prefers_color_scheme(ColorScheme::Dark);
+    /// assert_eq!(query.to_string(), "(prefers-color-scheme: dark)");
+    /// ```
+    pub fn prefers_color_scheme(value: ColorScheme) -> Self {
+        MediaQuery::Feature(MediaFeature::PrefersColorScheme(value))
+    }
+
+    /// Creates a query on the motion preference of the user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::{MediaQuery, ReducedMotion};
+    ///
+    /// let query = MediaQuery::prefers_reduced_motion(ReducedMotion::Reduce);
+    /// assert_eq!(query.to_string(), "(prefers-reduced-motion: reduce)");
+    /// ```
+    pub fn prefers_reduced_motion(value: ReducedMotion) -> Self {
+        MediaQuery::Feature(MediaFeature::PrefersReducedMotion(value))
+    }
+
+    /// Creates a query on the hover capability of the primary input mechanism.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::{Hover, MediaQuery};
+    ///
+    /// assert_eq!(MediaQuery::hover(Hover::Hover).to_string(), "(hover: hover)");
+    /// ```
+    pub fn hover(value: Hover) -> Self {
+        MediaQuery::Feature(MediaFeature::Hover(value))
+    }
+
+    /// Creates a query on the accuracy of the primary pointing device.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::{MediaQuery, Pointer};
+    ///
+    /// assert_eq!(MediaQuery::pointer(Pointer::Coarse).to_string(), "(pointer: coarse)");
+    /// ```
+    pub fn pointer(value: Pointer) -> Self {
+        MediaQuery::Feature(MediaFeature::Pointer(value))
+    }
+
+    /// Creates a query on a feature not covered by the typed constructors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    ///
+    /// let query = MediaQuery::feature("forced-colors: active");
+    /// assert_eq!(query.to_string(), "(forced-colors: active)");
+    /// ```
+    pub fn feature(value: &str) -> Self {
+        MediaQuery::Feature(MediaFeature::Custom(value.trim().to_string()))
+    }
+
+    /// Combines this query with another one; both must match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::{MediaQuery, Orientation};
+    /// use mew_css::values::Size;
+    ///
+    /// let query = MediaQuery::screen()
+    ///     .and(MediaQuery::min_width(Size::Px(768.0)))
+    ///     .and(MediaQuery::orientation(Orientation::Portrait));
+    /// assert_eq!(query.to_string(), "screen and (min-width: 768px) and (orientation: portrait)");
+    /// ```
+    pub fn and(self, other: MediaQuery) -> Self {
+        match (self, other) {
+            (MediaQuery::And(mut queries), MediaQuery::And(others)) => {
+                queries.extend(others);
+                MediaQuery::And(queries)
+            }
+            (MediaQuery::And(mut queries), other) => {
+                queries.push(other);
+                MediaQuery::And(queries)
+            }
+            (query, other) => MediaQuery::And(vec![query, other]),
+        }
+    }
+
+    /// Combines this query with another one; at least one must match.
+    ///
+    /// Feature conditions are joined with `or`, while alternatives involving media types
+    /// are rendered as a comma-separated media query list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::{Hover, MediaQuery, Pointer};
+    ///
+    /// let query = MediaQuery::hover(Hover::None).or(MediaQuery::pointer(Pointer::Coarse));
+    /// assert_eq!(query.to_string(), "(hover: none) or (pointer: coarse)");
+    /// ```
+    pub fn or(self, other: MediaQuery) -> Self {
+        match self {
+            MediaQuery::Or(mut queries) => {
+                queries.push(other);
+                MediaQuery::Or(queries)
+            }
+            query => MediaQuery::Or(vec![query, other]),
+        }
+    }
+
+    /// Negates this query. This is equivalent to the `!` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    ///
+    /// assert_eq!(MediaQuery::print().negate().to_string(), "not print");
+    /// ```
+    pub fn negate(self) -> Self {
+        MediaQuery::Not(Box::new(self))
+    }
+
+    /// Combines this query with another one like `and`, unless the result has no CSS
+    /// equivalent.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The query that must also match
+    ///
+    /// # Returns
+    ///
+    /// The combined query, or a `MewError::InvalidMediaQuery` error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    /// use mew_css::values::Size;
+    ///
+    /// assert!(MediaQuery::min_width(Size::Px(600.0)).try_and(MediaQuery::screen()).is_ok());
+    /// assert!(MediaQuery::min_width(Size::Px(600.0)).try_and(!MediaQuery::print()).is_err());
+    /// ```
+    pub fn try_and(self, other: MediaQuery) -> Result<Self, MewError> {
+        let query = self.and(other);
+        query.validate().map(|_| query)
+    }
+
+    /// Combines this query with another one like `or`, unless the result has no CSS
+    /// equivalent.
+    pub fn try_or(self, other: MediaQuery) -> Result<Self, MewError> {
+        let query = self.or(other);
+        query.validate().map(|_| query)
+    }
+
+    /// Negates this query like `negate`, unless the result has no CSS equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    ///
+    /// assert!(MediaQuery::print().or(MediaQuery::screen()).try_negate().is_err());
+    /// ```
+    pub fn try_negate(self) -> Result<Self, MewError> {
+        let query = self.negate();
+        query.validate().map(|_| query)
+    }
+
+    /// Checks that this query can be written as a media query list.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the query is valid, or a `MewError::InvalidMediaQuery` error if a
+    /// media type or its negation is nested inside a condition, if a list of media
+    /// types is negated or combined with `and`, or if two different media types must
+    /// both match
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::media::MediaQuery;
+    /// use mew_css::values::Size;
+    ///
+    /// let query = MediaQuery::screen().and(MediaQuery::print().or(MediaQuery::min_width(Size::Px(1.0))));
+    /// assert!(query.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), MewError> {
+        match self.entries() {
+            Some(_) => Ok(()),
+            None => Err(MewError::InvalidMediaQuery(self.to_string())),
+        }
+    }
+
+    /// Returns `true` if this query contains a media type, which means it can only
+    /// appear at the top level of a media query list.
+    fn has_media_type(&self) -> bool {
+        match self {
+            MediaQuery::Type(_) => true,
+            MediaQuery::Feature(_) => false,
+            MediaQuery::And(queries) | MediaQuery::Or(queries) => queries.iter().any(MediaQuery::has_media_type),
+            MediaQuery::Not(query) => query.has_media_type(),
+        }
+    }
+
+    /// Returns the queries of the media query list this query is written as, or `None`
+    /// if it cannot be written as a media query list.
+    fn entries(&self) -> Option<Vec<ListEntry>> {
+        let entries = match self {
+            MediaQuery::Type(media_type) => vec![ListEntry::new(Some(*media_type), None)],
+            MediaQuery::Or(queries) if self.has_media_type() => {
+                queries.iter().map(MediaQuery::entries).collect::<Option<Vec<_>>>()?.concat()
+            }
+            // Each operand must be a single query, since lists cannot be nested
+            MediaQuery::And(queries) if self.has_media_type() => {
+                let entry = queries.iter().try_fold(ListEntry::new(None, None), |entry, query| {
+                    match query.entries()?.as_slice() {
+                        [other] => entry.and(other),
+                        _ => None,
+                    }
+                })?;
+                vec![entry]
+            }
+            MediaQuery::Not(query) if query.has_media_type() => match query.entries()?.as_slice() {
+                [entry] => vec![ListEntry {
+                    negated: !entry.negated,
+                    ..entry.clone()
+                }],
+                _ => return None,
+            },
+            condition => vec![ListEntry::new(None, Some(condition.clone()))],
+        };
+        Some(entries)
+    }
+
+    /// Writes this query as a media feature condition, with media types written in place.
+    fn write_condition(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MediaQuery::Type(media_type) => write!(f, "{}", media_type),
+            MediaQuery::Feature(feature) => write!(f, "{}", feature),
+            MediaQuery::And(queries) | MediaQuery::Or(queries) => {
+                let separator = if matches!(self, MediaQuery::And(_)) { " and " } else { " or " };
+                for (i, query) in queries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "{}", separator)?;
+                    }
+                    query.write_operand(f)?;
+                }
+                Ok(())
+            }
+            MediaQuery::Not(query) => {
+                write!(f, "not ")?;
+                query.write_operand(f)
+            }
+        }
+    }
+
+    /// Writes this query as an operand of `and`, `or` or `not`, wrapping it in
+    /// parentheses when it is a compound condition.
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MediaQuery::Type(_) | MediaQuery::Feature(_) => self.write_condition(f),
+            _ => {
+                write!(f, "(")?;
+                self.write_condition(f)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// A query of a media query list: an optionally negated media type, followed by a
+/// condition on media features (e.g., `not screen and (hover: hover)`).
+#[derive(Debug, Clone, PartialEq)]
+struct ListEntry {
+    /// Whether the whole query is negated with a leading `not`
+    negated: bool,
+    /// The media type, if any
+    media_type: Option<MediaType>,
+    /// The condition, which contains no media types
+    condition: Option<MediaQuery>,
+}
+
+impl ListEntry {
+    fn new(media_type: Option<MediaType>, condition: Option<MediaQuery>) -> Self {
+        Self {
+            negated: false,
+            media_type,
+            condition,
+        }
+    }
+
+    /// Returns the query matching both queries, or `None` if there is none.
+    fn and(&self, other: &ListEntry) -> Option<ListEntry> {
+        if *self == ListEntry::new(None, None) {
+            return Some(other.clone());
+        }
+        // A leading `not` negates the whole query, so a negated query cannot be extended
+        if self.negated || other.negated {
+            return None;
+        }
+
+        let media_type = match (self.media_type, other.media_type) {
+            (Some(MediaType::All), media_type) | (media_type, Some(MediaType::All)) => {
+                media_type.or(Some(MediaType::All))
+            }
+            (Some(a), Some(b)) if a != b => return None,
+            (a, b) => a.or(b),
+        };
+        let condition = match (&self.condition, &other.condition) {
+            (Some(a), Some(b)) => Some(a.clone().and(b.clone())),
+            (a, b) => a.clone().or(b.clone()),
+        };
+        Some(ListEntry::new(media_type, condition))
+    }
+}
+
+impl fmt::Display for ListEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negated {
+            write!(f, "not ")?;
+        }
+        match (&self.media_type, &self.condition) {
+            (Some(media_type), Some(condition)) => {
+                write!(f, "{} and ", media_type)?;
+                // A condition following a media type cannot use `or` without parentheses
+                match condition {
+                    MediaQuery::Or(_) => condition.write_operand(f),
+                    _ => condition.write_condition(f),
+                }
+            }
+            (Some(media_type), None) => write!(f, "{}", media_type),
+            (None, Some(condition)) => condition.write_condition(f),
+            (None, None) => write!(f, "{}", MediaType::All),
+        }
+    }
+}
+
+impl Not for MediaQuery {
+    type Output = MediaQuery;
+
+    fn not(self) -> Self::Output {
+        self.negate()
+    }
+}
+
+impl fmt::Display for MediaQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Queries with media types are written as a media query list, and the invalid
+        // ones (see `validate`) as given
+        match self.has_media_type().then(|| self.entries()).flatten() {
+            Some(entries) => {
+                for (i, entry) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", entry)?;
+                }
+                Ok(())
+            }
+            _ => self.write_condition(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_types() {
+        assert_eq!(MediaQuery::all().to_string(), "all");
+        assert_eq!(MediaQuery::screen().to_string(), "screen");
+        assert_eq!(MediaQuery::print().to_string(), "print");
+    }
+
+    #[test]
+    fn test_size_features() {
+        assert_eq!(MediaQuery::min_width(Size::Px(768.0)).to_string(), "(min-width: 768px)");
+        assert_eq!(MediaQuery::max_width(Size::Em(40.0)).to_string(), "(max-width: 40em)");
+        assert_eq!(MediaQuery::min_height(Size::Px(300.0)).to_string(), "(min-height: 300px)");
+        assert_eq!(MediaQuery::max_height(Size::Rem(50.0)).to_string(), "(max-height: 50rem)");
+    }
+
+    #[test]
+    fn test_resolution_features() {
+        assert_eq!(MediaQuery::min_resolution(Resolution::Dpi(192.0)).to_string(), "(min-resolution: 192dpi)");
+        assert_eq!(MediaQuery::max_resolution(Resolution::X(1.5)).to_string(), "(max-resolution: 1.5x)");
+    }
+
+    #[test]
+    fn test_range_features() {
+        assert_eq!(
+            MediaQuery::width_between(Size::Px(400.0), Size::Px(700.0)).to_string(),
+            "(400px <= width <= 700px)"
+        );
+        assert_eq!(
+            MediaQuery::width(Comparison::Gt, Size::Px(1024.0)).to_string(),
+            "(width > 1024px)"
+        );
+        assert_eq!(
+            MediaQuery::height(Comparison::Eq, Size::Px(600.0)).to_string(),
+            "(height = 600px)"
+        );
+        assert_eq!(
+            MediaQuery::range(RangeFeature::Height, Some((Size::Px(200.0), Comparison::Lt)), None).to_string(),
+            "(200px < height)"
+        );
+    }
+
+    #[test]
+    fn test_preference_features() {
+        assert_eq!(
+            MediaQuery::orientation(Orientation::Portrait).to_string(),
+            "(orientation: portrait)"
+        );
+        assert_eq!(
+            MediaQuery::prefers_color_scheme(ColorScheme::Light).to_string(),
+            "(prefers-color-scheme: light)"
+        );
+        assert_eq!(
+            MediaQuery::prefers_reduced_motion(ReducedMotion::NoPreference).to_string(),
+            "(prefers-reduced-motion: no-preference)"
+        );
+        assert_eq!(MediaQuery::hover(Hover::None).to_string(), "(hover: none)");
+        assert_eq!(MediaQuery::pointer(Pointer::Fine).to_string(), "(pointer: fine)");
+        assert_eq!(MediaQuery::feature("monochrome").to_string(), "(monochrome)");
+    }
+
+    #[test]
+    fn test_and_composition() {
+        let query = MediaQuery::screen()
+            .and(MediaQuery::min_width(Size::Px(768.0)))
+            .and(MediaQuery::max_width(Size::Px(1024.0)));
+        assert_eq!(
+            query.to_string(),
+            "screen and (min-width: 768px) and (max-width: 1024px)"
+        );
+    }
+
+    #[test]
+    fn test_or_composition() {
+        let query = MediaQuery::max_width(Size::Px(600.0)).or(MediaQuery::orientation(Orientation::Portrait));
+        assert_eq!(query.to_string(), "(max-width: 600px) or (orientation: portrait)");
+
+        let query = MediaQuery::print().or(MediaQuery::screen().and(MediaQuery::max_width(Size::Px(600.0))));
+        assert_eq!(query.to_string(), "print, screen and (max-width: 600px)");
+    }
+
+    #[test]
+    fn test_not_composition() {
+        assert_eq!((!MediaQuery::print()).to_string(), "not print");
+        assert_eq!(
+            (!MediaQuery::hover(Hover::Hover)).to_string(),
+            "not (hover: hover)"
+        );
+        assert_eq!(
+            MediaQuery::screen().and(MediaQuery::hover(Hover::Hover)).negate().to_string(),
+            "not screen and (hover: hover)"
+        );
+    }
+
+    #[test]
+    fn test_nested_composition() {
+        let query = MediaQuery::screen().and(
+            MediaQuery::max_width(Size::Px(600.0)).or(MediaQuery::pointer(Pointer::Coarse)),
+        );
+        assert_eq!(
+            query.to_string(),
+            "screen and ((max-width: 600px) or (pointer: coarse))"
+        );
+
+        let query = MediaQuery::min_width(Size::Px(600.0))
+            .and(!MediaQuery::prefers_reduced_motion(ReducedMotion::Reduce));
+        assert_eq!(
+            query.to_string(),
+            "(min-width: 600px) and (not (prefers-reduced-motion: reduce))"
+        );
+
+        let query = MediaQuery::screen().and((!MediaQuery::hover(Hover::Hover)).or(MediaQuery::pointer(Pointer::Fine)));
+        assert_eq!(query.to_string(), "screen and ((not (hover: hover)) or (pointer: fine))");
+        assert_eq!(query.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_media_types_at_top_level() {
+        // Media types are moved to the start of their query
+        let query = MediaQuery::min_width(Size::Px(600.0)).and(MediaQuery::print());
+        assert_eq!(query.to_string(), "print and (min-width: 600px)");
+
+        let query = MediaQuery::all().and(MediaQuery::screen()).and(MediaQuery::hover(Hover::Hover));
+        assert_eq!(query.to_string(), "screen and (hover: hover)");
+
+        let query = !(MediaQuery::screen().and(MediaQuery::min_width(Size::Px(1.0))).and(MediaQuery::pointer(Pointer::Fine)));
+        assert_eq!(query.to_string(), "not screen and (min-width: 1px) and (pointer: fine)");
+
+        let query = MediaQuery::hover(Hover::Hover).or(MediaQuery::print());
+        assert_eq!(query.to_string(), "(hover: hover), print");
+
+        assert_eq!((!!MediaQuery::print()).to_string(), "print");
+    }
+
+    #[test]
+    fn test_invalid_compositions() {
+        let invalid = |query: MediaQuery| match query.validate() {
+            Err(MewError::InvalidMediaQuery(css)) => css,
+            result => panic!("expected an invalid media query, got {:?}", result),
+        };
+
+        // `not <type>` cannot be nested inside a condition
+        let query = MediaQuery::min_width(Size::Px(1.0)).and(!MediaQuery::print());
+        assert_eq!(invalid(query), "(min-width: 1px) and (not print)");
+
+        // A list cannot be negated
+        invalid(!(MediaQuery::print().or(MediaQuery::screen())));
+
+        // A list cannot be nested inside `and`
+        invalid(MediaQuery::screen().and(MediaQuery::print().or(MediaQuery::min_width(Size::Px(1.0)))));
+        invalid(MediaQuery::hover(Hover::Hover).and(MediaQuery::print().or(MediaQuery::screen())));
+
+        // Two different media types never both match
+        invalid(MediaQuery::screen().and(MediaQuery::print()));
+
+        // A negated query cannot be extended
+        invalid((!MediaQuery::print()).and(MediaQuery::hover(Hover::Hover)));
+
+        assert!(MediaQuery::screen().try_and(MediaQuery::print()).is_err());
+        assert!(MediaQuery::print().try_or(MediaQuery::screen()).is_ok());
+        assert!((!MediaQuery::print()).try_negate().is_ok());
+    }
+}
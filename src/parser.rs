@@ -0,0 +1,660 @@
+//! # Declaration Parser Module
+//!
+//! This module reads CSS declaration blocks, such as the content of a `style` attribute,
+//! and turns them into a `Style`. It is used by `Style::parse` and the `FromStr`
+//! implementation of `Style`.
+//!
+//! Declarations of properties that the library already types (`align-content`,
+//! `background-color`, `border`, ...) are parsed into their typed values, so invalid
+//! values are reported. Any other declaration is kept as written with `Property::new`, as
+//! are valid values that the typed values cannot represent, such as the CSS-wide keywords
+//! (`inherit`, `initial`, ...), `var()` references, `display: flow-root` or `font-weight: 350`.
+//!
+//! Errors carry the byte offset and the text of the offending part of the input.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::style::Style;
+//!
+//! let style = Style::parse("background-color: RED; padding: 1rem 2rem").unwrap();
+//! assert_eq!(style.apply(), "background-color: red; padding: 1rem 2rem;");
+//!
+//! let error = Style::parse("align-items: center; border: 1px wavy").unwrap_err();
+//! assert_eq!(error.offset(), 29);
+//! assert_eq!(error.text(), "1px wavy");
+//! ```
+
+use crate::style::Style;
+use crate::values::{BorderStyle, Color, FontWeight, ParseValueError, Size, split_components};
+use std::fmt;
+
+/// The reason a declaration block could not be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    /// A declaration has no `:` between its name and its value
+    MissingColon,
+    /// A declaration has an empty or malformed property name
+    InvalidName,
+    /// A declaration has no value
+    EmptyValue,
+    /// A parenthesis, bracket, string or comment is not closed
+    Unclosed,
+    /// A character that cannot appear in a declaration block, such as `{` or an unmatched `)`
+    UnexpectedCharacter(char),
+    /// The value of a typed property could not be parsed
+    InvalidValue(ParseValueError),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingColon => write!(f, "expected `:` after the property name"),
+            ParseErrorKind::InvalidName => write!(f, "invalid property name"),
+            ParseErrorKind::EmptyValue => write!(f, "missing property value"),
+            ParseErrorKind::Unclosed => write!(f, "unclosed block, string or comment"),
+            ParseErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected character `{}`", c),
+            ParseErrorKind::InvalidValue(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// An error returned when a declaration block cannot be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::parser::ParseErrorKind;
+/// use mew_css::style::Style;
+///
+/// let error = Style::parse("color red").unwrap_err();
+/// assert_eq!(error.kind(), &ParseErrorKind::MissingColon);
+/// assert_eq!(error.offset(), 0);
+/// assert_eq!(error.text(), "color red");
+/// assert_eq!(error.to_string(), "expected `:` after the property name at byte 0: `color red`");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// The reason of the error
+    kind: ParseErrorKind,
+    /// The byte offset of the offending text in the input
+    offset: usize,
+    /// The offending text
+    text: String,
+}
+
+impl ParseError {
+    fn new(kind: ParseErrorKind, offset: usize, text: &str) -> Self {
+        Self {
+            kind,
+            offset,
+            text: text.to_string(),
+        }
+    }
+
+    /// Returns the reason of the error.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte offset of the offending text in the input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the offending text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}: `{}`", self.kind, self.offset, self.text)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A slice of the input along with its byte offset.
+#[derive(Debug, Clone, Copy)]
+struct Span<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Span<'a> {
+    /// Returns the span without its leading and trailing whitespace.
+    fn trim(self) -> Self {
+        let start = self.text.len() - self.text.trim_start().len();
+        Span {
+            text: self.text.trim(),
+            offset: self.offset + start,
+        }
+    }
+
+    fn error(self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(kind, self.offset, self.text)
+    }
+}
+
+/// A single `name: value` declaration.
+struct Declaration<'a> {
+    name: Span<'a>,
+    value: Span<'a>,
+}
+
+/// Replaces comments with spaces, keeping every other byte at its offset.
+fn strip_comments(input: &str) -> Result<String, ParseError> {
+    let mut output = String::with_capacity(input.len());
+    let mut quote: Option<char> = None;
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match quote {
+            Some(q) => {
+                output.push(c);
+                if c == '\\' {
+                    if let Some((_, escaped)) = chars.next() {
+                        output.push(escaped);
+                    }
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None if c == '/' && chars.peek().map(|(_, next)| *next) == Some('*') => {
+                let end = input[i + 2..]
+                    .find("*/")
+                    .ok_or_else(|| ParseError::new(ParseErrorKind::Unclosed, i, &input[i..]))?;
+                let comment_len = end + 4;
+                output.push_str(&" ".repeat(comment_len));
+                for _ in 0..input[i..i + comment_len].chars().count() - 1 {
+                    chars.next();
+                }
+            }
+            None => {
+                if c == '"' || c == '\'' {
+                    quote = Some(c);
+                }
+                output.push(c);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Splits a declaration block into declarations on the semicolons that are not
+/// inside parentheses, brackets or strings.
+fn split_declarations(input: &str) -> Result<Vec<Span<'_>>, ParseError> {
+    let mut spans = Vec::new();
+    let mut openers: Vec<(char, usize)> = Vec::new();
+    let mut quote: Option<(char, usize)> = None;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (i, c) in input.char_indices() {
+        if let Some((q, _)) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote = Some((c, i)),
+            '(' | '[' => openers.push((c, i)),
+            ')' | ']' => {
+                let expected = if c == ')' { '(' } else { '[' };
+                match openers.pop() {
+                    Some((opener, _)) if opener == expected => {}
+                    _ => return Err(ParseError::new(ParseErrorKind::UnexpectedCharacter(c), i, &input[i..i + 1])),
+                }
+            }
+            '{' | '}' => return Err(ParseError::new(ParseErrorKind::UnexpectedCharacter(c), i, &input[i..i + 1])),
+            ';' if openers.is_empty() => {
+                spans.push(Span { text: &input[start..i], offset: start });
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((_, i)) = quote.or(openers.first().copied()) {
+        return Err(ParseError::new(ParseErrorKind::Unclosed, i, input[i..].trim_end()));
+    }
+
+    spans.push(Span { text: &input[start..], offset: start });
+    Ok(spans.into_iter().map(Span::trim).filter(|span| !span.text.is_empty()).collect())
+}
+
+/// Returns `true` if `name` is a valid property name, including custom properties
+/// (`--name`) and vendor-prefixed properties (`-webkit-name`).
+fn is_valid_name(name: &str) -> bool {
+    let body = name.strip_prefix("--").or_else(|| name.strip_prefix('-')).unwrap_or(name);
+    let custom = name.starts_with("--");
+
+    !body.is_empty()
+        && (custom || body.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_'))
+        && body.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || (custom && !c.is_ascii()))
+}
+
+/// Splits a declaration into its name and value on the first colon.
+fn parse_declaration(span: Span<'_>) -> Result<Declaration<'_>, ParseError> {
+    let colon = span.text.find(':').ok_or_else(|| span.error(ParseErrorKind::MissingColon))?;
+
+    let name = Span { text: &span.text[..colon], offset: span.offset }.trim();
+    let value = Span { text: &span.text[colon + 1..], offset: span.offset + colon + 1 }.trim();
+
+    if !is_valid_name(name.text) {
+        return Err(name.error(ParseErrorKind::InvalidName));
+    }
+    if value.text.is_empty() && !name.text.starts_with("--") {
+        return Err(span.error(ParseErrorKind::EmptyValue));
+    }
+
+    Ok(Declaration { name, value })
+}
+
+/// The parts of a `border` shorthand value.
+struct BorderParts {
+    width: Option<Size>,
+    style: Option<BorderStyle>,
+    color: Option<Color>,
+    /// Whether the width is a keyword (`thin`, `medium` or `thick`), which `Size` cannot represent
+    keyword_width: bool,
+}
+
+/// The CSS-wide keywords, which are valid values of every property.
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["initial", "inherit", "unset", "revert", "revert-layer"];
+
+/// The keywords of `display` that can only be used on their own.
+const DISPLAY_KEYWORDS: [&str; 16] = [
+    "none", "contents", "inline-block", "inline-table", "inline-flex", "inline-grid",
+    "table-row-group", "table-header-group", "table-footer-group", "table-row", "table-cell",
+    "table-column-group", "table-column", "table-caption", "ruby-base", "ruby-text",
+];
+
+/// The outer display types, which can be combined with an inner display type.
+const DISPLAY_OUTSIDE: [&str; 3] = ["block", "inline", "run-in"];
+
+/// The inner display types, which can be combined with an outer display type.
+const DISPLAY_INSIDE: [&str; 7] = ["flow", "flow-root", "table", "flex", "grid", "ruby", "math"];
+
+/// Returns whether a `display` value is valid, using either a single keyword or the
+/// multi-keyword syntax (e.g., `inline flex` or `block flow list-item`).
+fn is_display_value(value: &str) -> bool {
+    let keywords: Vec<String> = split_components(value).iter().map(|k| k.to_ascii_lowercase()).collect();
+    if let [keyword] = keywords.as_slice()
+        && DISPLAY_KEYWORDS.contains(&keyword.as_str())
+    {
+        return true;
+    }
+
+    let count = |list: &[&str]| keywords.iter().filter(|k| list.contains(&k.as_str())).count();
+    let outside = count(&DISPLAY_OUTSIDE);
+    let inside = count(&DISPLAY_INSIDE);
+    let list_item = count(&["list-item"]);
+
+    // A list item can only have a flow layout inside
+    let list_item_inside = list_item == 0
+        || keywords.iter().all(|k| !DISPLAY_INSIDE.contains(&k.as_str()) || k.starts_with("flow"));
+
+    !keywords.is_empty()
+        && outside <= 1
+        && inside <= 1
+        && list_item <= 1
+        && outside + inside + list_item == keywords.len()
+        && list_item_inside
+}
+
+/// Returns whether an alignment value uses an overflow position the typed values do not
+/// cover (e.g., `safe end` or `unsafe flex-start`).
+fn is_alignment_value(value: &str, positions: &[&str]) -> bool {
+    let keywords: Vec<String> = split_components(value).iter().map(|k| k.to_ascii_lowercase()).collect();
+    match keywords.as_slice() {
+        [overflow, position] => (overflow == "safe" || overflow == "unsafe") && positions.contains(&position.as_str()),
+        _ => false,
+    }
+}
+
+/// Returns whether a value rejected by the typed parser is still valid CSS for the property,
+/// in which case the declaration is kept as written.
+fn is_untyped_value(name: &str, value: &str) -> bool {
+    let lower = value.to_ascii_lowercase();
+    // Substitution functions are only resolved at computed-value time
+    if CSS_WIDE_KEYWORDS.contains(&lower.as_str()) || lower.contains("var(") || lower.contains("env(") {
+        return true;
+    }
+
+    match name {
+        "align-content" => is_alignment_value(value, &["center", "start", "end", "flex-start", "flex-end"]),
+        "align-items" => is_alignment_value(
+            value,
+            &["center", "start", "end", "self-start", "self-end", "flex-start", "flex-end"],
+        ),
+        "display" => is_display_value(value),
+        "container-type" => {
+            let keywords: Vec<String> = split_components(value).iter().map(|k| k.to_ascii_lowercase()).collect();
+            match keywords.as_slice() {
+                [keyword] => keyword == "scroll-state",
+                [first, second] => matches!(
+                    (first.as_str(), second.as_str()),
+                    ("size" | "inline-size", "scroll-state") | ("scroll-state", "size" | "inline-size")
+                ),
+                _ => false,
+            }
+        }
+        // Any number between 1 and 1000, including the fractional weights
+        "font-weight" => {
+            lower.starts_with("calc(") || lower.parse::<f32>().is_ok_and(|w| (1.0..=1000.0).contains(&w))
+        }
+        _ => false,
+    }
+}
+
+/// Parses a `border` shorthand value, whose width, style and color can appear in any order.
+fn parse_border(value: &str) -> Result<BorderParts, ParseValueError> {
+    let mut parts = BorderParts {
+        width: None,
+        style: None,
+        color: None,
+        keyword_width: false,
+    };
+
+    for component in split_components(value) {
+        if parts.width.is_none() && !parts.keyword_width {
+            if let Ok(width) = component.parse::<Size>() {
+                parts.width = Some(width);
+                continue;
+            }
+            if ["thin", "medium", "thick"].iter().any(|k| component.eq_ignore_ascii_case(k)) {
+                parts.keyword_width = true;
+                continue;
+            }
+        }
+        if parts.style.is_none()
+            && let Ok(style) = component.parse::<BorderStyle>()
+        {
+            parts.style = Some(style);
+            continue;
+        }
+        if parts.color.is_none()
+            && let Ok(color) = component.parse::<Color>()
+        {
+            parts.color = Some(color);
+            continue;
+        }
+        return Err(ParseValueError::new("border", value));
+    }
+
+    Ok(parts)
+}
+
+/// Adds a declaration to the style, using the typed setter when the property is known.
+///
+/// Values that are valid CSS but cannot be represented by the typed values are kept as written.
+fn apply_declaration(style: &mut Style, name: &str, value: &str) -> Result<(), ParseValueError> {
+    let property = name.to_ascii_lowercase();
+    match apply_typed_declaration(style, &property, value) {
+        Err(_) if is_untyped_value(&property, value) => {
+            style.custom_property(&property, value);
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+/// Adds a declaration to the style with the typed setter of the property, if there is one.
+fn apply_typed_declaration(style: &mut Style, name: &str, value: &str) -> Result<(), ParseValueError> {
+    match name {
+        "align-content" => {
+            style.align_content(value.parse()?);
+        }
+        "align-items" => {
+            style.align_items(value.parse()?);
+        }
+        "background-color" => {
+            style.background_color(value.parse()?);
+        }
+        "color" => {
+            style.color(value.parse()?);
+        }
+        "display" => {
+            style.display(value.parse()?);
+        }
+        "container-type" => {
+            style.container_type(value.parse()?);
+        }
+        // Numeric weights are given to the setter as is, so out-of-range weights are reported
+        // by `validate()` like with `FontWeight::Weight`
+        "font-weight" => match value.trim().parse::<u16>() {
+            Ok(weight) => {
+                style.font_weight(FontWeight::Weight(weight));
+            }
+            Err(_) => {
+                style.font_weight(value.parse()?);
+            }
+        },
+        side @ ("border" | "border-top" | "border-right" | "border-bottom" | "border-left") => {
+            let parts = parse_border(value)?;
+            match parts {
+                BorderParts { style: None, .. } | BorderParts { keyword_width: true, .. } => {
+                    // A border without a style or with a keyword width is valid CSS but cannot be
+                    // built with the typed setters
+                    style.custom_property(side, value);
+                }
+                BorderParts { width, style: Some(border_style), color, .. } => {
+                    apply_border(style, side, width, border_style, color);
+                }
+            }
+        }
+        _ => {
+            style.custom_property(name, value);
+        }
+    }
+    Ok(())
+}
+
+/// Calls the typed setter of a border side matching the parts that are present.
+fn apply_border(style: &mut Style, side: &str, width: Option<Size>, border_style: BorderStyle, color: Option<Color>) {
+    match (side, width, color) {
+        ("border", None, None) => style.border(border_style),
+        ("border", Some(w), None) => style.border_with_width(w, border_style),
+        ("border", None, Some(c)) => style.border_with_color(border_style, c),
+        ("border", Some(w), Some(c)) => style.border_with_width_and_color(w, border_style, c),
+        ("border-top", None, None) => style.border_top(border_style),
+        ("border-top", Some(w), None) => style.border_top_with_width(w, border_style),
+        ("border-top", None, Some(c)) => style.border_top_with_color(border_style, c),
+        ("border-top", Some(w), Some(c)) => style.border_top_with_width_and_color(w, border_style, c),
+        ("border-right", None, None) => style.border_right(border_style),
+        ("border-right", Some(w), None) => style.border_right_with_width(w, border_style),
+        ("border-right", None, Some(c)) => style.border_right_with_color(border_style, c),
+        ("border-right", Some(w), Some(c)) => style.border_right_with_width_and_color(w, border_style, c),
+        ("border-bottom", None, None) => style.border_bottom(border_style),
+        ("border-bottom", Some(w), None) => style.border_bottom_with_width(w, border_style),
+        ("border-bottom", None, Some(c)) => style.border_bottom_with_color(border_style, c),
+        ("border-bottom", Some(w), Some(c)) => style.border_bottom_with_width_and_color(w, border_style, c),
+        (_, None, None) => style.border_left(border_style),
+        (_, Some(w), None) => style.border_left_with_width(w, border_style),
+        (_, None, Some(c)) => style.border_left_with_color(border_style, c),
+        (_, Some(w), Some(c)) => style.border_left_with_width_and_color(w, border_style, c),
+    };
+}
+
+/// Splits `!important` off a value, returning the value and whether it was present.
+fn split_important(value: &str) -> (&str, bool) {
+    match value.rfind('!') {
+        Some(i) if value[i + 1..].trim().eq_ignore_ascii_case("important") => (value[..i].trim_end(), true),
+        _ => (value, false),
+    }
+}
+
+/// Parses a declaration block into a `Style`.
+pub(crate) fn parse_style(input: &str) -> Result<Style, ParseError> {
+    let stripped = strip_comments(input)?;
+    let mut style = Style::new();
+
+    for span in split_declarations(&stripped)? {
+        let declaration = parse_declaration(span)?;
+        let (value, important) = split_important(declaration.value.text);
+
+        // The declaration is added through the typed setters, which record invalid values
+        let start = style.properties().len();
+        apply_declaration(&mut style, declaration.name.text, value).map_err(|error| {
+            // Report the original text rather than the copy without comments
+            let value = declaration.value;
+            ParseError::new(
+                ParseErrorKind::InvalidValue(error),
+                value.offset,
+                input[value.offset..value.offset + value.text.len()].trim(),
+            )
+        })?;
+
+        if important {
+            style.mark_important(start);
+        }
+    }
+
+    Ok(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_properties() {
+        let style = parse_style("align-content: Space-Between; background-color: #fff; display: GRID").unwrap();
+        assert_eq!(
+            style.apply(),
+            "align-content: space-between; background-color: rgb(255, 255, 255); display: grid;"
+        );
+
+        let style = parse_style("color: light-dark(#000, color-mix(in oklch, var(--brand) 20%, white))").unwrap();
+        assert_eq!(
+            style.apply(),
+            "color: light-dark(rgb(0, 0, 0), color-mix(in oklch, var(--brand) 20%, white));"
+        );
+
+        // Out-of-range channels are clamped, as browsers do
+        let style = parse_style("color: rgb(300,0,0); background-color: hsla(0, 0%, 120%, 1.5)").unwrap();
+        assert_eq!(style.apply(), "color: rgb(255, 0, 0); background-color: hsla(0, 0%, 100%, 1);");
+        assert!(style.validate().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_properties_kept() {
+        let style = parse_style("padding: 1rem 2rem; --gap: 4px; -webkit-line-clamp: 3;").unwrap();
+        assert_eq!(style.apply(), "padding: 1rem 2rem; --gap: 4px; -webkit-line-clamp: 3;");
+    }
+
+    #[test]
+    fn test_border_shorthand() {
+        let style = parse_style("border: solid 2px red; border-top: dashed; border-left: 1px black").unwrap();
+        assert_eq!(
+            style.apply(),
+            "border: 2px solid red; border-top: dashed; border-left: 1px black;"
+        );
+
+        let error = parse_style("border-bottom: 1px solid dotted").unwrap_err();
+        assert_eq!(error.offset(), 15);
+        assert_eq!(error.text(), "1px solid dotted");
+    }
+
+    #[test]
+    fn test_untyped_values_kept() {
+        let style = parse_style(
+            "font-weight: 350; border: thin solid red; color: initial; display: flow-root; display: inline flex",
+        )
+        .unwrap();
+        assert_eq!(
+            style.apply(),
+            "font-weight: 350; border: thin solid red; color: initial; display: flow-root; display: inline flex;"
+        );
+
+        let style = parse_style(
+            "Display: REVERT-LAYER; background-color: unset; align-items: safe end; \
+             container-type: inline-size scroll-state; display: block flow list-item; border-top: 2px var(--style)",
+        )
+        .unwrap();
+        assert_eq!(
+            style.apply(),
+            "display: REVERT-LAYER; background-color: unset; align-items: safe end; \
+             container-type: inline-size scroll-state; display: block flow list-item; border-top: 2px var(--style);"
+        );
+
+        // Invalid values are still reported
+        for input in [
+            "font-weight: 1000.5",
+            "display: inline block",
+            "display: grid list-item",
+            "display: none flex",
+            "align-content: safe self-start",
+            "border: thin thick solid",
+            "color: inherits",
+        ] {
+            assert!(parse_style(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_separators_strings_and_comments() {
+        let style = parse_style(
+            "/* layout */ content: \"a;b\"; background-image: url(data:image/png;base64,AA==); /* end */",
+        )
+        .unwrap();
+        assert_eq!(
+            style.apply(),
+            "content: \"a;b\"; background-image: url(data:image/png;base64,AA==);"
+        );
+
+        let style = parse_style(";;  ;").unwrap();
+        assert!(style.is_empty());
+    }
+
+    #[test]
+    fn test_important() {
+        let style = parse_style("display: flex !important; margin: 0!IMPORTANT").unwrap();
+        assert_eq!(style.apply(), "display: flex !important; margin: 0 !important;");
+    }
+
+    #[test]
+    fn test_validation_issues_kept() {
+        let style = parse_style("font-weight: 450; font-weight: 1200 !important").unwrap();
+        assert_eq!(style.apply(), "font-weight: 450; font-weight: 400 !important;");
+
+        let issues = style.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].property(), issues[0].value()), ("font-weight", "1200"));
+        assert_eq!(issues[0].error(), &crate::error::MewError::InvalidFontWeight(1200));
+    }
+
+    #[test]
+    fn test_error_offsets() {
+        let error = parse_style("display: block;\n  align-items: middle").unwrap_err();
+        assert_eq!(error.offset(), 31);
+        assert_eq!(error.text(), "middle");
+        assert!(matches!(error.kind(), ParseErrorKind::InvalidValue(e) if e.kind() == "AlignItems"));
+
+        let error = parse_style("color: red; 1color: blue").unwrap_err();
+        assert_eq!((error.kind(), error.offset(), error.text()), (&ParseErrorKind::InvalidName, 12, "1color"));
+
+        let error = parse_style("color: red; margin:").unwrap_err();
+        assert_eq!((error.kind(), error.offset(), error.text()), (&ParseErrorKind::EmptyValue, 12, "margin:"));
+
+        let error = parse_style("width: calc(100% - 2px").unwrap_err();
+        assert_eq!((error.kind(), error.offset(), error.text()), (&ParseErrorKind::Unclosed, 11, "(100% - 2px"));
+
+        let error = parse_style("a { color: red }").unwrap_err();
+        assert_eq!(error.kind(), &ParseErrorKind::UnexpectedCharacter('{'));
+        assert_eq!(error.offset(), 2);
+
+        let error = parse_style("color: red /* note").unwrap_err();
+        assert_eq!((error.kind(), error.offset()), (&ParseErrorKind::Unclosed, 11));
+    }
+}
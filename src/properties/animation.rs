@@ -0,0 +1,147 @@
+//! # Animation Property
+//!
+//! This module provides a function for creating the CSS `animation` property.
+//! The `animation` property is a shorthand property that applies an animation between styles.
+//! It sets the animation-name, animation-duration, animation-timing-function, animation-delay,
+//! animation-iteration-count, animation-direction, animation-fill-mode and animation-play-state.
+//!
+//! ## Syntax
+//!
+//! ```css
+//! /* name | duration */
+//! animation: slide-in 3s;
+//!
+//! /* name | duration | timing-function | delay */
+//! animation: slide-in 3s ease-in 1s;
+//!
+//! /* name | duration | timing-function | delay | iteration-count | direction | fill-mode | play-state */
+//! animation: slide-in 3s ease-in 1s 2 reverse both paused;
+//!
+//! /* Global values */
+//! animation: inherit;
+//! animation: initial;
+//! animation: unset;
+//! ```
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::keyframes::Keyframes;
+//! use mew_css::properties::animation;
+//! use mew_css::values::{Animation, Time, TimingFunction};
+//!
+//! let slide_in = Keyframes::new("slide-in");
+//!
+//! let prop = animation::animation(Animation::new(&slide_in, Time::S(3.0)));
+//! assert_eq!(prop.to_string(), "animation: slide-in 3s;");
+//!
+//! let prop = animation::animation(Animation::new(&slide_in, Time::S(3.0))
+//!     .timing_function(TimingFunction::EaseIn)
+//!     .delay(Time::S(1.0)));
+//! assert_eq!(prop.to_string(), "animation: slide-in 3s ease-in 1s;");
+//! ```
+
+use crate::properties::Property;
+use crate::values::Animation;
+
+/// Creates a CSS `animation` property.
+///
+/// The `animation` property is a shorthand property that applies an animation between styles.
+/// The animation name comes from the `Keyframes` used to create the `Animation` value.
+///
+/// ## Values
+///
+/// - `name`: The name of the `@keyframes` rule to run
+/// - `duration`: The duration of one cycle, as a `Time` (e.g., `1.5s`, `300ms` or `var(--duration)`)
+/// - `timing-function`: `ease`, `linear`, `ease-in`, `cubic-bezier(...)`, `steps(...)`, etc.
+/// - `delay`: The delay before the animation starts, as a `Time`
+/// - `iteration-count`: A number of cycles or `infinite`
+/// - `direction`: `normal`, `reverse`, `alternate`, `alternate-reverse`
+/// - `fill-mode`: `none`, `forwards`, `backwards`, `both`
+/// - `play-state`: `running`, `paused`
+///
+/// # Arguments
+///
+/// * `value` - The animation value to use
+///
+/// # Returns
+///
+/// A new `Property` instance representing the animation property
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::keyframes::Keyframes;
+/// use mew_css::properties::animation;
+/// use mew_css::values::{Animation, AnimationDirection, AnimationFillMode, AnimationPlayState, IterationCount, Time, TimingFunction};
+///
+/// let spin = Keyframes::new("spin");
+///
+/// let prop = animation::animation(Animation::new(&spin, Time::S(1.0))
+///     .timing_function(TimingFunction::Linear)
+///     .iteration_count(IterationCount::Infinite));
+/// assert_eq!(prop.to_string(), "animation: spin 1s linear infinite;");
+///
+/// let prop = animation::animation(Animation::new(&spin, Time::S(0.5))
+///     .delay(Time::Ms(250.0))
+///     .iteration_count(IterationCount::Count(2.0))
+///     .direction(AnimationDirection::Reverse)
+///     .fill_mode(AnimationFillMode::Both)
+///     .play_state(AnimationPlayState::Paused));
+/// assert_eq!(prop.to_string(), "animation: spin 0.5s 250ms 2 reverse both paused;");
+/// ```
+pub fn animation(value: Animation) -> Property {
+    Property::new("animation", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes::Keyframes;
+    use crate::values::{
+        AnimationDirection, AnimationFillMode, AnimationPlayState, IterationCount, Time, TimingFunction,
+    };
+    use crate::variable::var;
+
+    #[test]
+    fn test_name_and_duration() {
+        let fade = Keyframes::new("fade-in");
+        let prop = animation(Animation::new(&fade, Time::S(2.0)));
+        assert_eq!(prop.to_string(), "animation: fade-in 2s;");
+    }
+
+    #[test]
+    fn test_timing_functions() {
+        let fade = Keyframes::new("fade");
+
+        let prop = animation(Animation::new(&fade, Time::S(1.0)).timing_function(TimingFunction::EaseOut));
+        assert_eq!(prop.to_string(), "animation: fade 1s ease-out;");
+
+        let prop = animation(Animation::new(&fade, Time::S(1.0)).timing_function(TimingFunction::CubicBezier(0.1, 0.7, 1.0, 0.1)));
+        assert_eq!(prop.to_string(), "animation: fade 1s cubic-bezier(0.1, 0.7, 1, 0.1);");
+
+        let prop = animation(Animation::new(&fade, Time::S(1.0)).timing_function(TimingFunction::Steps(4)));
+        assert_eq!(prop.to_string(), "animation: fade 1s steps(4);");
+
+        let prop = animation(Animation::new(&fade, Time::S(1.0)).timing_function(TimingFunction::Var(var("easing"))));
+        assert_eq!(prop.to_string(), "animation: fade 1s var(--easing);");
+    }
+
+    #[test]
+    fn test_all_parts() {
+        let slide = Keyframes::new("slide");
+        let prop = animation(
+            Animation::new(&slide, Time::S(3.0))
+                .timing_function(TimingFunction::EaseInOut)
+                .delay(Time::S(0.5))
+                .iteration_count(IterationCount::Count(2.5))
+                .direction(AnimationDirection::AlternateReverse)
+                .fill_mode(AnimationFillMode::Forwards)
+                .play_state(AnimationPlayState::Running),
+        );
+        assert_eq!(
+            prop.to_string(),
+            "animation: slide 3s ease-in-out 0.5s 2.5 alternate-reverse forwards running;"
+        );
+    }
+}
@@ -35,7 +35,7 @@
 //! assert_eq!(prop.to_string(), "border-bottom: solid;");
 //!
 //! // Width and style
-//! let prop = border_bottom::border_bottom_with_width(Size::Px(2), BorderStyle::Dotted);
+//! let prop = border_bottom::border_bottom_with_width(Size::Px(2.0), BorderStyle::Dotted);
 //! assert_eq!(prop.to_string(), "border-bottom: 2px dotted;");
 //!
 //! // Style and color
@@ -43,7 +43,7 @@
 //! assert_eq!(prop.to_string(), "border-bottom: outset #f33;");
 //!
 //! // Width, style, and color
-//! let prop = border_bottom::border_bottom_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green);
+//! let prop = border_bottom::border_bottom_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green);
 //! assert_eq!(prop.to_string(), "border-bottom: 3px dashed green;");
 //! ```
 
@@ -140,7 +140,7 @@ pub fn border_bottom(style: BorderStyle) -> Property {
 /// use mew_css::properties::border_bottom;
 /// use mew_css::values::{BorderStyle, Size};
 ///
-/// let prop = border_bottom::border_bottom_with_width(Size::Px(2), BorderStyle::Dotted);
+/// let prop = border_bottom::border_bottom_with_width(Size::Px(2.0), BorderStyle::Dotted);
 /// assert_eq!(prop.to_string(), "border-bottom: 2px dotted;");
 ///
 /// let prop = border_bottom::border_bottom_with_width(Size::Rem(1.5), BorderStyle::Solid);
@@ -224,10 +224,10 @@ pub fn border_bottom_with_color(style: BorderStyle, color: Color) -> Property {
 /// use mew_css::properties::border_bottom;
 /// use mew_css::values::{BorderStyle, Color, Size};
 ///
-/// let prop = border_bottom::border_bottom_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green);
+/// let prop = border_bottom::border_bottom_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green);
 /// assert_eq!(prop.to_string(), "border-bottom: 3px dashed green;");
 ///
-/// let prop = border_bottom::border_bottom_with_width_and_color(Size::Px(4), BorderStyle::Double, Color::Rgb(50, 161, 206));
+/// let prop = border_bottom::border_bottom_with_width_and_color(Size::Px(4.0), BorderStyle::Double, Color::Rgb(50, 161, 206));
 /// assert_eq!(prop.to_string(), "border-bottom: 4px double rgb(50, 161, 206);");
 /// ```
 pub fn border_bottom_with_width_and_color(width: Size, style: BorderStyle, color: Color) -> Property {
@@ -277,7 +277,7 @@ mod tests {
 
     #[test]
     fn test_border_bottom_with_width() {
-        let prop = border_bottom_with_width(Size::Px(2), BorderStyle::Dotted);
+        let prop = border_bottom_with_width(Size::Px(2.0), BorderStyle::Dotted);
         assert_eq!(prop.to_string(), "border-bottom: 2px dotted;");
 
         let prop = border_bottom_with_width(Size::Rem(1.5), BorderStyle::Solid);
@@ -307,10 +307,10 @@ mod tests {
 
     #[test]
     fn test_border_bottom_with_width_and_color() {
-        let prop = border_bottom_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green);
+        let prop = border_bottom_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green);
         assert_eq!(prop.to_string(), "border-bottom: 3px dashed green;");
 
-        let prop = border_bottom_with_width_and_color(Size::Px(4), BorderStyle::Double, Color::Rgb(50, 161, 206));
+        let prop = border_bottom_with_width_and_color(Size::Px(4.0), BorderStyle::Double, Color::Rgb(50, 161, 206));
         assert_eq!(prop.to_string(), "border-bottom: 4px double rgb(50, 161, 206);");
 
         let prop = border_bottom_with_width_and_color(Size::Rem(0.25), BorderStyle::Ridge, Color::Rgba(211, 220, 50, 0.6));
@@ -0,0 +1,89 @@
+//! # Color Property
+//!
+//! This module provides a function for creating the CSS `color` property.
+//! The `color` property sets the foreground color of the text and text decorations
+//! of an element, and the value of `currentColor`.
+//!
+//! ## Syntax
+//!
+//! ```css
+//! /* Keyword values */
+//! color: currentcolor;
+//! color: rebeccapurple;
+//!
+//! /* Hexadecimal value */
+//! color: #00ff00;
+//!
+//! /* RGB and HSL values */
+//! color: rgb(34, 12, 64);
+//! color: hsla(30, 100%, 50%, 0.6);
+//!
+//! /* Modern color spaces */
+//! color: oklch(70% 0.1 150);
+//! color: color(display-p3 1 0.5 0);
+//!
+//! /* Global values */
+//! color: inherit;
+//! ```
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::properties::color;
+//! use mew_css::values::Color;
+//!
+//! let prop = color::color(Color::RebeccaPurple);
+//! assert_eq!(prop.to_string(), "color: rebeccapurple;");
+//! ```
+
+use crate::properties::Property;
+use crate::values::Color;
+
+/// Creates a CSS `color` property.
+///
+/// The `color` property sets the foreground color of the text of an element.
+///
+/// # Arguments
+///
+/// * `value` - The color value to use
+///
+/// # Returns
+///
+/// A new `Property` instance representing the color property
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::properties::color;
+/// use mew_css::values::Color;
+///
+/// let prop = color::color(Color::Rgb(34, 12, 64));
+/// assert_eq!(prop.to_string(), "color: rgb(34, 12, 64);");
+/// ```
+pub fn color(value: Color) -> Property {
+    Property::new("color", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variable::var;
+
+    #[test]
+    fn test_color_values() {
+        let prop = color(Color::Black);
+        assert_eq!(prop.to_string(), "color: black;");
+
+        let prop = color(Color::Hsla(30, 100, 50, 0.6));
+        assert_eq!(prop.to_string(), "color: hsla(30, 100%, 50%, 0.6);");
+
+        let prop = color(Color::CurrentColor);
+        assert_eq!(prop.to_string(), "color: currentColor;");
+    }
+
+    #[test]
+    fn test_variables() {
+        let prop = color(Color::Var(var("text")));
+        assert_eq!(prop.to_string(), "color: var(--text);");
+    }
+}
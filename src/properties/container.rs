@@ -0,0 +1,87 @@
+//! # Container Property
+//!
+//! This module provides a function for creating the CSS `container` property.
+//! The `container` property is a shorthand property that sets both the `container-name`
+//! and `container-type` properties.
+//!
+//! ## Syntax
+//!
+//! ```css
+//! /* <container-name> */
+//! container: my-layout;
+//!
+//! /* <container-name> / <container-type> */
+//! container: my-layout / size;
+//! container: card / inline-size;
+//!
+//! /* Global values */
+//! container: inherit;
+//! container: initial;
+//! container: unset;
+//! ```
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::properties::container;
+//! use mew_css::values::ContainerType;
+//!
+//! let prop = container::container("card", ContainerType::InlineSize);
+//! assert_eq!(prop.to_string(), "container: card / inline-size;");
+//! ```
+
+use crate::properties::Property;
+use crate::values::ContainerType;
+
+/// Creates a CSS `container` property.
+///
+/// The `container` property is a shorthand property that sets both the `container-name`
+/// and `container-type` properties.
+///
+/// ## Values
+///
+/// - `<container-name>`: One or more names identifying the container, separated by spaces.
+/// - `<container-type>`: `normal`, `size` or `inline-size`.
+///
+/// # Arguments
+///
+/// * `name` - The container name, or several names separated by spaces
+/// * `value` - The container type to use
+///
+/// # Returns
+///
+/// A new `Property` instance representing the container property
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::properties::container;
+/// use mew_css::values::ContainerType;
+///
+/// let prop = container::container("sidebar", ContainerType::Size);
+/// assert_eq!(prop.to_string(), "container: sidebar / size;");
+/// ```
+pub fn container(name: &str, value: ContainerType) -> Property {
+    Property::new("container", format!("{} / {}", name.trim(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variable::var;
+
+    #[test]
+    fn test_name_and_type() {
+        let prop = container("card", ContainerType::InlineSize);
+        assert_eq!(prop.to_string(), "container: card / inline-size;");
+
+        let prop = container("layout main", ContainerType::Normal);
+        assert_eq!(prop.to_string(), "container: layout main / normal;");
+    }
+
+    #[test]
+    fn test_variable_type() {
+        let prop = container("card", ContainerType::Var(var("card-type")));
+        assert_eq!(prop.to_string(), "container: card / var(--card-type);");
+    }
+}
@@ -0,0 +1,82 @@
+//! # Container Name Property
+//!
+//! This module provides a function for creating the CSS `container-name` property.
+//! The `container-name` property gives a query container one or more names, which
+//! `@container` rules can use to target a specific container instead of the nearest one.
+//!
+//! ## Syntax
+//!
+//! ```css
+//! /* Keyword value */
+//! container-name: none;
+//!
+//! /* Custom identifiers */
+//! container-name: sidebar;
+//! container-name: layout card;
+//!
+//! /* Global values */
+//! container-name: inherit;
+//! container-name: initial;
+//! container-name: unset;
+//! ```
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::properties::container_name;
+//!
+//! let prop = container_name::container_name("sidebar");
+//! assert_eq!(prop.to_string(), "container-name: sidebar;");
+//! ```
+
+use crate::properties::Property;
+
+/// Creates a CSS `container-name` property.
+///
+/// The `container-name` property gives a query container one or more names,
+/// separated by spaces.
+///
+/// ## Values
+///
+/// - `none`: The query container has no name.
+/// - `<custom-ident>`: One or more names identifying the container.
+///
+/// # Arguments
+///
+/// * `name` - The container name, or several names separated by spaces
+///
+/// # Returns
+///
+/// A new `Property` instance representing the container-name property
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::properties::container_name;
+///
+/// let prop = container_name::container_name("layout card");
+/// assert_eq!(prop.to_string(), "container-name: layout card;");
+///
+/// let prop = container_name::container_name("none");
+/// assert_eq!(prop.to_string(), "container-name: none;");
+/// ```
+pub fn container_name(name: &str) -> Property {
+    Property::new("container-name", name.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_name() {
+        let prop = container_name("card");
+        assert_eq!(prop.to_string(), "container-name: card;");
+    }
+
+    #[test]
+    fn test_multiple_names() {
+        let prop = container_name(" layout card ");
+        assert_eq!(prop.to_string(), "container-name: layout card;");
+    }
+}
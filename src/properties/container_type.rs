@@ -0,0 +1,91 @@
+//! # Container Type Property
+//!
+//! This module provides a function for creating the CSS `container-type` property.
+//! The `container-type` property establishes an element as a query container, so that
+//! its descendants can be styled with `@container` rules based on its size.
+//!
+//! ## Syntax
+//!
+//! ```css
+//! /* Keyword values */
+//! container-type: normal;
+//! container-type: size;
+//! container-type: inline-size;
+//!
+//! /* Global values */
+//! container-type: inherit;
+//! container-type: initial;
+//! container-type: unset;
+//! ```
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::properties::container_type;
+//! use mew_css::values::ContainerType;
+//!
+//! let prop = container_type::container_type(ContainerType::InlineSize);
+//! assert_eq!(prop.to_string(), "container-type: inline-size;");
+//! ```
+
+use crate::properties::Property;
+use crate::values::ContainerType;
+
+/// Creates a CSS `container-type` property.
+///
+/// The `container-type` property establishes an element as a query container for
+/// container size queries.
+///
+/// ## Values
+///
+/// - `normal`: The element is not a query container for size queries, but remains one for style queries.
+/// - `size`: Size queries can be made on both the inline and block axis. Size containment is applied.
+/// - `inline-size`: Size queries can be made on the inline axis only. Inline-size containment is applied.
+///
+/// # Arguments
+///
+/// * `value` - The container type to use
+///
+/// # Returns
+///
+/// A new `Property` instance representing the container-type property
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::properties::container_type;
+/// use mew_css::values::ContainerType;
+///
+/// let prop = container_type::container_type(ContainerType::Size);
+/// assert_eq!(prop.to_string(), "container-type: size;");
+///
+/// let prop = container_type::container_type(ContainerType::Normal);
+/// assert_eq!(prop.to_string(), "container-type: normal;");
+/// ```
+pub fn container_type(value: ContainerType) -> Property {
+    Property::new("container-type", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variable::var;
+
+    #[test]
+    fn test_keywords() {
+        let prop = container_type(ContainerType::Normal);
+        assert_eq!(prop.to_string(), "container-type: normal;");
+
+        let prop = container_type(ContainerType::Size);
+        assert_eq!(prop.to_string(), "container-type: size;");
+
+        let prop = container_type(ContainerType::InlineSize);
+        assert_eq!(prop.to_string(), "container-type: inline-size;");
+    }
+
+    #[test]
+    fn test_variable() {
+        let prop = container_type(ContainerType::Var(var("container")));
+        assert_eq!(prop.to_string(), "container-type: var(--container);");
+    }
+}
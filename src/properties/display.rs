@@ -0,0 +1,121 @@
+//! # Display Property
+//!
+//! This module provides a function for creating the CSS `display` property.
+//! The `display` property sets whether an element is treated as a block or inline box
+//! and the layout used for its children, such as flow layout, grid or flex.
+//!
+//! ## Syntax
+//!
+//! ```css
+//! /* Outside keywords */
+//! display: block;
+//! display: inline;
+//! display: inline-block;
+//!
+//! /* Inside keywords */
+//! display: flex;
+//! display: grid;
+//! display: table;
+//!
+//! /* Box generation */
+//! display: none;
+//!
+//! /* Global values */
+//! display: inherit;
+//! display: initial;
+//! display: unset;
+//! ```
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::properties::display;
+//! use mew_css::values::Display;
+//!
+//! let prop = display::display(Display::Flex);
+//! assert_eq!(prop.to_string(), "display: flex;");
+//!
+//! let prop = display::display(Display::None);
+//! assert_eq!(prop.to_string(), "display: none;");
+//! ```
+
+use crate::properties::Property;
+use crate::values::Display;
+
+/// Creates a CSS `display` property.
+///
+/// The `display` property sets whether an element is treated as a block or inline box
+/// and the layout used for its children.
+///
+/// ## Values
+///
+/// - `none`: The element and its descendants are not displayed.
+/// - `block`: The element generates a block box, with line breaks before and after it.
+/// - `inline`: The element generates one or more inline boxes that flow with the text.
+/// - `inline-block`: The element generates a block box that flows with the surrounding content.
+/// - `flex`: The element behaves like a block and lays out its content according to the flexbox model.
+/// - `grid`: The element behaves like a block and lays out its content according to the grid model.
+/// - `table`: The element behaves like the HTML `<table>` element.
+///
+/// # Arguments
+///
+/// * `value` - The display value to use
+///
+/// # Returns
+///
+/// A new `Property` instance representing the display property
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::properties::display;
+/// use mew_css::values::Display;
+///
+/// let prop = display::display(Display::Grid);
+/// assert_eq!(prop.to_string(), "display: grid;");
+///
+/// let prop = display::display(Display::InlineBlock);
+/// assert_eq!(prop.to_string(), "display: inline-block;");
+/// ```
+pub fn display(value: Display) -> Property {
+    Property::new("display", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variable::var;
+
+    #[test]
+    fn test_outside_keywords() {
+        let prop = display(Display::Block);
+        assert_eq!(prop.to_string(), "display: block;");
+
+        let prop = display(Display::Inline);
+        assert_eq!(prop.to_string(), "display: inline;");
+
+        let prop = display(Display::InlineBlock);
+        assert_eq!(prop.to_string(), "display: inline-block;");
+    }
+
+    #[test]
+    fn test_inside_keywords() {
+        let prop = display(Display::Flex);
+        assert_eq!(prop.to_string(), "display: flex;");
+
+        let prop = display(Display::Grid);
+        assert_eq!(prop.to_string(), "display: grid;");
+
+        let prop = display(Display::Table);
+        assert_eq!(prop.to_string(), "display: table;");
+    }
+
+    #[test]
+    fn test_none_and_variables() {
+        let prop = display(Display::None);
+        assert_eq!(prop.to_string(), "display: none;");
+
+        let prop = display(Display::Var(var("layout")));
+        assert_eq!(prop.to_string(), "display: var(--layout);");
+    }
+}
@@ -0,0 +1,104 @@
+//! # Font Weight Property
+//!
+//! This module provides a function for creating the CSS `font-weight` property.
+//! The `font-weight` property sets the weight (or boldness) of the font.
+//!
+//! ## Syntax
+//!
+//! ```css
+//! /* Keyword values */
+//! font-weight: normal;
+//! font-weight: bold;
+//!
+//! /* Keyword values relative to the parent */
+//! font-weight: lighter;
+//! font-weight: bolder;
+//!
+//! /* Numeric values */
+//! font-weight: 100;
+//! font-weight: 600;
+//! font-weight: 900;
+//! ```
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::properties::font_weight;
+//! use mew_css::values::FontWeight;
+//!
+//! let prop = font_weight::font_weight(FontWeight::Bold);
+//! assert_eq!(prop.to_string(), "font-weight: bold;");
+//!
+//! let prop = font_weight::font_weight(FontWeight::Weight(300));
+//! assert_eq!(prop.to_string(), "font-weight: 300;");
+//! ```
+
+use crate::properties::Property;
+use crate::values::FontWeight;
+
+/// Creates a CSS `font-weight` property.
+///
+/// The `font-weight` property sets the weight (or boldness) of the font.
+///
+/// ## Values
+///
+/// - `normal`: Normal font weight, the same as `400`.
+/// - `bold`: Bold font weight, the same as `700`.
+/// - `lighter`: One relative font weight lighter than the parent element.
+/// - `bolder`: One relative font weight heavier than the parent element.
+/// - `100` to `900`: A numeric weight; invalid weights are written as `400`
+///   (see `FontWeight::try_weight`).
+///
+/// # Arguments
+///
+/// * `value` - The font weight value to use
+///
+/// # Returns
+///
+/// A new `Property` instance representing the font-weight property
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::properties::font_weight;
+/// use mew_css::values::FontWeight;
+///
+/// let prop = font_weight::font_weight(FontWeight::Lighter);
+/// assert_eq!(prop.to_string(), "font-weight: lighter;");
+/// ```
+pub fn font_weight(value: FontWeight) -> Property {
+    Property::new("font-weight", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variable::var;
+
+    #[test]
+    fn test_keywords() {
+        let prop = font_weight(FontWeight::Normal);
+        assert_eq!(prop.to_string(), "font-weight: normal;");
+
+        let prop = font_weight(FontWeight::Bolder);
+        assert_eq!(prop.to_string(), "font-weight: bolder;");
+    }
+
+    #[test]
+    fn test_numeric_weights() {
+        let prop = font_weight(FontWeight::Weight(900));
+        assert_eq!(prop.to_string(), "font-weight: 900;");
+
+        let prop = font_weight(FontWeight::Weight(450));
+        assert_eq!(prop.to_string(), "font-weight: 450;");
+
+        let prop = font_weight(FontWeight::Weight(1200));
+        assert_eq!(prop.to_string(), "font-weight: 400;");
+    }
+
+    #[test]
+    fn test_variables() {
+        let prop = font_weight(FontWeight::Var(var("weight")));
+        assert_eq!(prop.to_string(), "font-weight: var(--weight);");
+    }
+}
@@ -9,6 +9,11 @@
 //!
 //! - `align_content`: Align content property for flex and grid containers
 //! - `align_items`: Align items property for flex and grid containers
+//! - `animation`: Animation shorthand property referencing `@keyframes` rules
+//! - `color`: Color property setting the foreground color of the text
+//! - `container`, `container_name`, `container_type`: Query container properties used by `@container` rules
+//! - `display`: Display property controlling the box type and layout of an element
+//! - `font_weight`: Font weight property setting the boldness of the text
 //!
 //! ## Usage
 //!
@@ -28,6 +33,7 @@
 //! let css = style().align_content(AlignContent::Center).apply();
 //! ```
 
+use crate::format::OutputOptions;
 use std::fmt;
 
 /// Represents a single CSS property with a name and value.
@@ -51,7 +57,7 @@ use std::fmt;
 /// // The string representation includes the semicolon
 /// assert_eq!(color_prop.to_string(), "color: blue;");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Property {
     /// The CSS property name (e.g., "color", "margin-top")
     name: String,
@@ -88,6 +94,56 @@ impl Property {
             value: value.to_string(),
         }
     }
+
+    /// Returns the CSS property name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::properties::Property;
+    ///
+    /// assert_eq!(Property::new("margin", "10px").name(), "margin");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the CSS property value as a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::properties::Property;
+    ///
+    /// assert_eq!(Property::new("opacity", 0.5).value(), "0.5");
+    /// ```
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Generates the CSS text of this declaration with the given output options.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The output options to use
+    ///
+    /// # Returns
+    ///
+    /// A string containing the CSS representation of the declaration
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::format::OutputOptions;
+    /// use mew_css::properties::Property;
+    ///
+    /// let prop = Property::new("color", "blue");
+    /// assert_eq!(prop.to_css_with(&OutputOptions::compact()), "color: blue;");
+    /// assert_eq!(prop.to_css_with(&OutputOptions::minified()), "color:blue");
+    /// ```
+    pub fn to_css_with(&self, options: &OutputOptions) -> String {
+        options.declaration(self)
+    }
 }
 
 impl fmt::Display for Property {
@@ -99,9 +155,16 @@ impl fmt::Display for Property {
 // Export property modules
 pub mod align_content;
 pub mod align_items;
+pub mod animation;
 pub mod background_color;
 pub mod border;
 pub mod border_bottom;
 pub mod border_top;
 pub mod border_left;
 pub mod border_right;
+pub mod color;
+pub mod container;
+pub mod container_name;
+pub mod container_type;
+pub mod display;
+pub mod font_weight;
@@ -0,0 +1,501 @@
+//! # CSS Rule Module
+//!
+//! This module provides the `Rule` type, which pairs a CSS selector with a `Style`
+//! to produce a complete style rule such as `.btn { color: red; }`.
+//!
+//! While a `Style` only knows how to render a flat list of declarations, a `Rule`
+//! wraps those declarations in a selector block so they can be used directly in a
+//! stylesheet.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::{rule, style};
+//! use mew_css::values::{AlignItems, Color};
+//!
+//! let btn = rule(".btn", style()
+//!     .align_items(AlignItems::Center)
+//!     .background_color(Color::Red));
+//!
+//! assert_eq!(btn.to_string(), ".btn { align-items: center; background-color: red; }");
+//! ```
+
+use crate::at_rule::AtRule;
+use crate::format::OutputOptions;
+use crate::style::{Nesting, Style};
+use crate::stylesheet::StylesheetItem;
+use std::fmt;
+
+/// A CSS style rule made of a selector and the style applied to it.
+///
+/// The `Rule` struct renders its style inside a selector block through its
+/// `Display` implementation. The selector is stored as written, so any valid CSS
+/// selector (type, class, id, attribute, combinators, selector lists) can be used.
+///
+/// Nested styles (such as `:hover` states) are rendered as additional blocks
+/// following the main block, with their selector derived from the rule's selector.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::rule::Rule;
+/// use mew_css::style;
+/// use mew_css::values::Color;
+///
+/// let rule = Rule::new("a:visited", style().background_color(Color::Purple));
+/// assert_eq!(rule.to_string(), "a:visited { background-color: purple; }");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rule {
+    /// The CSS selector targeted by this rule (e.g., ".btn", "nav > a")
+    selector: String,
+    /// The style applied to the elements matched by the selector
+    style: Style,
+}
+
+impl Rule {
+    /// Creates a new rule for the given selector and style.
+    ///
+    /// The style is copied into the rule, which makes it possible to pass the
+    /// result of a `style()` chain directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - The CSS selector the rule applies to
+    /// * `style` - The style to apply to the matched elements
+    ///
+    /// # Returns
+    ///
+    /// A new `Rule` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::rule::Rule;
+    /// use mew_css::style;
+    /// use mew_css::values::AlignContent;
+    ///
+    /// let rule = Rule::new(".grid", style().align_content(AlignContent::SpaceBetween));
+    /// assert_eq!(rule.to_string(), ".grid { align-content: space-between; }");
+    /// ```
+    pub fn new(selector: &str, style: &Style) -> Self {
+        Self {
+            selector: selector.trim().to_string(),
+            style: style.clone(),
+        }
+    }
+
+    /// Returns the selector of this rule.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    ///
+    /// let rule = rule(".card", &style());
+    /// assert_eq!(rule.selector(), ".card");
+    /// ```
+    pub fn selector(&self) -> &str {
+        &self.selector
+    }
+
+    /// Returns the style applied by this rule.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let rule = rule(".card", style().background_color(Color::White));
+    /// assert_eq!(rule.style().apply(), "background-color: white;");
+    /// ```
+    pub fn style(&self) -> &Style {
+        &self.style
+    }
+
+    /// Expands this rule and its nested styles into a flat list of blocks.
+    ///
+    /// The first item is the rule itself with only its own declarations, followed by
+    /// one item per nested style, in insertion order. The main block is omitted when
+    /// the rule has no declarations of its own but does have nested styles.
+    ///
+    /// # Returns
+    ///
+    /// The blocks that make up this rule, none of which contain nested styles
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let btn = rule(".btn", style()
+    ///     .background_color(Color::Blue)
+    ///     .hover(|s| s.background_color(Color::Navy)));
+    ///
+    /// let blocks: Vec<String> = btn.flatten().iter().map(|item| item.to_string()).collect();
+    /// assert_eq!(blocks, vec![
+    ///     ".btn { background-color: blue; }",
+    ///     ".btn:hover { background-color: navy; }",
+    /// ]);
+    /// ```
+    pub fn flatten(&self) -> Vec<StylesheetItem> {
+        let mut items = Vec::new();
+        flatten_into(&self.selector, &self.style, &mut items);
+        items
+    }
+
+    /// Generates the CSS text of this rule with the given output options.
+    ///
+    /// Nested styles are rendered as separate blocks, as with the `Display` implementation,
+    /// which is equivalent to using `OutputOptions::compact()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The output options to use
+    ///
+    /// # Returns
+    ///
+    /// A string containing the CSS representation of the rule
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::format::OutputOptions;
+    /// use mew_css::values::Color;
+    ///
+    /// let btn = rule(".btn", style()
+    ///     .background_color(Color::Blue)
+    ///     .hover(|s| s.background_color(Color::Navy)));
+    ///
+    /// assert_eq!(
+    ///     btn.to_css_with(&OutputOptions::pretty()),
+    ///     ".btn {\n  background-color: blue;\n}\n\n.btn:hover {\n  background-color: navy;\n}"
+    /// );
+    /// assert_eq!(
+    ///     btn.to_css_with(&OutputOptions::minified()),
+    ///     ".btn{background-color:blue}.btn:hover{background-color:navy}"
+    /// );
+    /// ```
+    pub fn to_css_with(&self, options: &OutputOptions) -> String {
+        options.join_blocks(options.blocks(&[self.clone().into()], 0), " ")
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_css_with(&OutputOptions::compact()))
+    }
+}
+
+/// Pushes the blocks of a selector and its style (including nested styles) to `items`.
+fn flatten_into(selector: &str, style: &Style, items: &mut Vec<StylesheetItem>) {
+    if !style.properties().is_empty() || style.nested().is_empty() {
+        items.push(Rule::new(selector, &style.declarations()).into());
+    }
+
+    for nested in style.nested() {
+        match nested.nesting() {
+            Nesting::Selector(suffix) => {
+                flatten_into(&nest_selector(selector, suffix), nested.style(), items);
+            }
+            Nesting::Media(query) => {
+                let mut block = Vec::new();
+                flatten_into(selector, nested.style(), &mut block);
+                items.push(AtRule::media(query, block).into());
+            }
+            Nesting::Supports(condition) => {
+                let mut block = Vec::new();
+                flatten_into(selector, nested.style(), &mut block);
+                items.push(AtRule::supports(condition, block).into());
+            }
+            Nesting::Container(query) => {
+                let mut block = Vec::new();
+                flatten_into(selector, nested.style(), &mut block);
+                items.push(AtRule::container(query.clone(), block).into());
+            }
+        }
+    }
+}
+
+/// Combines a parent selector with a nested selector.
+///
+/// Both selectors may be comma-separated lists, which are combined pairwise, so that
+/// `a, b` nested with `:hover, :focus` becomes `a:hover, a:focus, b:hover, b:focus`.
+/// If a nested selector contains `&`, it is substituted by the parent selector,
+/// otherwise the nested selector is appended.
+fn nest_selector(parent: &str, nested: &str) -> String {
+    let nested = split_selector_list(nested);
+    split_selector_list(parent)
+        .iter()
+        .flat_map(|parent| {
+            nested.iter().map(move |nested| {
+                if nested.contains('&') {
+                    nested.replace('&', parent)
+                } else {
+                    format!("{}{}", parent, nested)
+                }
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Splits a selector list on its top-level commas, ignoring commas inside
+/// parentheses or brackets (e.g., in `:is(a, b)` or `[title="a,b"]`).
+fn split_selector_list(selector: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+
+    for (i, c) in selector.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '(') | (None, '[') => depth += 1,
+            (None, ')') | (None, ']') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                parts.push(selector[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(selector[start..].trim());
+    parts
+}
+
+/// Creates a new CSS rule for the given selector and style.
+///
+/// This is a convenience function that creates a new `Rule` instance.
+/// It's a shorter alternative to calling `Rule::new()`.
+///
+/// # Arguments
+///
+/// * `selector` - The CSS selector the rule applies to
+/// * `style` - The style to apply to the matched elements
+///
+/// # Returns
+///
+/// A new `Rule` instance
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::{rule, style};
+/// use mew_css::values::{BorderStyle, Color};
+///
+/// let css = rule(".btn", style().border_with_color(BorderStyle::Solid, Color::Black)).to_string();
+/// assert_eq!(css, ".btn { border: solid black; }");
+/// ```
+pub fn rule(selector: &str, style: &Style) -> Rule {
+    Rule::new(selector, style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::style;
+    use crate::container::ContainerQuery;
+    use crate::media::{Hover, MediaQuery};
+    use crate::properties::display::display;
+    use crate::supports::supports;
+    use crate::values::{AlignItems, Color, Display, Size};
+    use crate::variable::var;
+
+    #[test]
+    fn test_rule_single_property() {
+        let rule = rule(".btn", style().background_color(Color::Red));
+        assert_eq!(rule.to_string(), ".btn { background-color: red; }");
+    }
+
+    #[test]
+    fn test_rule_multiple_properties() {
+        let rule = rule(
+            "nav > a",
+            style()
+                .align_items(AlignItems::Center)
+                .background_color(Color::Transparent),
+        );
+        assert_eq!(
+            rule.to_string(),
+            "nav > a { align-items: center; background-color: transparent; }"
+        );
+    }
+
+    #[test]
+    fn test_rule_empty_style() {
+        let rule = rule(".empty", &style());
+        assert_eq!(rule.to_string(), ".empty {}");
+    }
+
+    #[test]
+    fn test_rule_trims_selector() {
+        let rule = rule("  .btn  ", style().background_color(Color::Blue));
+        assert_eq!(rule.selector(), ".btn");
+        assert_eq!(rule.to_string(), ".btn { background-color: blue; }");
+    }
+
+    #[test]
+    fn test_rule_hover_state() {
+        let rule = rule(
+            ".btn",
+            style()
+                .background_color(Color::Blue)
+                .hover(|s| s.background_color(Color::Navy)),
+        );
+        assert_eq!(
+            rule.to_string(),
+            ".btn { background-color: blue; } .btn:hover { background-color: navy; }"
+        );
+    }
+
+    #[test]
+    fn test_rule_multiple_states() {
+        let rule = rule(
+            ".btn",
+            style()
+                .background_color(Color::Blue)
+                .focus_visible(|s| s.background_color(Color::RoyalBlue))
+                .disabled(|s| s.background_color(Color::Gray)),
+        );
+        assert_eq!(
+            rule.to_string(),
+            ".btn { background-color: blue; } \
+             .btn:focus-visible { background-color: royalblue; } \
+             .btn:disabled { background-color: gray; }"
+        );
+    }
+
+    #[test]
+    fn test_rule_only_nested() {
+        let rule = rule("a", style().hover(|s| s.background_color(Color::Red)));
+        assert_eq!(rule.to_string(), "a:hover { background-color: red; }");
+    }
+
+    #[test]
+    fn test_rule_deeply_nested() {
+        let rule = rule(
+            "li",
+            style().pseudo(":nth-child(2n)", |s| {
+                s.background_color(Color::Silver)
+                    .hover(|s| s.background_color(Color::Gray))
+            }),
+        );
+        assert_eq!(
+            rule.to_string(),
+            "li:nth-child(2n) { background-color: silver; } li:nth-child(2n):hover { background-color: gray; }"
+        );
+    }
+
+    #[test]
+    fn test_rule_selector_list() {
+        let list = rule("a, button", style().hover(|s| s.background_color(Color::Red)));
+        assert_eq!(list.to_string(), "a:hover, button:hover { background-color: red; }");
+
+        let list = rule(":is(a, b), c", style().focus(|s| s.background_color(Color::Red)));
+        assert_eq!(list.to_string(), ":is(a, b):focus, c:focus { background-color: red; }");
+
+        let list = rule(".a, .b", style().pseudo(":hover, :focus", |s| s.background_color(Color::Red)));
+        assert_eq!(list.to_string(), ".a:hover, .a:focus, .b:hover, .b:focus { background-color: red; }");
+
+        let list = rule(".a, .b", style().pseudo("& > p, :is(x, y)", |s| s.background_color(Color::Red)));
+        assert_eq!(list.to_string(), ".a > p, .a:is(x, y), .b > p, .b:is(x, y) { background-color: red; }");
+    }
+
+    #[test]
+    fn test_rule_ampersand_selector() {
+        let card = rule(".card", style().pseudo(".dark &", |s| s.background_color(Color::Black)));
+        assert_eq!(card.to_string(), ".dark .card { background-color: black; }");
+
+        let item = rule(".item", style().pseudo("& + &", |s| s.background_color(Color::White)));
+        assert_eq!(item.to_string(), ".item + .item { background-color: white; }");
+    }
+
+    #[test]
+    fn test_rule_media() {
+        let nav = rule(
+            ".nav",
+            style()
+                .align_items(AlignItems::Start)
+                .media(MediaQuery::min_width(Size::Px(768.0)), |s| {
+                    s.align_items(AlignItems::Center)
+                        .hover(|s| s.background_color(Color::Black))
+                }),
+        );
+        assert_eq!(
+            nav.to_string(),
+            ".nav { align-items: start; } \
+             @media (min-width: 768px) { .nav { align-items: center; } .nav:hover { background-color: black; } }"
+        );
+    }
+
+    #[test]
+    fn test_rule_media_inside_state() {
+        let link = rule(
+            "a",
+            style().hover(|s| {
+                s.media(MediaQuery::hover(Hover::Hover), |s| s.background_color(Color::Yellow))
+            }),
+        );
+        assert_eq!(
+            link.to_string(),
+            "@media (hover: hover) { a:hover { background-color: yellow; } }"
+        );
+    }
+
+    #[test]
+    fn test_rule_supports() {
+        let gallery = rule(
+            ".gallery",
+            style()
+                .display(Display::Flex)
+                .supports(supports(display(Display::Grid)), |s| {
+                    s.display(Display::Grid)
+                        .media(MediaQuery::min_width(Size::Px(768.0)), |s| s.align_items(AlignItems::Center))
+                }),
+        );
+        assert_eq!(
+            gallery.to_string(),
+            ".gallery { display: flex; } \
+             @supports (display: grid) { .gallery { display: grid; } \
+             @media (min-width: 768px) { .gallery { align-items: center; } } }"
+        );
+    }
+
+    #[test]
+    fn test_rule_container() {
+        let title = rule(
+            ".card h2",
+            style()
+                .background_color(Color::White)
+                .container(ContainerQuery::style(var("theme"), "dark"), |s| {
+                    s.background_color(Color::Black)
+                })
+                .container(ContainerQuery::min_width(Size::Px(400.0)).named("card"), |s| {
+                    s.hover(|s| s.background_color(Color::Gray))
+                }),
+        );
+        assert_eq!(
+            title.to_string(),
+            ".card h2 { background-color: white; } \
+             @container style(--theme: dark) { .card h2 { background-color: black; } } \
+             @container card (min-width: 400px) { .card h2:hover { background-color: gray; } }"
+        );
+    }
+
+    #[test]
+    fn test_rule_copies_style() {
+        let mut base = style();
+        base.background_color(Color::Red);
+
+        let rule = rule(".btn", &base);
+        base.background_color(Color::Blue);
+
+        assert_eq!(rule.to_string(), ".btn { background-color: red; }");
+    }
+}
@@ -45,10 +45,32 @@
 //!     .set_var("spacing", "1rem")
 //!     .apply();
 //! ```
+//!
+//! ### Nested States
+//!
+//! ```rust
+//! use mew_css::{rule, style};
+//! use mew_css::values::Color;
+//!
+//! let css = rule(".btn", style()
+//!     .background_color(Color::Blue)
+//!     .hover(|s| s.background_color(Color::Navy)))
+//!     .to_string();
+//!
+//! assert_eq!(css, ".btn { background-color: blue; } .btn:hover { background-color: navy; }");
+//! ```
 
-use crate::properties::{Property, align_content, align_items, background_color, border, border_bottom, border_top, border_left, border_right};
+use crate::format::OutputOptions;
+use crate::media::MediaQuery;
+use crate::properties::{Property, align_content, align_items, animation, background_color, border, border_bottom, border_top, border_left, border_right, color, container, container_name, container_type, display, font_weight};
+use crate::supports::SupportsCondition;
+use crate::container::ContainerPrelude;
+use crate::parser::{self, ParseError};
+use crate::error::{MewError, ValidationIssue};
+use crate::color::{ContrastAudit, WcagLevel};
 use crate::values::*;
 use std::fmt;
+use std::str::FromStr;
 
 /// A CSS style builder that provides a fluent API for creating CSS styles.
 ///
@@ -71,48 +93,770 @@ use std::fmt;
 /// ```
 ///
 /// The resulting CSS string will be: `align-content: center;`
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Style {
     /// Collection of CSS properties that make up this style
     properties: Vec<Property>,
+    /// Nested styles rendered as separate blocks when the style is emitted as a rule
+    nested: Vec<NestedStyle>,
+    /// Whether properties with invalid values are left out instead of being added
+    strict: bool,
+    /// Invalid values given to the typed property methods
+    issues: Vec<ValidationIssue>,
+}
+
+/// Describes how a nested style is attached to its parent style.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Nesting {
+    /// A selector appended to the parent selector (e.g., `:hover`, `::before`).
+    ///
+    /// If the selector contains `&`, every `&` is replaced by the parent selector
+    /// instead (e.g., `.dark &` or `& + &`).
+    Selector(String),
+    /// A `@media` block wrapping the parent selector.
+    Media(MediaQuery),
+    /// A `@supports` block wrapping the parent selector.
+    Supports(SupportsCondition),
+    /// A `@container` block wrapping the parent selector.
+    Container(ContainerPrelude),
+}
+
+/// A style nested inside another style, such as the `:hover` state of a button.
+///
+/// Nested styles are created through methods like `Style::hover` or `Style::pseudo`.
+/// They do not appear in the output of `Style::apply`, which only renders
+/// declarations, but are emitted as separate blocks when the style is rendered
+/// as part of a `Rule`.
+#[derive(Debug, Clone)]
+pub struct NestedStyle {
+    /// How the nested style is attached to its parent
+    nesting: Nesting,
+    /// The declarations (and further nested styles) of the nested block
+    style: Style,
+}
+
+impl NestedStyle {
+    /// Returns how the nested style is attached to its parent.
+    pub fn nesting(&self) -> &Nesting {
+        &self.nesting
+    }
+
+    /// Returns the style of the nested block.
+    pub fn style(&self) -> &Style {
+        &self.style
+    }
 }
 
 impl Style {
     /// Creates a new empty style with no properties.
     ///
-    /// This is the starting point for building a CSS style. After creating a new style,
-    /// you can chain method calls to add properties.
+    /// This is the starting point for building a CSS style. After creating a new style,
+    /// you can chain method calls to add properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style::Style;
+    ///
+    /// let style = Style::new();
+    /// ```
+    ///
+    /// More commonly, you'll use the `style()` function:
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    ///
+    /// let style = style();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            properties: Vec::new(),
+            nested: Vec::new(),
+            strict: false,
+            issues: Vec::new(),
+        }
+    }
+
+    /// Parses a CSS declaration block into a style.
+    ///
+    /// Declarations of properties the library types (such as `align-content`,
+    /// `background-color` or `border`) are parsed into their typed values, and invalid
+    /// values are reported. Other declarations are kept as written, like `custom_property`.
+    /// Comments and `!important` are supported. Typed values go through the same setters as
+    /// the builder methods, so a weight such as `font-weight: 1200` is reported by `validate()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `css` - The declarations, separated by semicolons (e.g., the content of a `style` attribute)
+    ///
+    /// # Returns
+    ///
+    /// The parsed style, or a `ParseError` carrying the byte offset and the text of the
+    /// offending declaration or value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style::Style;
+    ///
+    /// let style = Style::parse("align-items: center; padding: 1rem 2rem").unwrap();
+    /// assert_eq!(style.apply(), "align-items: center; padding: 1rem 2rem;");
+    ///
+    /// let error = Style::parse("align-items: middle").unwrap_err();
+    /// assert_eq!(error.offset(), 13);
+    /// assert_eq!(error.text(), "middle");
+    /// ```
+    pub fn parse(css: &str) -> Result<Self, ParseError> {
+        parser::parse_style(css)
+    }
+
+    /// Adds a property to the style and returns a mutable reference to self.
+    ///
+    /// This is a low-level method used by the property-specific methods. Most users
+    /// won't need to call this directly, but it's useful for extending the library
+    /// with custom properties.
+    ///
+    /// # Arguments
+    ///
+    /// * `property` - The CSS property to add
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::properties::Property;
+    ///
+    /// let css = style()
+    ///     .add_property(Property::new("color", "blue"))
+    ///     .add_property(Property::new("font-size", "16px"))
+    ///     .apply();
+    /// ```
+    pub fn add_property(&mut self, property: Property) -> &mut Self {
+        self.properties.push(property);
+        self
+    }
+
+    /// Adds a property built from typed values, recording an issue if the values are invalid.
+    ///
+    /// In strict mode, the property is left out when the values are invalid.
+    fn add_validated(&mut self, property: Property, validation: Result<(), MewError>) -> &mut Self {
+        let input = property.value().to_string();
+        self.add_validated_input(property, &input, validation)
+    }
+
+    /// Same as `add_validated`, but records `input` as the value of the issue, for values
+    /// that are rewritten when written (e.g., an invalid `FontWeight::Weight` is written as `400`).
+    fn add_validated_input(
+        &mut self,
+        property: Property,
+        input: &str,
+        validation: Result<(), MewError>,
+    ) -> &mut Self {
+        match validation {
+            Ok(()) => self.add_property(property),
+            Err(error) => {
+                self.issues.push(ValidationIssue::new(property.name(), input, error));
+                if self.strict { self } else { self.add_property(property) }
+            }
+        }
+    }
+
+    /// Enables or disables strict mode.
+    ///
+    /// By default, invalid values given to the typed property methods are still written,
+    /// possibly rewritten (e.g., an invalid `FontWeight::Weight` is written as `400`). In
+    /// strict mode, the properties with invalid values are left out of the style instead.
+    /// In both modes, the invalid values are reported by `validate()`.
+    ///
+    /// Nested styles created after this call inherit the mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether properties with invalid values are left out
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::values::{Color, FontWeight};
+    ///
+    /// let mut lenient = style();
+    /// lenient.font_weight(FontWeight::Weight(1200)).background_color(Color::Red);
+    /// assert_eq!(lenient.apply(), "font-weight: 400; background-color: red;");
+    ///
+    /// let mut strict = style();
+    /// strict.strict(true).font_weight(FontWeight::Weight(1200)).background_color(Color::Red);
+    /// assert_eq!(strict.apply(), "background-color: red;");
+    /// assert_eq!(strict.validate().len(), 1);
+    /// ```
+    pub fn strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Returns `true` if strict mode is enabled (see `strict()`).
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Returns the invalid values given to the typed property methods of this style
+    /// and of its nested styles.
+    ///
+    /// Values are checked when they are added, as some invalid values cannot be told
+    /// apart once written (an invalid `FontWeight::Weight` is written as `400`).
+    /// Properties added as text, such as with `custom_property`, are not checked.
+    ///
+    /// # Returns
+    ///
+    /// The issues found, in insertion order (the issues of nested styles come last)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::error::MewError;
+    /// use mew_css::style;
+    /// use mew_css::values::{BorderStyle, Color};
+    ///
+    /// let mut style = style();
+    /// style
+    ///     .background_color(Color::Hex("#12".to_string()))
+    ///     .hover(|s| s.border_with_color(BorderStyle::Solid, Color::Rgba(0, 0, 0, 2.0)));
+    ///
+    /// let issues = style.validate();
+    /// assert_eq!(issues[0].error(), &MewError::InvalidHex("#12".to_string()));
+    /// assert_eq!(issues[1].value(), "solid rgba(0, 0, 0, 2)");
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = self.issues.clone();
+        for nested in &self.nested {
+            issues.extend(nested.style.validate());
+        }
+        issues
+    }
+
+    /// Checks the contrast between the `color` and `background-color` of this style
+    /// against a WCAG level.
+    ///
+    /// The last declaration of each property is used, whether it was added with
+    /// `color` and `background_color`, parsed, or added as text. Nested styles are
+    /// not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The level to check against
+    ///
+    /// # Returns
+    ///
+    /// The result of the check, or `None` if either property is missing or its color
+    /// cannot be resolved (e.g., a CSS variable)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::color::WcagLevel;
+    /// use mew_css::style;
+    /// use mew_css::values::Color;
+    ///
+    /// let mut button = style();
+    /// button.color(Color::White).background_color(Color::Orange);
+    ///
+    /// let audit = button.audit_contrast(WcagLevel::AA).unwrap();
+    /// assert!(!audit.passes_normal_text());
+    /// assert!(!audit.passes_large_text());
+    ///
+    /// button.background_color(Color::DarkGreen);
+    /// assert!(button.audit_contrast(WcagLevel::AAA).unwrap().passes_normal_text());
+    /// ```
+    pub fn audit_contrast(&self, level: WcagLevel) -> Option<ContrastAudit> {
+        let last_color = |name: &str| {
+            self.properties
+                .iter()
+                .rev()
+                .find(|property| property.name() == name)
+                .and_then(|property| Color::parse(property.value()).ok())
+        };
+
+        ContrastAudit::new(&last_color("color")?, &last_color("background-color")?, level)
+    }
+
+    /// Returns the properties that have been added to this style, in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::values::AlignContent;
+    ///
+    /// let mut style = style();
+    /// style.align_content(AlignContent::Center);
+    ///
+    /// assert_eq!(style.properties().len(), 1);
+    /// assert_eq!(style.properties()[0].to_string(), "align-content: center;");
+    /// ```
+    pub fn properties(&self) -> &[Property] {
+        &self.properties
+    }
+
+    /// Returns the nested styles of this style, in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::style::Nesting;
+    /// use mew_css::values::Color;
+    ///
+    /// let mut style = style();
+    /// style.hover(|s| s.background_color(Color::Red));
+    ///
+    /// assert_eq!(style.nested()[0].nesting(), &Nesting::Selector(":hover".to_string()));
+    /// ```
+    pub fn nested(&self) -> &[NestedStyle] {
+        &self.nested
+    }
+
+    /// Returns `true` if no property and no nested style has been added to this style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::values::AlignContent;
+    ///
+    /// let mut style = style();
+    /// assert!(style.is_empty());
+    ///
+    /// style.align_content(AlignContent::Center);
+    /// assert!(!style.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty() && self.nested.is_empty()
+    }
+
+    /// Returns a copy of this style containing only its declarations.
+    pub(crate) fn declarations(&self) -> Style {
+        Self {
+            properties: self.properties.clone(),
+            ..Self::new()
+        }
+    }
+
+    /// Marks the properties from index `start` onwards as `!important`.
+    pub(crate) fn mark_important(&mut self, start: usize) {
+        for property in &mut self.properties[start..] {
+            *property = Property::new(property.name(), format!("{} !important", property.value()));
+        }
+    }
+
+    /// Adds a nested style built by the given closure.
+    ///
+    /// This is the low-level method used by `pseudo` and the state helpers such as
+    /// `hover`.
+    ///
+    /// # Arguments
+    ///
+    /// * `nesting` - How the nested style is attached to this style
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::style::Nesting;
+    /// use mew_css::values::Color;
+    ///
+    /// let css = rule(".card", style()
+    ///     .nest(Nesting::Selector(".dark &".to_string()), |s| s.background_color(Color::Black)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(css, ".dark .card { background-color: black; }");
+    /// ```
+    pub fn nest<F>(&mut self, nesting: Nesting, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        let mut style = Style::new();
+        style.strict = self.strict;
+        build(&mut style);
+        self.nested.push(NestedStyle { nesting, style });
+        self
+    }
+
+    /// Adds a nested style for an arbitrary pseudo-class or pseudo-element.
+    ///
+    /// The selector is appended to the selector of the enclosing rule. If it contains
+    /// `&`, each `&` is replaced by the enclosing selector instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - The pseudo selector to append (e.g., `":nth-child(2n)"`, `"::before"`)
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let css = rule("tr", style()
+    ///     .pseudo(":nth-child(2n)", |s| s.background_color(Color::WhiteSmoke)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(css, "tr:nth-child(2n) { background-color: whitesmoke; }");
+    /// ```
+    pub fn pseudo<F>(&mut self, selector: &str, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.nest(Nesting::Selector(selector.trim().to_string()), build)
+    }
+
+    /// Adds a nested style that only applies when the media query matches.
+    ///
+    /// When the style is rendered as part of a `Rule`, the nested style is emitted as a
+    /// `@media` block containing the rule's selector.
+    ///
+    /// A query that cannot be written as a media query list (see `MediaQuery::validate`)
+    /// is reported by `validate()`, and the nested style is left out in strict mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The media query that must match
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::media::MediaQuery;
+    /// use mew_css::values::{AlignContent, Size};
+    ///
+    /// let css = rule(".grid", style()
+    ///     .align_content(AlignContent::Start)
+    ///     .media(MediaQuery::min_width(Size::Px(1024.0)), |s| s.align_content(AlignContent::Center)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(css, ".grid { align-content: start; } \
+    ///                  @media (min-width: 1024px) { .grid { align-content: center; } }");
+    /// ```
+    pub fn media<F>(&mut self, query: MediaQuery, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        if let Err(error) = query.validate() {
+            self.issues.push(ValidationIssue::new("@media", &query.to_string(), error));
+            if self.strict {
+                return self;
+            }
+        }
+        self.nest(Nesting::Media(query), build)
+    }
+
+    /// Adds a nested style that only applies when the browser supports the condition.
+    ///
+    /// When the style is rendered as part of a `Rule`, the nested style is emitted as a
+    /// `@supports` block containing the rule's selector.
+    ///
+    /// An invalid condition (see `SupportsCondition::validate`) is reported by `validate()`,
+    /// and the nested style is left out in strict mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - The feature query that must be supported
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::properties::display::display;
+    /// use mew_css::supports::supports;
+    /// use mew_css::values::Display;
+    ///
+    /// let css = rule(".layout", style()
+    ///     .display(Display::Block)
+    ///     .supports(supports(display(Display::Grid)), |s| s.display(Display::Grid)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(css, ".layout { display: block; } \
+    ///                  @supports (display: grid) { .layout { display: grid; } }");
+    /// ```
+    pub fn supports<F>(&mut self, condition: SupportsCondition, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        if let Err(error) = condition.validate() {
+            self.issues.push(ValidationIssue::new("@supports", &condition.to_string(), error));
+            if self.strict {
+                return self;
+            }
+        }
+        self.nest(Nesting::Supports(condition), build)
+    }
+
+    /// Adds a nested style that only applies when the container query matches.
+    ///
+    /// When the style is rendered as part of a `Rule`, the nested style is emitted as a
+    /// `@container` block containing the rule's selector. The query is evaluated against
+    /// the nearest ancestor established as a query container with `container_type`, or the
+    /// nearest one with a matching name when the query was restricted with `ContainerQuery::named`.
+    ///
+    /// An invalid query (see `ContainerQuery::validate`) is reported by `validate()`, and the
+    /// nested style is left out in strict mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The container query that must match, optionally named
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::container::ContainerQuery;
+    /// use mew_css::values::{Display, Size};
+    ///
+    /// let css = rule(".card", style()
+    ///     .display(Display::Block)
+    ///     .container(ContainerQuery::min_inline_size(Size::Px(400.0)).named("sidebar"), |s| s
+    ///         .display(Display::Flex)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(css, ".card { display: block; } \
+    ///                  @container sidebar (min-inline-size: 400px) { .card { display: flex; } }");
+    /// ```
+    pub fn container<F>(&mut self, query: impl Into<ContainerPrelude>, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        let query = query.into();
+        if let Err(error) = query.validate() {
+            self.issues.push(ValidationIssue::new("@container", &query.to_string(), error));
+            if self.strict {
+                return self;
+            }
+        }
+        self.nest(Nesting::Container(query), build)
+    }
+
+    /// Adds a nested style for the `:hover` state.
+    ///
+    /// # Arguments
+    ///
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let css = rule("a", style().hover(|s| s.background_color(Color::Yellow))).to_string();
+    /// assert_eq!(css, "a:hover { background-color: yellow; }");
+    /// ```
+    pub fn hover<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.pseudo(":hover", build)
+    }
+
+    /// Adds a nested style for the `:focus` state.
+    ///
+    /// # Arguments
+    ///
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let css = rule("input", style().focus(|s| s.background_color(Color::White))).to_string();
+    /// assert_eq!(css, "input:focus { background-color: white; }");
+    /// ```
+    pub fn focus<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.pseudo(":focus", build)
+    }
+
+    /// Adds a nested style for the `:focus-visible` state.
+    ///
+    /// # Arguments
+    ///
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::{BorderStyle, Color};
+    ///
+    /// let css = rule(".btn", style()
+    ///     .focus_visible(|s| s.border_with_color(BorderStyle::Solid, Color::Blue)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(css, ".btn:focus-visible { border: solid blue; }");
+    /// ```
+    pub fn focus_visible<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.pseudo(":focus-visible", build)
+    }
+
+    /// Adds a nested style for the `:focus-within` state.
+    ///
+    /// # Arguments
+    ///
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let css = rule("form", style().focus_within(|s| s.background_color(Color::Ivory))).to_string();
+    /// assert_eq!(css, "form:focus-within { background-color: ivory; }");
+    /// ```
+    pub fn focus_within<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.pseudo(":focus-within", build)
+    }
+
+    /// Adds a nested style for the `:active` state.
+    ///
+    /// # Arguments
+    ///
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use mew_css::style::Style;
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
     ///
-    /// let style = Style::new();
+    /// let css = rule(".btn", style().active(|s| s.background_color(Color::DarkBlue))).to_string();
+    /// assert_eq!(css, ".btn:active { background-color: darkblue; }");
     /// ```
+    pub fn active<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.pseudo(":active", build)
+    }
+
+    /// Adds a nested style for the `:visited` state of links.
     ///
-    /// More commonly, you'll use the `style()` function:
+    /// # Arguments
+    ///
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// use mew_css::style;
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
     ///
-    /// let style = style();
+    /// let css = rule("a", style().visited(|s| s.background_color(Color::Purple))).to_string();
+    /// assert_eq!(css, "a:visited { background-color: purple; }");
     /// ```
-    pub fn new() -> Self {
-        Self {
-            properties: Vec::new(),
-        }
+    pub fn visited<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.pseudo(":visited", build)
     }
 
-    /// Adds a property to the style and returns a mutable reference to self.
+    /// Adds a nested style for the `:disabled` state of form controls.
     ///
-    /// This is a low-level method used by the property-specific methods. Most users
-    /// won't need to call this directly, but it's useful for extending the library
-    /// with custom properties.
+    /// # Arguments
+    ///
+    /// * `build` - A closure that adds properties to the nested style
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
+    ///
+    /// let css = rule(".btn", style().disabled(|s| s.background_color(Color::Gray))).to_string();
+    /// assert_eq!(css, ".btn:disabled { background-color: gray; }");
+    /// ```
+    pub fn disabled<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.pseudo(":disabled", build)
+    }
+
+    /// Adds a nested style for the `:checked` state of checkboxes, radios and options.
     ///
     /// # Arguments
     ///
-    /// * `property` - The CSS property to add
+    /// * `build` - A closure that adds properties to the nested style
     ///
     /// # Returns
     ///
@@ -121,24 +865,25 @@ impl Style {
     /// # Examples
     ///
     /// ```rust
-    /// use mew_css::style;
-    /// use mew_css::properties::Property;
+    /// use mew_css::{rule, style};
+    /// use mew_css::values::Color;
     ///
-    /// let css = style()
-    ///     .add_property(Property::new("color", "blue"))
-    ///     .add_property(Property::new("font-size", "16px"))
-    ///     .apply();
+    /// let css = rule("input", style().checked(|s| s.background_color(Color::Green))).to_string();
+    /// assert_eq!(css, "input:checked { background-color: green; }");
     /// ```
-    pub fn add_property(&mut self, property: Property) -> &mut Self {
-        self.properties.push(property);
-        self
+    pub fn checked<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Style) -> &mut Style,
+    {
+        self.pseudo(":checked", build)
     }
 
     /// Generates the final CSS string from all added properties.
     ///
     /// This method should be called after adding all desired properties to generate
     /// the CSS string. It formats each property as `name: value;` and joins them
-    /// with spaces.
+    /// with spaces. Nested styles such as `:hover` states need a selector and are
+    /// only rendered when the style is part of a `Rule`.
     ///
     /// # Returns
     ///
@@ -184,12 +929,45 @@ impl Style {
         self.apply()
     }
 
+    /// Generates the CSS string of all added properties with the given output options.
+    ///
+    /// Like `apply()`, this only renders declarations; nested styles are rendered
+    /// when the style is part of a `Rule`.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The output options to use
+    ///
+    /// # Returns
+    ///
+    /// A string containing the CSS representation of all properties
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::format::OutputOptions;
+    /// use mew_css::values::{AlignContent, AlignItems};
+    ///
+    /// let mut style = style();
+    /// style.align_content(AlignContent::Center).align_items(AlignItems::Start);
+    ///
+    /// assert_eq!(style.to_css_with(&OutputOptions::pretty()), "align-content: center;\nalign-items: start;");
+    /// assert_eq!(style.to_css_with(&OutputOptions::minified()), "align-content:center;align-items:start");
+    /// ```
+    pub fn to_css_with(&self, options: &OutputOptions) -> String {
+        options.declarations(self, 0)
+    }
+
     /// Adds a custom property with the given name and value.
     ///
     /// This method allows you to add any CSS property, including those not explicitly
     /// supported by the library. It's useful for experimental properties, vendor-prefixed
     /// properties, or any other property not covered by the built-in methods.
     ///
+    /// The value is written as given: it is exempt from `validate()` and is kept in
+    /// strict mode, so prefer the typed methods when they exist.
+    ///
     /// # Arguments
     ///
     /// * `name` - The CSS property name
@@ -322,6 +1100,41 @@ impl Style {
         self.add_property(align_items::align_items(value))
     }
 
+    /// Sets the animation property of an element.
+    ///
+    /// The `animation` property is a shorthand property that applies an animation between styles.
+    /// The animation name comes from the `Keyframes` used to create the `Animation` value, so it
+    /// always matches the `@keyframes` rule added to the stylesheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The animation value to set
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::keyframes::Keyframes;
+    /// use mew_css::values::{Animation, IterationCount, Time, TimingFunction};
+    ///
+    /// let spin = Keyframes::new("spin");
+    ///
+    /// let css = style().animation(Animation::new(&spin, Time::S(2.0))).apply();
+    /// assert_eq!(css, "animation: spin 2s;");
+    ///
+    /// let css = style().animation(Animation::new(&spin, Time::S(1.0))
+    ///     .timing_function(TimingFunction::Linear)
+    ///     .iteration_count(IterationCount::Infinite)).apply();
+    /// assert_eq!(css, "animation: spin 1s linear infinite;");
+    /// ```
+    pub fn animation(&mut self, value: Animation) -> &mut Self {
+        self.add_property(animation::animation(value))
+    }
+
     /// Sets the background-color property of an element.
     ///
     /// The `background-color` property sets the background color of an element.
@@ -353,7 +1166,8 @@ impl Style {
     /// assert_eq!(css, "background-color: rgba(255, 0, 0, 0.5);");
     /// ```
     pub fn background_color(&mut self, value: Color) -> &mut Self {
-        self.add_property(background_color::background_color(value))
+        let validation = value.validate();
+        self.add_validated(background_color::background_color(value), validation)
     }
 
     /// Sets the border property of an element with only style.
@@ -405,14 +1219,15 @@ impl Style {
     /// use mew_css::style;
     /// use mew_css::values::{BorderStyle, Size};
     ///
-    /// let css = style().border_with_width(Size::Px(2), BorderStyle::Dotted).apply();
+    /// let css = style().border_with_width(Size::Px(2.0), BorderStyle::Dotted).apply();
     /// assert_eq!(css, "border: 2px dotted;");
     ///
     /// let css = style().border_with_width(Size::Rem(1.5), BorderStyle::Solid).apply();
     /// assert_eq!(css, "border: 1.5rem solid;");
     /// ```
     pub fn border_with_width(&mut self, width: Size, style: BorderStyle) -> &mut Self {
-        self.add_property(border::border_with_width(width, style))
+        let validation = width.validate();
+        self.add_validated(border::border_with_width(width, style), validation)
     }
 
     /// Sets the border property of an element with style and color.
@@ -442,7 +1257,8 @@ impl Style {
     /// assert_eq!(css, "border: solid red;");
     /// ```
     pub fn border_with_color(&mut self, style: BorderStyle, color: Color) -> &mut Self {
-        self.add_property(border::border_with_color(style, color))
+        let validation = color.validate();
+        self.add_validated(border::border_with_color(style, color), validation)
     }
 
     /// Sets the border property of an element with width, style, and color.
@@ -466,14 +1282,15 @@ impl Style {
     /// use mew_css::style;
     /// use mew_css::values::{BorderStyle, Color, Size};
     ///
-    /// let css = style().border_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green).apply();
+    /// let css = style().border_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green).apply();
     /// assert_eq!(css, "border: 3px dashed green;");
     ///
-    /// let css = style().border_with_width_and_color(Size::Px(4), BorderStyle::Double, Color::Rgb(50, 161, 206)).apply();
+    /// let css = style().border_with_width_and_color(Size::Px(4.0), BorderStyle::Double, Color::Rgb(50, 161, 206)).apply();
     /// assert_eq!(css, "border: 4px double rgb(50, 161, 206);");
     /// ```
     pub fn border_with_width_and_color(&mut self, width: Size, style: BorderStyle, color: Color) -> &mut Self {
-        self.add_property(border::border_with_width_and_color(width, style, color))
+        let validation = width.validate().and_then(|_| color.validate());
+        self.add_validated(border::border_with_width_and_color(width, style, color), validation)
     }
 
     /// Sets the border-bottom property of an element with only style.
@@ -525,14 +1342,15 @@ impl Style {
     /// use mew_css::style;
     /// use mew_css::values::{BorderStyle, Size};
     ///
-    /// let css = style().border_bottom_with_width(Size::Px(2), BorderStyle::Dotted).apply();
+    /// let css = style().border_bottom_with_width(Size::Px(2.0), BorderStyle::Dotted).apply();
     /// assert_eq!(css, "border-bottom: 2px dotted;");
     ///
     /// let css = style().border_bottom_with_width(Size::Rem(1.5), BorderStyle::Solid).apply();
     /// assert_eq!(css, "border-bottom: 1.5rem solid;");
     /// ```
     pub fn border_bottom_with_width(&mut self, width: Size, style: BorderStyle) -> &mut Self {
-        self.add_property(border_bottom::border_bottom_with_width(width, style))
+        let validation = width.validate();
+        self.add_validated(border_bottom::border_bottom_with_width(width, style), validation)
     }
 
     /// Sets the border-bottom property of an element with style and color.
@@ -562,7 +1380,8 @@ impl Style {
     /// assert_eq!(css, "border-bottom: solid red;");
     /// ```
     pub fn border_bottom_with_color(&mut self, style: BorderStyle, color: Color) -> &mut Self {
-        self.add_property(border_bottom::border_bottom_with_color(style, color))
+        let validation = color.validate();
+        self.add_validated(border_bottom::border_bottom_with_color(style, color), validation)
     }
 
     /// Sets the border-bottom property of an element with width, style, and color.
@@ -586,14 +1405,15 @@ impl Style {
     /// use mew_css::style;
     /// use mew_css::values::{BorderStyle, Color, Size};
     ///
-    /// let css = style().border_bottom_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green).apply();
+    /// let css = style().border_bottom_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green).apply();
     /// assert_eq!(css, "border-bottom: 3px dashed green;");
     ///
-    /// let css = style().border_bottom_with_width_and_color(Size::Px(4), BorderStyle::Double, Color::Rgb(50, 161, 206)).apply();
+    /// let css = style().border_bottom_with_width_and_color(Size::Px(4.0), BorderStyle::Double, Color::Rgb(50, 161, 206)).apply();
     /// assert_eq!(css, "border-bottom: 4px double rgb(50, 161, 206);");
     /// ```
     pub fn border_bottom_with_width_and_color(&mut self, width: Size, style: BorderStyle, color: Color) -> &mut Self {
-        self.add_property(border_bottom::border_bottom_with_width_and_color(width, style, color))
+        let validation = width.validate().and_then(|_| color.validate());
+        self.add_validated(border_bottom::border_bottom_with_width_and_color(width, style, color), validation)
     }
 
     /// Sets the border-top property of an element with only style.
@@ -645,14 +1465,15 @@ impl Style {
     /// use mew_css::style;
     /// use mew_css::values::{BorderStyle, Size};
     ///
-    /// let css = style().border_top_with_width(Size::Px(2), BorderStyle::Dotted).apply();
+    /// let css = style().border_top_with_width(Size::Px(2.0), BorderStyle::Dotted).apply();
     /// assert_eq!(css, "border-top: 2px dotted;");
     ///
     /// let css = style().border_top_with_width(Size::Rem(1.5), BorderStyle::Solid).apply();
     /// assert_eq!(css, "border-top: 1.5rem solid;");
     /// ```
     pub fn border_top_with_width(&mut self, width: Size, style: BorderStyle) -> &mut Self {
-        self.add_property(border_top::border_top_with_width(width, style))
+        let validation = width.validate();
+        self.add_validated(border_top::border_top_with_width(width, style), validation)
     }
 
     /// Sets the border-top property of an element with style and color.
@@ -682,7 +1503,8 @@ impl Style {
     /// assert_eq!(css, "border-top: solid red;");
     /// ```
     pub fn border_top_with_color(&mut self, style: BorderStyle, color: Color) -> &mut Self {
-        self.add_property(border_top::border_top_with_color(style, color))
+        let validation = color.validate();
+        self.add_validated(border_top::border_top_with_color(style, color), validation)
     }
 
     /// Sets the border-top property of an element with width, style, and color.
@@ -706,14 +1528,15 @@ impl Style {
     /// use mew_css::style;
     /// use mew_css::values::{BorderStyle, Color, Size};
     ///
-    /// let css = style().border_top_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green).apply();
+    /// let css = style().border_top_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green).apply();
     /// assert_eq!(css, "border-top: 3px dashed green;");
     ///
-    /// let css = style().border_top_with_width_and_color(Size::Px(4), BorderStyle::Double, Color::Rgb(50, 161, 206)).apply();
+    /// let css = style().border_top_with_width_and_color(Size::Px(4.0), BorderStyle::Double, Color::Rgb(50, 161, 206)).apply();
     /// assert_eq!(css, "border-top: 4px double rgb(50, 161, 206);");
     /// ```
     pub fn border_top_with_width_and_color(&mut self, width: Size, style: BorderStyle, color: Color) -> &mut Self {
-        self.add_property(border_top::border_top_with_width_and_color(width, style, color))
+        let validation = width.validate().and_then(|_| color.validate());
+        self.add_validated(border_top::border_top_with_width_and_color(width, style, color), validation)
     }
 
     /// Sets the border-left property of an element with only style.
@@ -765,14 +1588,15 @@ impl Style {
     /// use mew_css::style;
     /// use mew_css::values::{BorderStyle, Size};
     ///
-    /// let css = style().border_left_with_width(Size::Px(2), BorderStyle::Dotted).apply();
+    /// let css = style().border_left_with_width(Size::Px(2.0), BorderStyle::Dotted).apply();
     /// assert_eq!(css, "border-left: 2px dotted;");
     ///
     /// let css = style().border_left_with_width(Size::Rem(1.5), BorderStyle::Solid).apply();
     /// assert_eq!(css, "border-left: 1.5rem solid;");
     /// ```
     pub fn border_left_with_width(&mut self, width: Size, style: BorderStyle) -> &mut Self {
-        self.add_property(border_left::border_left_with_width(width, style))
+        let validation = width.validate();
+        self.add_validated(border_left::border_left_with_width(width, style), validation)
     }
 
     /// Sets the border-left property of an element with style and color.
@@ -802,7 +1626,8 @@ impl Style {
     /// assert_eq!(css, "border-left: solid red;");
     /// ```
     pub fn border_left_with_color(&mut self, style: BorderStyle, color: Color) -> &mut Self {
-        self.add_property(border_left::border_left_with_color(style, color))
+        let validation = color.validate();
+        self.add_validated(border_left::border_left_with_color(style, color), validation)
     }
 
     /// Sets the border-left property of an element with width, style, and color.
@@ -826,14 +1651,15 @@ impl Style {
     /// use mew_css::style;
     /// use mew_css::values::{BorderStyle, Color, Size};
     ///
-    /// let css = style().border_left_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green).apply();
+    /// let css = style().border_left_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green).apply();
     /// assert_eq!(css, "border-left: 3px dashed green;");
     ///
-    /// let css = style().border_left_with_width_and_color(Size::Px(4), BorderStyle::Double, Color::Rgb(50, 161, 206)).apply();
+    /// let css = style().border_left_with_width_and_color(Size::Px(4.0), BorderStyle::Double, Color::Rgb(50, 161, 206)).apply();
     /// assert_eq!(css, "border-left: 4px double rgb(50, 161, 206);");
     /// ```
     pub fn border_left_with_width_and_color(&mut self, width: Size, style: BorderStyle, color: Color) -> &mut Self {
-        self.add_property(border_left::border_left_with_width_and_color(width, style, color))
+        let validation = width.validate().and_then(|_| color.validate());
+        self.add_validated(border_left::border_left_with_width_and_color(width, style, color), validation)
     }
 
     /// Sets the border-right property of an element with only style.
@@ -885,14 +1711,15 @@ impl Style {
     /// use mew_css::style;
     /// use mew_css::values::{BorderStyle, Size};
     ///
-    /// let css = style().border_right_with_width(Size::Px(2), BorderStyle::Dotted).apply();
+    /// let css = style().border_right_with_width(Size::Px(2.0), BorderStyle::Dotted).apply();
     /// assert_eq!(css, "border-right: 2px dotted;");
     ///
     /// let css = style().border_right_with_width(Size::Rem(1.5), BorderStyle::Solid).apply();
     /// assert_eq!(css, "border-right: 1.5rem solid;");
     /// ```
     pub fn border_right_with_width(&mut self, width: Size, style: BorderStyle) -> &mut Self {
-        self.add_property(border_right::border_right_with_width(width, style))
+        let validation = width.validate();
+        self.add_validated(border_right::border_right_with_width(width, style), validation)
     }
 
     /// Sets the border-right property of an element with style and color.
@@ -922,7 +1749,8 @@ impl Style {
     /// assert_eq!(css, "border-right: solid red;");
     /// ```
     pub fn border_right_with_color(&mut self, style: BorderStyle, color: Color) -> &mut Self {
-        self.add_property(border_right::border_right_with_color(style, color))
+        let validation = color.validate();
+        self.add_validated(border_right::border_right_with_color(style, color), validation)
     }
 
     /// Sets the border-right property of an element with width, style, and color.
@@ -946,14 +1774,183 @@ impl Style {
     /// use mew_css::style;
     /// use mew_css::values::{BorderStyle, Color, Size};
     ///
-    /// let css = style().border_right_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green).apply();
+    /// let css = style().border_right_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green).apply();
     /// assert_eq!(css, "border-right: 3px dashed green;");
     ///
-    /// let css = style().border_right_with_width_and_color(Size::Px(4), BorderStyle::Double, Color::Rgb(50, 161, 206)).apply();
+    /// let css = style().border_right_with_width_and_color(Size::Px(4.0), BorderStyle::Double, Color::Rgb(50, 161, 206)).apply();
     /// assert_eq!(css, "border-right: 4px double rgb(50, 161, 206);");
     /// ```
     pub fn border_right_with_width_and_color(&mut self, width: Size, style: BorderStyle, color: Color) -> &mut Self {
-        self.add_property(border_right::border_right_with_width_and_color(width, style, color))
+        let validation = width.validate().and_then(|_| color.validate());
+        self.add_validated(border_right::border_right_with_width_and_color(width, style, color), validation)
+    }
+
+    /// Sets the color property of an element.
+    ///
+    /// The `color` property sets the foreground color of the text of an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The color value to set
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::values::Color;
+    ///
+    /// let css = style().color(Color::Navy).background_color(Color::White).apply();
+    /// assert_eq!(css, "color: navy; background-color: white;");
+    /// ```
+    pub fn color(&mut self, value: Color) -> &mut Self {
+        let validation = value.validate();
+        self.add_validated(color::color(value), validation)
+    }
+
+    /// Sets the display property of an element.
+    ///
+    /// The `display` property sets whether an element is treated as a block or inline box
+    /// and the layout used for its children, such as flex or grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The display value to set
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::values::Display;
+    ///
+    /// let css = style().display(Display::Flex).apply();
+    /// assert_eq!(css, "display: flex;");
+    ///
+    /// let css = style().display(Display::None).apply();
+    /// assert_eq!(css, "display: none;");
+    /// ```
+    pub fn display(&mut self, value: Display) -> &mut Self {
+        self.add_property(display::display(value))
+    }
+
+    /// Sets the font-weight property of an element.
+    ///
+    /// The `font-weight` property sets the weight (or boldness) of the font. Invalid
+    /// numeric weights are reported by `validate()` (see also `strict()`).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The font weight value to set
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::values::FontWeight;
+    ///
+    /// let css = style().font_weight(FontWeight::Bold).apply();
+    /// assert_eq!(css, "font-weight: bold;");
+    ///
+    /// let css = style().font_weight(FontWeight::Weight(300)).apply();
+    /// assert_eq!(css, "font-weight: 300;");
+    /// ```
+    pub fn font_weight(&mut self, value: FontWeight) -> &mut Self {
+        let validation = value.validate();
+        // Record the weight as given, not the `400` it is written as
+        let input = match &value {
+            FontWeight::Weight(weight) => weight.to_string(),
+            value => value.to_string(),
+        };
+        self.add_validated_input(font_weight::font_weight(value), &input, validation)
+    }
+
+    /// Sets the container-type property of an element.
+    ///
+    /// The `container-type` property establishes the element as a query container, so that
+    /// its descendants can use `Style::container` to adapt to its size.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The container type to set
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::values::ContainerType;
+    ///
+    /// let css = style().container_type(ContainerType::InlineSize).apply();
+    /// assert_eq!(css, "container-type: inline-size;");
+    /// ```
+    pub fn container_type(&mut self, value: ContainerType) -> &mut Self {
+        self.add_property(container_type::container_type(value))
+    }
+
+    /// Sets the container-name property of an element.
+    ///
+    /// The `container-name` property gives a query container one or more names, which
+    /// container queries can target with `ContainerQuery::named`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The container name, or several names separated by spaces
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    ///
+    /// let css = style().container_name("sidebar").apply();
+    /// assert_eq!(css, "container-name: sidebar;");
+    /// ```
+    pub fn container_name(&mut self, name: &str) -> &mut Self {
+        self.add_property(container_name::container_name(name))
+    }
+
+    /// Sets the container property of an element with both a name and a type.
+    ///
+    /// The `container` property is a shorthand property that sets the container-name
+    /// and container-type properties.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The container name, or several names separated by spaces
+    /// * `value` - The container type to set
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::style;
+    /// use mew_css::values::ContainerType;
+    ///
+    /// let css = style().container_name_and_type("card", ContainerType::InlineSize).apply();
+    /// assert_eq!(css, "container: card / inline-size;");
+    /// ```
+    pub fn container_name_and_type(&mut self, name: &str, value: ContainerType) -> &mut Self {
+        self.add_property(container::container(name, value))
     }
 }
 
@@ -969,6 +1966,14 @@ impl fmt::Display for Style {
     }
 }
 
+impl FromStr for Style {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Style::parse(s)
+    }
+}
+
 /// Creates a new style builder for constructing CSS styles.
 ///
 /// This function is the main entry point for the Mew CSS library. It returns a new
@@ -997,6 +2002,7 @@ pub fn style() -> Style {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::container::ContainerQuery;
 
     #[test]
     fn test_style_builder() {
@@ -1059,6 +2065,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_animation() {
+        let fade = crate::keyframes::Keyframes::new("fade-in");
+
+        let css = style()
+            .animation(Animation::new(&fade, Time::S(0.3)))
+            .apply();
+
+        assert_eq!(
+            css,
+            "animation: fade-in 0.3s;"
+        );
+
+        let css = style()
+            .animation(Animation::new(&fade, Time::S(1.0)).delay(Time::S(0.5)).fill_mode(AnimationFillMode::Both))
+            .apply();
+
+        assert_eq!(
+            css,
+            "animation: fade-in 1s 0.5s both;"
+        );
+    }
+
     #[test]
     fn test_background_color() {
         // Named color
@@ -1116,7 +2145,7 @@ mod tests {
 
         // Width and style
         let css = style()
-            .border_with_width(Size::Px(2), BorderStyle::Dotted)
+            .border_with_width(Size::Px(2.0), BorderStyle::Dotted)
             .apply();
 
         assert_eq!(
@@ -1136,7 +2165,7 @@ mod tests {
 
         // Width, style, and color
         let css = style()
-            .border_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green)
+            .border_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green)
             .apply();
 
         assert_eq!(
@@ -1159,7 +2188,7 @@ mod tests {
 
         // Width and style
         let css = style()
-            .border_bottom_with_width(Size::Px(2), BorderStyle::Dotted)
+            .border_bottom_with_width(Size::Px(2.0), BorderStyle::Dotted)
             .apply();
 
         assert_eq!(
@@ -1179,7 +2208,7 @@ mod tests {
 
         // Width, style, and color
         let css = style()
-            .border_bottom_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green)
+            .border_bottom_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green)
             .apply();
 
         assert_eq!(
@@ -1202,7 +2231,7 @@ mod tests {
 
         // Width and style
         let css = style()
-            .border_top_with_width(Size::Px(2), BorderStyle::Dotted)
+            .border_top_with_width(Size::Px(2.0), BorderStyle::Dotted)
             .apply();
 
         assert_eq!(
@@ -1222,7 +2251,7 @@ mod tests {
 
         // Width, style, and color
         let css = style()
-            .border_top_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green)
+            .border_top_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green)
             .apply();
 
         assert_eq!(
@@ -1245,7 +2274,7 @@ mod tests {
 
         // Width and style
         let css = style()
-            .border_left_with_width(Size::Px(2), BorderStyle::Dotted)
+            .border_left_with_width(Size::Px(2.0), BorderStyle::Dotted)
             .apply();
 
         assert_eq!(
@@ -1265,7 +2294,7 @@ mod tests {
 
         // Width, style, and color
         let css = style()
-            .border_left_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green)
+            .border_left_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green)
             .apply();
 
         assert_eq!(
@@ -1288,7 +2317,7 @@ mod tests {
 
         // Width and style
         let css = style()
-            .border_right_with_width(Size::Px(2), BorderStyle::Dotted)
+            .border_right_with_width(Size::Px(2.0), BorderStyle::Dotted)
             .apply();
 
         assert_eq!(
@@ -1308,7 +2337,7 @@ mod tests {
 
         // Width, style, and color
         let css = style()
-            .border_right_with_width_and_color(Size::Px(3), BorderStyle::Dashed, Color::Green)
+            .border_right_with_width_and_color(Size::Px(3.0), BorderStyle::Dashed, Color::Green)
             .apply();
 
         assert_eq!(
@@ -1316,4 +2345,139 @@ mod tests {
             "border-right: 3px dashed green;"
         );
     }
+
+    #[test]
+    fn test_nested_states() {
+        let mut style = style();
+        style
+            .background_color(Color::Blue)
+            .hover(|s| s.background_color(Color::Navy))
+            .pseudo(":nth-child(2n)", |s| s.background_color(Color::Gray));
+
+        // Nested styles are not part of the declaration list
+        assert_eq!(style.apply(), "background-color: blue;");
+
+        assert_eq!(style.nested().len(), 2);
+        assert_eq!(style.nested()[0].nesting(), &Nesting::Selector(":hover".to_string()));
+        assert_eq!(style.nested()[0].style().apply(), "background-color: navy;");
+        assert_eq!(style.nested()[1].nesting(), &Nesting::Selector(":nth-child(2n)".to_string()));
+    }
+
+    #[test]
+    fn test_validate_and_strict() {
+        let mut lenient = style();
+        lenient
+            .font_weight(FontWeight::Weight(1200))
+            .background_color(Color::Hex("#zz".to_string()))
+            .border_top_with_color(BorderStyle::Solid, Color::Rgb(0, 0, 0));
+
+        assert_eq!(
+            lenient.apply(),
+            "font-weight: 400; background-color: #zz; border-top: solid rgb(0, 0, 0);"
+        );
+        let issues = lenient.validate();
+        assert_eq!(issues.len(), 2);
+        assert_eq!((issues[0].property(), issues[0].value()), ("font-weight", "1200"));
+        assert_eq!(issues[1].error(), &crate::error::MewError::InvalidHex("#zz".to_string()));
+
+        let mut strict = style();
+        strict
+            .strict(true)
+            .background_color(Color::Rgba(0, 0, 0, -1.0))
+            .hover(|s| s.font_weight(FontWeight::Weight(0)).font_weight(FontWeight::Bold));
+
+        assert!(strict.is_strict());
+        assert!(strict.properties().is_empty());
+        assert!(strict.nested()[0].style().is_strict());
+        assert_eq!(strict.nested()[0].style().apply(), "font-weight: bold;");
+        assert_eq!(strict.validate().len(), 2);
+    }
+
+    #[test]
+    fn test_validate_border_width() {
+        let mut lenient = style();
+        lenient.border_top_with_width(Size::Auto + Size::Px(1.0), BorderStyle::Solid);
+        assert_eq!(lenient.apply(), "border-top: calc(auto + 1px) solid;");
+        assert_eq!(lenient.validate()[0].value(), "calc(auto + 1px) solid");
+
+        let mut strict = style();
+        strict
+            .strict(true)
+            .border_with_width_and_color(Size::Auto * 2.0, BorderStyle::Solid, Color::Red)
+            .border_with_width(Size::Px(1.0) * 2.0, BorderStyle::Solid);
+        assert_eq!(strict.apply(), "border: 2px solid;");
+        assert_eq!(strict.validate().len(), 1);
+    }
+
+    #[test]
+    fn test_validate_media_query() {
+        let query = || MediaQuery::min_width(Size::Px(600.0)).and(!MediaQuery::print());
+
+        let mut lenient = style();
+        lenient.media(query(), |s| s.display(Display::Flex));
+        assert_eq!(lenient.nested().len(), 1);
+        let issues = lenient.validate();
+        assert_eq!((issues[0].property(), issues[0].value()), ("@media", "(min-width: 600px) and (not print)"));
+
+        let mut strict = style();
+        strict.strict(true).media(query(), |s| s.display(Display::Flex));
+        assert!(strict.nested().is_empty());
+        assert_eq!(strict.validate().len(), 1);
+    }
+
+    #[test]
+    fn test_validate_supports() {
+        let mut lenient = style();
+        lenient
+            .supports(SupportsCondition::And(vec![]), |s| s.display(Display::Grid))
+            .supports(SupportsCondition::selector(":has(a)"), |s| s.display(Display::Flex));
+        assert_eq!(lenient.nested().len(), 2);
+        let issues = lenient.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].property(), issues[0].value()), ("@supports", ""));
+
+        let mut strict = style();
+        strict.strict(true).supports(SupportsCondition::selector(""), |s| s.display(Display::Grid));
+        assert!(strict.nested().is_empty());
+        assert_eq!(strict.validate().len(), 1);
+    }
+
+    #[test]
+    fn test_validate_container() {
+        let mut lenient = style();
+        lenient
+            .container(ContainerQuery::feature(""), |s| s.display(Display::Flex))
+            .container(ContainerQuery::min_width(Size::Px(400.0)).named("card"), |s| s.display(Display::Block));
+        assert_eq!(lenient.nested().len(), 2);
+        let issues = lenient.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].property(), issues[0].value()), ("@container", "()"));
+
+        let mut strict = style();
+        strict.strict(true).container(ContainerQuery::min_width(Size::Auto), |s| s.display(Display::Flex));
+        assert!(strict.nested().is_empty());
+        assert_eq!(strict.validate().len(), 1);
+    }
+
+
+    #[test]
+    fn test_audit_contrast() {
+        let mut card = style();
+        assert_eq!(card.audit_contrast(WcagLevel::AA), None);
+
+        card.color(Color::Hex("#777".to_string()));
+        assert_eq!(card.audit_contrast(WcagLevel::AA), None);
+
+        card.custom_property("background-color", "#fff");
+        let audit = card.audit_contrast(WcagLevel::AA).unwrap();
+        assert!(!audit.passes_normal_text());
+        assert!(audit.passes_large_text());
+
+        card.color(Color::Var(crate::variable::var("text")));
+        assert_eq!(card.audit_contrast(WcagLevel::AA), None);
+
+        let parsed = Style::parse("color: #222; background-color: hsl(0 0% 95%)").unwrap();
+        assert!(parsed.audit_contrast(WcagLevel::AAA).unwrap().passes_normal_text());
+    }
+
 }
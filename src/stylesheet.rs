@@ -0,0 +1,782 @@
+//! # Stylesheet Module
+//!
+//! This module provides the `Stylesheet` type, an ordered collection of rules and
+//! at-rules that renders to a complete CSS file.
+//!
+//! A stylesheet sits above `Rule` and `AtRule`: rules pair selectors with styles,
+//! at-rules cover constructs like `@import` or `@media`, and the stylesheet keeps
+//! them in the order they were added so the cascade behaves as written.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::{rule, style, stylesheet};
+//! use mew_css::at_rule::AtRule;
+//! use mew_css::values::{AlignItems, Color};
+//!
+//! let css = stylesheet()
+//!     .add_at_rule(AtRule::statement("import", "url(\"reset.css\")"))
+//!     .add_rule(rule("body", style().background_color(Color::White)))
+//!     .add_rule(rule(".row", style().align_items(AlignItems::Center)))
+//!     .apply();
+//!
+//! assert_eq!(css, "@import url(\"reset.css\");\n\
+//!                  body { background-color: white; }\n\
+//!                  .row { align-items: center; }");
+//! ```
+//!
+//! ## Cascade Layers
+//!
+//! Rules can be grouped into cascade layers with `layer`, and the priority of the
+//! layers declared up front with `layer_order`. Blocks targeting the same layer are
+//! merged into a single `@layer` block when the stylesheet is rendered.
+//!
+//! ```rust
+//! use mew_css::{rule, style, stylesheet};
+//! use mew_css::values::Color;
+//!
+//! let css = stylesheet()
+//!     .layer_order(&["base", "components"])
+//!     .layer("components", |l| l.add_rule(rule(".btn", style().background_color(Color::Blue))))
+//!     .layer("base", |l| l.add_rule(rule("body", style().background_color(Color::White))))
+//!     .layer("components", |l| l.add_rule(rule(".card", style().background_color(Color::White))))
+//!     .apply();
+//!
+//! assert_eq!(css, "@layer base, components;\n\
+//!                  @layer components { .btn { background-color: blue; } .card { background-color: white; } }\n\
+//!                  @layer base { body { background-color: white; } }");
+//! ```
+
+use crate::at_rule::{AtRule, AtRuleBody};
+use crate::format::OutputOptions;
+use crate::rule::Rule;
+use std::fmt;
+
+/// A single entry of a stylesheet: either a style rule or an at-rule.
+#[derive(Debug, Clone)]
+pub enum StylesheetItem {
+    /// A selector-scoped style rule
+    Rule(Rule),
+    /// An at-rule such as `@import` or `@media`
+    AtRule(AtRule),
+}
+
+impl fmt::Display for StylesheetItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StylesheetItem::Rule(rule) => write!(f, "{}", rule),
+            StylesheetItem::AtRule(at_rule) => write!(f, "{}", at_rule),
+        }
+    }
+}
+
+// Implement From<Rule> for StylesheetItem to allow automatic conversion
+impl From<Rule> for StylesheetItem {
+    fn from(rule: Rule) -> Self {
+        StylesheetItem::Rule(rule)
+    }
+}
+
+// Implement From<AtRule> for StylesheetItem to allow automatic conversion
+impl From<AtRule> for StylesheetItem {
+    fn from(at_rule: AtRule) -> Self {
+        StylesheetItem::AtRule(at_rule)
+    }
+}
+
+/// An ordered list of rules and at-rules that renders to a complete CSS file.
+///
+/// Like `Style`, the `Stylesheet` struct uses a fluent API where each method
+/// returns a mutable reference to self. Items are rendered in insertion order,
+/// one per line, and nested styles of rules are rendered as separate lines
+/// following their rule.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::{rule, style};
+/// use mew_css::stylesheet::Stylesheet;
+/// use mew_css::values::Color;
+///
+/// let mut sheet = Stylesheet::new();
+/// sheet.add_rule(rule("a", style().background_color(Color::Yellow)));
+///
+/// assert_eq!(sheet.to_string(), "a { background-color: yellow; }");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Stylesheet {
+    /// Rules and at-rules in the order they were added
+    items: Vec<StylesheetItem>,
+}
+
+impl Stylesheet {
+    /// Creates a new empty stylesheet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::stylesheet::Stylesheet;
+    ///
+    /// let sheet = Stylesheet::new();
+    /// assert!(sheet.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Adds a rule or an at-rule to the end of the stylesheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The item to add, which can be anything convertible into a `StylesheetItem`
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style, stylesheet};
+    /// use mew_css::at_rule::AtRule;
+    ///
+    /// let css = stylesheet()
+    ///     .add(AtRule::statement("charset", "\"utf-8\""))
+    ///     .add(rule("p", &style()))
+    ///     .apply();
+    ///
+    /// assert_eq!(css, "@charset \"utf-8\";\np {}");
+    /// ```
+    pub fn add<T: Into<StylesheetItem>>(&mut self, item: T) -> &mut Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Adds a style rule to the end of the stylesheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - The rule to add
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style, stylesheet};
+    /// use mew_css::values::Color;
+    ///
+    /// let css = stylesheet()
+    ///     .add_rule(rule("h1", style().background_color(Color::Black)))
+    ///     .apply();
+    ///
+    /// assert_eq!(css, "h1 { background-color: black; }");
+    /// ```
+    pub fn add_rule(&mut self, rule: Rule) -> &mut Self {
+        self.add(rule)
+    }
+
+    /// Adds an at-rule to the end of the stylesheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `at_rule` - The at-rule to add
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::stylesheet;
+    /// use mew_css::at_rule::AtRule;
+    ///
+    /// let css = stylesheet()
+    ///     .add_at_rule(AtRule::statement("import", "url(\"fonts.css\")"))
+    ///     .apply();
+    ///
+    /// assert_eq!(css, "@import url(\"fonts.css\");");
+    /// ```
+    pub fn add_at_rule(&mut self, at_rule: AtRule) -> &mut Self {
+        self.add(at_rule)
+    }
+
+    /// Appends every item of an iterator to the end of the stylesheet.
+    ///
+    /// Since `Stylesheet` implements `IntoIterator`, this can also be used to merge
+    /// another stylesheet into this one.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The items to append, in order
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style, stylesheet};
+    /// use mew_css::values::Color;
+    ///
+    /// let mut base = stylesheet();
+    /// base.add_rule(rule("body", style().background_color(Color::White)));
+    ///
+    /// let mut theme = stylesheet();
+    /// theme.add_rule(rule(".btn", style().background_color(Color::Blue)));
+    ///
+    /// base.extend(theme).extend(vec![rule(".link", &style())]);
+    ///
+    /// assert_eq!(
+    ///     base.apply(),
+    ///     "body { background-color: white; }\n.btn { background-color: blue; }\n.link {}"
+    /// );
+    /// ```
+    pub fn extend<I>(&mut self, items: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<StylesheetItem>,
+    {
+        self.items.extend(items.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds a `@layer` statement declaring the order of cascade layers.
+    ///
+    /// Layers declared first have the lowest priority, and unlayered rules always take
+    /// precedence over layered ones. The statement should be added before any layer block.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The layer names, from lowest to highest priority
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::stylesheet;
+    ///
+    /// let css = stylesheet()
+    ///     .layer_order(&["reset", "base", "components", "utilities"])
+    ///     .apply();
+    ///
+    /// assert_eq!(css, "@layer reset, base, components, utilities;");
+    /// ```
+    pub fn layer_order(&mut self, names: &[&str]) -> &mut Self {
+        self.add(AtRule::layer_order(names))
+    }
+
+    /// Adds rules to a named cascade layer, built by the given closure.
+    ///
+    /// The closure receives an empty stylesheet whose items are placed in the layer.
+    /// Calling `layer` inside the closure creates a nested layer. Several blocks for
+    /// the same layer are merged into the first one when the stylesheet is rendered, whether
+    /// the layer is named with a dotted path or nested.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The layer name, which can be a dotted path to a nested layer (e.g., `"components.buttons"`)
+    /// * `build` - A closure that adds rules and at-rules to the layer
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style, stylesheet};
+    /// use mew_css::values::Color;
+    ///
+    /// let css = stylesheet()
+    ///     .layer("components", |l| l
+    ///         .layer("buttons", |l| l.add_rule(rule(".btn", style().background_color(Color::Blue)))))
+    ///     .apply();
+    ///
+    /// assert_eq!(css, "@layer components { @layer buttons { .btn { background-color: blue; } } }");
+    /// ```
+    pub fn layer<F>(&mut self, name: &str, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Stylesheet) -> &mut Stylesheet,
+    {
+        let mut layer = Stylesheet::new();
+        build(&mut layer);
+        self.add(AtRule::layer(name, layer.items))
+    }
+
+    /// Adds rules to a new anonymous cascade layer, built by the given closure.
+    ///
+    /// Anonymous layers cannot be referenced from elsewhere, so they are never merged
+    /// with other layers.
+    ///
+    /// # Arguments
+    ///
+    /// * `build` - A closure that adds rules and at-rules to the layer
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style, stylesheet};
+    /// use mew_css::values::Color;
+    ///
+    /// let css = stylesheet()
+    ///     .anonymous_layer(|l| l.add_rule(rule("a", style().background_color(Color::Yellow))))
+    ///     .apply();
+    ///
+    /// assert_eq!(css, "@layer { a { background-color: yellow; } }");
+    /// ```
+    pub fn anonymous_layer<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Stylesheet) -> &mut Stylesheet,
+    {
+        self.layer("", build)
+    }
+
+    /// Returns the items of the stylesheet, in insertion order.
+    pub fn items(&self) -> &[StylesheetItem] {
+        &self.items
+    }
+
+    /// Returns the number of items in the stylesheet.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the stylesheet contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Generates the CSS text of the whole stylesheet.
+    ///
+    /// Each item is rendered on its own line, in insertion order.
+    ///
+    /// # Returns
+    ///
+    /// A string containing the CSS representation of all items
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style, stylesheet};
+    /// use mew_css::values::Color;
+    ///
+    /// let css = stylesheet()
+    ///     .add_rule(rule("a", style().background_color(Color::Red)))
+    ///     .add_rule(rule("b", style().background_color(Color::Blue)))
+    ///     .apply();
+    ///
+    /// assert_eq!(css, "a { background-color: red; }\nb { background-color: blue; }");
+    /// ```
+    pub fn apply(&self) -> String {
+        self.to_string()
+    }
+
+    /// Alias for `apply()` that generates the CSS text of the whole stylesheet.
+    pub fn build(&self) -> String {
+        self.apply()
+    }
+
+    /// Generates the CSS text of the whole stylesheet with the given output options.
+    ///
+    /// `apply()` is equivalent to using `OutputOptions::compact()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The output options to use
+    ///
+    /// # Returns
+    ///
+    /// A string containing the CSS representation of all items
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::{rule, style, stylesheet};
+    /// use mew_css::format::OutputOptions;
+    /// use mew_css::values::Color;
+    ///
+    /// let mut sheet = stylesheet();
+    /// sheet
+    ///     .add_rule(rule("a", style().background_color(Color::Red)))
+    ///     .add_rule(rule("b", style().background_color(Color::Blue)));
+    ///
+    /// assert_eq!(
+    ///     sheet.to_css_with(&OutputOptions::pretty()),
+    ///     "a {\n  background-color: red;\n}\n\nb {\n  background-color: blue;\n}"
+    /// );
+    /// assert_eq!(
+    ///     sheet.to_css_with(&OutputOptions::minified()),
+    ///     "a{background-color:red}b{background-color:blue}"
+    /// );
+    /// ```
+    pub fn to_css_with(&self, options: &OutputOptions) -> String {
+        options.join_blocks(options.blocks(&merge_layers(&self.items), 0), "\n")
+    }
+}
+
+impl fmt::Display for Stylesheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_css_with(&OutputOptions::compact()))
+    }
+}
+
+/// Returns the items of a named `@layer` block, or `None` for any other item
+/// (including anonymous layers and `@layer` statements).
+fn named_layer(item: &StylesheetItem) -> Option<(&str, &[StylesheetItem])> {
+    match item {
+        StylesheetItem::AtRule(at_rule) if at_rule.name() == "layer" && !at_rule.prelude().is_empty() => {
+            match at_rule.body() {
+                AtRuleBody::Items(items) => Some((at_rule.prelude(), items)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// A group of `@layer` blocks whose names start with the same layer, being merged.
+struct LayerGroup {
+    /// The first segment of the layer names (e.g., `a` for `a.b`)
+    name: String,
+    /// The position of the first block of the group in the merged items
+    index: usize,
+    /// The full name of the first block, kept when no other block joins the group
+    full_name: String,
+    /// The items of the blocks, with the rest of dotted names written as nested layers
+    items: Vec<StylesheetItem>,
+    /// The number of blocks in the group
+    blocks: usize,
+}
+
+/// Merges the `@layer` blocks targeting the same layer into the first block for that layer.
+///
+/// Names are compared as dotted paths, so `@layer a.b { ... }` is merged with
+/// `@layer a { @layer b { ... } }` (the merged block is written in the nested form). The
+/// items of later blocks are appended to the first block, keeping their relative order,
+/// and nested layers are merged recursively, including the layers inside the bodies of
+/// other at-rules such as `@media`. Layers are only merged with the layers of the same
+/// body, as the blocks inside a condition do not always apply.
+fn merge_layers(items: &[StylesheetItem]) -> Vec<StylesheetItem> {
+    let mut merged: Vec<StylesheetItem> = Vec::new();
+    let mut groups: Vec<LayerGroup> = Vec::new();
+
+    for item in items {
+        match named_layer(item) {
+            Some((name, content)) => {
+                let segments: Vec<&str> = name.split('.').map(str::trim).collect();
+                let (first, rest) = (segments[0], &segments[1..]);
+                let content = if rest.is_empty() {
+                    content.to_vec()
+                } else {
+                    vec![AtRule::layer(&rest.join("."), content.to_vec()).into()]
+                };
+
+                match groups.iter_mut().find(|group| group.name == first) {
+                    Some(group) => {
+                        group.items.extend(content);
+                        group.blocks += 1;
+                    }
+                    None => {
+                        groups.push(LayerGroup {
+                            name: first.to_string(),
+                            index: merged.len(),
+                            full_name: segments.join("."),
+                            items: content,
+                            blocks: 1,
+                        });
+                        merged.push(item.clone());
+                    }
+                }
+            }
+            None => merged.push(match item {
+                StylesheetItem::AtRule(at_rule) => match at_rule.body() {
+                    AtRuleBody::Items(body) => {
+                        AtRule::with_items(at_rule.name(), at_rule.prelude(), merge_layers(body)).into()
+                    }
+                    _ => item.clone(),
+                },
+                item => item.clone(),
+            }),
+        }
+    }
+
+    for group in groups {
+        merged[group.index] = match (group.blocks, named_layer(&merged[group.index])) {
+            // A single block keeps its name as written, dotted or not
+            (1, Some((_, content))) => AtRule::layer(&group.full_name, merge_layers(content)).into(),
+            _ => AtRule::layer(&group.name, merge_layers(&group.items)).into(),
+        };
+    }
+
+    merged
+}
+
+impl IntoIterator for Stylesheet {
+    type Item = StylesheetItem;
+    type IntoIter = std::vec::IntoIter<StylesheetItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<T: Into<StylesheetItem>> Extend<T> for Stylesheet {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.items.extend(iter.into_iter().map(Into::into));
+    }
+}
+
+impl<T: Into<StylesheetItem>> FromIterator<T> for Stylesheet {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            items: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Creates a new, empty stylesheet.
+///
+/// This is a convenience function that creates a new `Stylesheet` instance.
+/// It's a shorter alternative to calling `Stylesheet::new()`.
+///
+/// # Returns
+///
+/// A new, empty `Stylesheet` instance
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::{rule, style, stylesheet};
+/// use mew_css::values::Color;
+///
+/// let css = stylesheet()
+///     .add_rule(rule(".btn", style().background_color(Color::Red)))
+///     .apply();
+///
+/// assert_eq!(css, ".btn { background-color: red; }");
+/// ```
+pub fn stylesheet() -> Stylesheet {
+    Stylesheet::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::MediaQuery;
+    use crate::rule::rule;
+    use crate::style::style;
+    use crate::values::{AlignItems, Color};
+
+    #[test]
+    fn test_empty_stylesheet() {
+        let sheet = stylesheet();
+        assert!(sheet.is_empty());
+        assert_eq!(sheet.apply(), "");
+    }
+
+    #[test]
+    fn test_rules_in_order() {
+        let css = stylesheet()
+            .add_rule(rule("body", style().background_color(Color::White)))
+            .add_rule(rule(".row", style().align_items(AlignItems::Center)))
+            .apply();
+
+        assert_eq!(
+            css,
+            "body { background-color: white; }\n.row { align-items: center; }"
+        );
+    }
+
+    #[test]
+    fn test_rules_and_at_rules() {
+        let css = stylesheet()
+            .add_at_rule(AtRule::statement("import", "url(\"reset.css\")"))
+            .add_rule(rule("body", style().background_color(Color::White)))
+            .add_at_rule(AtRule::with_items(
+                "media",
+                "print",
+                vec![rule("body", style().background_color(Color::Transparent)).into()],
+            ))
+            .apply();
+
+        assert_eq!(
+            css,
+            "@import url(\"reset.css\");\n\
+             body { background-color: white; }\n\
+             @media print { body { background-color: transparent; } }"
+        );
+    }
+
+    #[test]
+    fn test_nested_states_on_separate_lines() {
+        let css = stylesheet()
+            .add_rule(rule(
+                ".btn",
+                style()
+                    .background_color(Color::Blue)
+                    .hover(|s| s.background_color(Color::Navy)),
+            ))
+            .apply();
+
+        assert_eq!(
+            css,
+            ".btn { background-color: blue; }\n.btn:hover { background-color: navy; }"
+        );
+    }
+
+    #[test]
+    fn test_extend_with_stylesheet() {
+        let mut theme = stylesheet();
+        theme.add_rule(rule(".btn", style().background_color(Color::Blue)));
+
+        let mut sheet = stylesheet();
+        sheet
+            .add_rule(rule("body", style().background_color(Color::White)))
+            .extend(theme);
+
+        assert_eq!(sheet.len(), 2);
+        assert_eq!(
+            sheet.apply(),
+            "body { background-color: white; }\n.btn { background-color: blue; }"
+        );
+    }
+
+    #[test]
+    fn test_extend_with_rules() {
+        let rules = vec![
+            rule("h1", style().background_color(Color::Red)),
+            rule("h2", style().background_color(Color::Green)),
+        ];
+
+        let mut sheet = stylesheet();
+        sheet.extend(rules);
+
+        assert_eq!(
+            sheet.apply(),
+            "h1 { background-color: red; }\nh2 { background-color: green; }"
+        );
+    }
+
+    #[test]
+    fn test_layer_order() {
+        let css = stylesheet().layer_order(&[" reset ", "base"]).apply();
+        assert_eq!(css, "@layer reset, base;");
+    }
+
+    #[test]
+    fn test_layers_merged_by_name() {
+        let css = stylesheet()
+            .layer_order(&["base", "components"])
+            .layer("base", |l| l.add_rule(rule("body", style().background_color(Color::White))))
+            .add_rule(rule(".unlayered", style().background_color(Color::Red)))
+            .layer("components", |l| l.add_rule(rule(".btn", style().background_color(Color::Blue))))
+            .layer("base", |l| l.add_rule(rule("a", style().background_color(Color::Black))))
+            .apply();
+
+        assert_eq!(
+            css,
+            "@layer base, components;\n\
+             @layer base { body { background-color: white; } a { background-color: black; } }\n\
+             .unlayered { background-color: red; }\n\
+             @layer components { .btn { background-color: blue; } }"
+        );
+    }
+
+    #[test]
+    fn test_nested_layers_merged() {
+        let css = stylesheet()
+            .layer("components", |l| {
+                l.layer("buttons", |l| l.add_rule(rule(".btn", style().background_color(Color::Blue))))
+            })
+            .layer("components", |l| {
+                l.layer("cards", |l| l.add_rule(rule(".card", style().background_color(Color::White))))
+                    .layer("buttons", |l| {
+                        l.add_rule(rule(".btn", style().hover(|s| s.background_color(Color::Navy))))
+                    })
+            })
+            .apply();
+
+        assert_eq!(
+            css,
+            "@layer components { \
+             @layer buttons { .btn { background-color: blue; } .btn:hover { background-color: navy; } } \
+             @layer cards { .card { background-color: white; } } }"
+        );
+    }
+
+    #[test]
+    fn test_dotted_layers_merged() {
+        let css = stylesheet()
+            .layer("components.buttons", |l| l.add_rule(rule(".btn", style().background_color(Color::Blue))))
+            .layer("components", |l| {
+                l.layer("buttons", |l| l.add_rule(rule(".link", style().background_color(Color::Navy))))
+            })
+            .layer("utilities . spacing", |l| l.add_rule(rule(".m-0", style().background_color(Color::White))))
+            .apply();
+
+        assert_eq!(
+            css,
+            "@layer components { @layer buttons { .btn { background-color: blue; } \
+             .link { background-color: navy; } } }\n\
+             @layer utilities.spacing { .m-0 { background-color: white; } }"
+        );
+    }
+
+    #[test]
+    fn test_layers_merged_inside_at_rules() {
+        let mut print = stylesheet();
+        print
+            .layer("base", |l| l.add_rule(rule("body", style().background_color(Color::White))))
+            .layer("base", |l| l.add_rule(rule("a", style().background_color(Color::Black))));
+
+        let css = stylesheet()
+            .layer("base", |l| l.add_rule(rule("p", style().background_color(Color::Gray))))
+            .add_at_rule(AtRule::media(&MediaQuery::print(), print.into_iter().collect()))
+            .apply();
+
+        // The layer inside `@media` is not merged with the top-level one
+        assert_eq!(
+            css,
+            "@layer base { p { background-color: gray; } }\n\
+             @media print { @layer base { body { background-color: white; } a { background-color: black; } } }"
+        );
+    }
+
+    #[test]
+    fn test_anonymous_layers_not_merged() {
+        let css = stylesheet()
+            .anonymous_layer(|l| l.add_rule(rule("a", style().background_color(Color::Red))))
+            .anonymous_layer(|l| l.add_rule(rule("b", style().background_color(Color::Blue))))
+            .apply();
+
+        assert_eq!(
+            css,
+            "@layer { a { background-color: red; } }\n@layer { b { background-color: blue; } }"
+        );
+    }
+
+    #[test]
+    fn test_collect_into_stylesheet() {
+        let sheet: Stylesheet = ["a", "b"]
+            .iter()
+            .map(|selector| rule(selector, style().background_color(Color::Black)))
+            .collect();
+
+        assert_eq!(
+            sheet.apply(),
+            "a { background-color: black; }\nb { background-color: black; }"
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! # Feature Query Module
+//!
+//! This module provides the `SupportsCondition` type, a typed builder for the conditions
+//! used by `@supports` rules. Conditions test whether the browser supports a declaration
+//! (built from any `Property`) or a selector, and can be combined with `and`, `or` and `not`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use mew_css::properties::display::display;
+//! use mew_css::supports::{SupportsCondition, supports};
+//! use mew_css::values::Display;
+//!
+//! let condition = supports(display(Display::Grid));
+//! assert_eq!(condition.to_string(), "(display: grid)");
+//!
+//! let condition = SupportsCondition::selector(":has(> img)")
+//!     .and(!supports(display(Display::Flex)));
+//! assert_eq!(condition.to_string(), "selector(:has(> img)) and (not (display: flex))");
+//! ```
+//!
+//! Conditions are usually attached to a style with `Style::supports`:
+//!
+//! ```rust
+//! use mew_css::{rule, style};
+//! use mew_css::properties::display::display;
+//! use mew_css::supports::supports;
+//! use mew_css::values::Display;
+//!
+//! let css = rule(".gallery", style()
+//!     .display(Display::Flex)
+//!     .supports(supports(display(Display::Grid)), |s| s.display(Display::Grid)))
+//!     .to_string();
+//!
+//! assert_eq!(css, ".gallery { display: flex; } \
+//!                  @supports (display: grid) { .gallery { display: grid; } }");
+//! ```
+
+use crate::error::MewError;
+use crate::properties::Property;
+use crate::values::ParseValueError;
+use std::fmt;
+use std::ops::Not;
+
+/// A condition of a `@supports` rule.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SupportsCondition {
+    /// Tests whether a declaration is supported, such as `(display: grid)`
+    Declaration(Property),
+    /// Tests whether a selector is supported, such as `selector(:has(a))`
+    Selector(String),
+    /// All the conditions must be supported
+    And(Vec<SupportsCondition>),
+    /// At least one of the conditions must be supported
+    Or(Vec<SupportsCondition>),
+    /// The condition must not be supported
+    Not(Box<SupportsCondition>),
+}
+
+impl SupportsCondition {
+    /// Creates a condition testing whether a declaration is supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `property` - The declaration to test
+    ///
+    /// # Returns
+    ///
+    /// A new `SupportsCondition` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::properties::Property;
+    /// use mew_css::supports::SupportsCondition;
+    ///
+    /// let condition = SupportsCondition::declaration(Property::new("backdrop-filter", "blur(4px)"));
+    /// assert_eq!(condition.to_string(), "(backdrop-filter: blur(4px))");
+    /// ```
+    pub fn declaration(property: Property) -> Self {
+        SupportsCondition::Declaration(property)
+    }
+
+    /// Creates a condition testing whether a selector is supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - The selector to test
+    ///
+    /// # Returns
+    ///
+    /// A new `SupportsCondition` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::supports::SupportsCondition;
+    ///
+    /// let condition = SupportsCondition::selector(":focus-visible");
+    /// assert_eq!(condition.to_string(), "selector(:focus-visible)");
+    /// ```
+    pub fn selector(selector: &str) -> Self {
+        SupportsCondition::Selector(selector.trim().to_string())
+    }
+
+    /// Combines this condition with another one; both must be supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::properties::Property;
+    /// use mew_css::supports::supports;
+    ///
+    /// let condition = supports(Property::new("display", "grid"))
+    ///     .and(supports(Property::new("gap", "1rem")));
+    /// assert_eq!(condition.to_string(), "(display: grid) and (gap: 1rem)");
+    /// ```
+    pub fn and(self, other: SupportsCondition) -> Self {
+        match self {
+            SupportsCondition::And(mut conditions) => {
+                conditions.push(other);
+                SupportsCondition::And(conditions)
+            }
+            condition => SupportsCondition::And(vec![condition, other]),
+        }
+    }
+
+    /// Combines this condition with another one; at least one must be supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::properties::Property;
+    /// use mew_css::supports::supports;
+    ///
+    /// let condition = supports(Property::new("position", "sticky"))
+    ///     .or(supports(Property::new("position", "-webkit-sticky")));
+    /// assert_eq!(condition.to_string(), "(position: sticky) or (position: -webkit-sticky)");
+    /// ```
+    pub fn or(self, other: SupportsCondition) -> Self {
+        match self {
+            SupportsCondition::Or(mut conditions) => {
+                conditions.push(other);
+                SupportsCondition::Or(conditions)
+            }
+            condition => SupportsCondition::Or(vec![condition, other]),
+        }
+    }
+
+    /// Negates this condition. This is equivalent to the `!` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::supports::SupportsCondition;
+    ///
+    /// let condition = SupportsCondition::selector(":has(a)").negate();
+    /// assert_eq!(condition.to_string(), "not selector(:has(a))");
+    /// ```
+    pub fn negate(self) -> Self {
+        SupportsCondition::Not(Box::new(self))
+    }
+
+    /// Checks that this condition can be written as a valid `@supports` prelude.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the condition is valid, or a `MewError` if a declaration has an empty
+    /// name or value, a selector is empty, or `and`/`or` combine no conditions
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::properties::Property;
+    /// use mew_css::supports::{SupportsCondition, supports};
+    ///
+    /// assert!(supports(Property::new("display", "grid")).validate().is_ok());
+    /// assert!(SupportsCondition::And(vec![]).validate().is_err());
+    /// assert!(SupportsCondition::selector(" ").validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), MewError> {
+        let error = || ParseValueError::new("SupportsCondition", &self.to_string()).into();
+
+        match self {
+            SupportsCondition::Declaration(property)
+                if property.name().trim().is_empty() || property.value().trim().is_empty() =>
+            {
+                Err(error())
+            }
+            SupportsCondition::Selector(selector) if selector.is_empty() => Err(error()),
+            SupportsCondition::Declaration(_) | SupportsCondition::Selector(_) => Ok(()),
+            SupportsCondition::And(conditions) | SupportsCondition::Or(conditions) if conditions.is_empty() => {
+                Err(error())
+            }
+            SupportsCondition::And(conditions) | SupportsCondition::Or(conditions) => {
+                conditions.iter().try_for_each(SupportsCondition::validate)
+            }
+            SupportsCondition::Not(condition) => condition.validate(),
+        }
+    }
+
+    /// Writes this condition as an operand of `and`, `or` or `not`, wrapping it in
+    /// parentheses when it is a compound condition.
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SupportsCondition::Declaration(_) | SupportsCondition::Selector(_) => write!(f, "{}", self),
+            _ => write!(f, "({})", self),
+        }
+    }
+}
+
+impl Not for SupportsCondition {
+    type Output = SupportsCondition;
+
+    fn not(self) -> Self::Output {
+        self.negate()
+    }
+}
+
+impl fmt::Display for SupportsCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SupportsCondition::Declaration(property) => {
+                write!(f, "({}: {})", property.name(), property.value())
+            }
+            SupportsCondition::Selector(selector) => write!(f, "selector({})", selector),
+            SupportsCondition::And(conditions) | SupportsCondition::Or(conditions) => {
+                let separator = if matches!(self, SupportsCondition::And(_)) { " and " } else { " or " };
+                for (i, condition) in conditions.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "{}", separator)?;
+                    }
+                    condition.write_operand(f)?;
+                }
+                Ok(())
+            }
+            SupportsCondition::Not(condition) => {
+                write!(f, "not ")?;
+                condition.write_operand(f)
+            }
+        }
+    }
+}
+
+// Implement From<Property> for SupportsCondition to allow automatic conversion
+impl From<Property> for SupportsCondition {
+    fn from(property: Property) -> Self {
+        SupportsCondition::Declaration(property)
+    }
+}
+
+/// Creates a condition testing whether a declaration is supported.
+///
+/// This is a convenience function that creates a new `SupportsCondition` instance.
+/// It's a shorter alternative to calling `SupportsCondition::declaration()`, and
+/// works with any function of the `properties` module.
+///
+/// # Arguments
+///
+/// * `property` - The declaration to test
+///
+/// # Returns
+///
+/// A new `SupportsCondition` instance
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::properties::display::display;
+/// use mew_css::supports::supports;
+/// use mew_css::values::Display;
+///
+/// assert_eq!(supports(display(Display::Grid)).to_string(), "(display: grid)");
+/// ```
+pub fn supports(property: Property) -> SupportsCondition {
+    SupportsCondition::declaration(property)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::properties::display::display;
+    use crate::values::Display;
+
+    #[test]
+    fn test_declaration() {
+        let condition = supports(display(Display::Grid));
+        assert_eq!(condition.to_string(), "(display: grid)");
+
+        let condition: SupportsCondition = Property::new("aspect-ratio", "16 / 9").into();
+        assert_eq!(condition.to_string(), "(aspect-ratio: 16 / 9)");
+    }
+
+    #[test]
+    fn test_selector() {
+        let condition = SupportsCondition::selector(" :has(+ .error) ");
+        assert_eq!(condition.to_string(), "selector(:has(+ .error))");
+    }
+
+    #[test]
+    fn test_and_or() {
+        let condition = supports(display(Display::Grid))
+            .and(supports(Property::new("gap", "1rem")))
+            .and(SupportsCondition::selector(":has(a)"));
+        assert_eq!(
+            condition.to_string(),
+            "(display: grid) and (gap: 1rem) and selector(:has(a))"
+        );
+
+        let condition = supports(display(Display::Grid)).or(supports(display(Display::Flex)));
+        assert_eq!(condition.to_string(), "(display: grid) or (display: flex)");
+    }
+
+    #[test]
+    fn test_not() {
+        let condition = !supports(display(Display::Grid));
+        assert_eq!(condition.to_string(), "not (display: grid)");
+
+        let condition = !supports(display(Display::Grid)).and(supports(Property::new("gap", "0")));
+        assert_eq!(condition.to_string(), "not ((display: grid) and (gap: 0))");
+    }
+
+    #[test]
+    fn test_mixed_operators() {
+        let condition = supports(display(Display::Grid))
+            .and(supports(Property::new("gap", "1rem")))
+            .or(supports(display(Display::Flex)));
+        assert_eq!(
+            condition.to_string(),
+            "((display: grid) and (gap: 1rem)) or (display: flex)"
+        );
+    }
+}
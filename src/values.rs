@@ -21,6 +21,7 @@
 //! - `Position`: CSS position property values
 //! - `FontWeight`: CSS font-weight property values
 //! - `BorderStyle`: CSS border-style property values
+//! - `Animation`: CSS animation shorthand values
 //!
 //! ## Usage Example
 //!
@@ -32,8 +33,26 @@
 //!     .align_content(AlignContent::Center)
 //!     .apply();
 //! ```
+//!
+//! ## Parsing Values
+//!
+//! Every value type implements `FromStr`, accepting the text its `Display` implementation
+//! produces (keywords are case-insensitive) and `var(--name)` for the `Var` variants.
+//! Invalid text is reported with a `ParseValueError`.
+//!
+//! ```rust
+//! use mew_css::values::{BorderStyle, JustifyContent, Size};
+//! use mew_css::var;
+//!
+//! assert_eq!("space-between".parse::<JustifyContent>(), Ok(JustifyContent::SpaceBetween));
+//! assert_eq!("2rem".parse::<Size>(), Ok(Size::Rem(2.0)));
+//! assert_eq!("var(--border)".parse::<BorderStyle>(), Ok(BorderStyle::Var(var("border"))));
+//! assert!("dashy".parse::<BorderStyle>().is_err());
+//! ```
 
+use crate::error::MewError;
 use std::fmt;
+use std::str::FromStr;
 
 /// Represents CSS color values with various formats and named colors.
 ///
@@ -44,6 +63,8 @@ use std::fmt;
 /// - HSL and HSLA values
 /// - Special values like `Transparent` and `CurrentColor`
 /// - CSS variables through the `Var` variant
+/// - `color-mix()` and relative colors, produced by the color operations of the
+///   `color` module when a color cannot be computed in Rust
 #[derive(Debug, Clone, PartialEq)]
 pub enum Color {
     /// Alice Blue (#F0F8FF)
@@ -216,6 +237,27 @@ pub enum Color {
     /// HSLA color
     Hsla(u16, u8, u8, f32),
 
+    /// HWB color: hue in degrees, whiteness and blackness from 0 to 100, and alpha
+    Hwb(f32, f32, f32, f32),
+
+    /// CIE Lab color: lightness from 0 to 100, `a` and `b` axes (about -125 to 125), and alpha
+    Lab(f32, f32, f32, f32),
+
+    /// CIE LCH color: lightness from 0 to 100, chroma (0 to about 150), hue in degrees, and alpha
+    Lch(f32, f32, f32, f32),
+
+    /// Oklab color: lightness from 0 to 1, `a` and `b` axes (about -0.4 to 0.4), and alpha
+    Oklab(f32, f32, f32, f32),
+
+    /// Oklch color: lightness from 0 to 1, chroma (0 to about 0.4), hue in degrees, and alpha
+    Oklch(f32, f32, f32, f32),
+
+    /// sRGB color written with the `color()` function: channels from 0 to 1, and alpha
+    Srgb(f32, f32, f32, f32),
+
+    /// Display P3 color written with the `color()` function: channels from 0 to 1, and alpha
+    DisplayP3(f32, f32, f32, f32),
+
     /// Current color
     CurrentColor,
 
@@ -224,6 +266,15 @@ pub enum Color {
 
     /// CSS variable
     Var(crate::variable::CssVar),
+
+    /// `color-mix()` function, mixing two colors in a color space
+    Mix(Box<crate::color::ColorMix>),
+
+    /// Relative color, deriving a color from another one (e.g., `hsl(from red h s calc(l + 10))`)
+    Relative(Box<crate::color::RelativeColor>),
+
+    /// `light-dark()` function, choosing a color for the light or dark color scheme
+    LightDark(Box<Color>, Box<Color>),
 }
 
 impl fmt::Display for Color {
@@ -379,16 +430,157 @@ impl fmt::Display for Color {
             Color::YellowGreen => write!(f, "yellowgreen"),
             Color::Transparent => write!(f, "transparent"),
             Color::Rgb(r, g, b) => write!(f, "rgb({}, {}, {})", r, g, b),
-            Color::Rgba(r, g, b, a) => write!(f, "rgba({}, {}, {}, {})", r, g, b, a),
+            Color::Rgba(r, g, b, a) => write!(f, "rgba({}, {}, {}, {})", r, g, b, Number::new(*a)),
             Color::Hex(hex) => {
                 let hex_str = if hex.starts_with('#') { hex.clone() } else { format!("#{}", hex) };
                 write!(f, "{}", hex_str)
             },
             Color::Hsl(h, s, l) => write!(f, "hsl({}, {}%, {}%)", h, s, l),
-            Color::Hsla(h, s, l, a) => write!(f, "hsla({}, {}%, {}%, {})", h, s, l, a),
+            Color::Hsla(h, s, l, a) => write!(f, "hsla({}, {}%, {}%, {})", h, s, l, Number::new(*a)),
+            Color::Hwb(h, w, b, a) => {
+                write!(f, "hwb({} {}% {}%{})", Number::new(*h), Number::new(*w), Number::new(*b), AlphaSuffix(*a))
+            }
+            Color::Lab(l, a, b, alpha) => write!(f, "lab({}{})", Channels(&[*l, *a, *b]), AlphaSuffix(*alpha)),
+            Color::Lch(l, c, h, a) => write!(f, "lch({}{})", Channels(&[*l, *c, *h]), AlphaSuffix(*a)),
+            Color::Oklab(l, a, b, alpha) => write!(f, "oklab({}{})", Channels(&[*l, *a, *b]), AlphaSuffix(*alpha)),
+            Color::Oklch(l, c, h, a) => write!(f, "oklch({}{})", Channels(&[*l, *c, *h]), AlphaSuffix(*a)),
+            Color::Srgb(r, g, b, a) => write!(f, "color(srgb {}{})", Channels(&[*r, *g, *b]), AlphaSuffix(*a)),
+            Color::DisplayP3(r, g, b, a) => {
+                write!(f, "color(display-p3 {}{})", Channels(&[*r, *g, *b]), AlphaSuffix(*a))
+            }
             Color::CurrentColor => write!(f, "currentColor"),
             Color::Inherit => write!(f, "inherit"),
             Color::Var(var) => write!(f, "{}", var),
+            Color::Mix(mix) => write!(f, "{}", mix),
+            Color::Relative(relative) => write!(f, "{}", relative),
+            Color::LightDark(light, dark) => write!(f, "light-dark({}, {})", light, dark),
+        }
+    }
+}
+
+/// Writes the space-separated channels of the modern color functions.
+struct Channels<'a>(&'a [f32]);
+
+impl fmt::Display for Channels<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, channel) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", Number::new(*channel))?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the ` / alpha` part of the modern color functions, only if the color is not opaque.
+struct AlphaSuffix(f32);
+
+impl fmt::Display for AlphaSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let alpha = Number::new(self.0);
+        if alpha.0 < 1.0 { write!(f, " / {}", alpha) } else { Ok(()) }
+    }
+}
+
+impl Color {
+    /// Creates a hex color, checking that it is made of 3, 4, 6 or 8 hexadecimal digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `hex` - The hex color, with or without the leading `#`
+    ///
+    /// # Returns
+    ///
+    /// A `Color::Hex` with a leading `#`, or `MewError::InvalidHex` if the digits are invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::error::MewError;
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::try_hex("#f33"), Ok(Color::Hex("#f33".to_string())));
+    /// assert_eq!(Color::try_hex("ff3333"), Ok(Color::Hex("#ff3333".to_string())));
+    /// assert_eq!(Color::try_hex("#red"), Err(MewError::InvalidHex("#red".to_string())));
+    /// ```
+    pub fn try_hex(hex: &str) -> Result<Self, MewError> {
+        let color = Color::Hex(format!("#{}", hex.strip_prefix('#').unwrap_or(hex)));
+        color.validate().map(|_| color)
+    }
+
+    /// Creates an RGBA color, checking that the alpha value is between 0.0 and 1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `r`, `g`, `b` - The red, green and blue channels
+    /// * `a` - The alpha value, from 0.0 (transparent) to 1.0 (opaque)
+    ///
+    /// # Returns
+    ///
+    /// A `Color::Rgba`, or `MewError::InvalidAlpha` if the alpha value is out of range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::error::MewError;
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::try_rgba(0, 0, 0, 0.5), Ok(Color::Rgba(0, 0, 0, 0.5)));
+    /// assert_eq!(Color::try_rgba(0, 0, 0, 50.0), Err(MewError::InvalidAlpha(50.0)));
+    /// ```
+    pub fn try_rgba(r: u8, g: u8, b: u8, a: f32) -> Result<Self, MewError> {
+        let color = Color::Rgba(r, g, b, a);
+        color.validate().map(|_| color)
+    }
+
+    /// Checks that the color is valid CSS: hex colors must be made of 3, 4, 6 or 8
+    /// hexadecimal digits, and alpha values must be between 0.0 and 1.0. Functions such
+    /// as `color-mix()` are checked through their arguments and the colors they are made of.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the color is valid, or the `MewError` describing the problem
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    ///
+    /// assert!(Color::Hsla(120, 50, 50, 0.3).validate().is_ok());
+    /// assert!(Color::Hex("#12345".to_string()).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), MewError> {
+        match self {
+            Color::Hex(hex) => {
+                let digits = hex.strip_prefix('#').unwrap_or(hex);
+                if matches!(digits.len(), 3 | 4 | 6 | 8) && digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                    Ok(())
+                } else {
+                    Err(MewError::InvalidHex(hex.clone()))
+                }
+            }
+            Color::Rgba(_, _, _, a)
+            | Color::Hsla(_, _, _, a)
+            | Color::Hwb(_, _, _, a)
+            | Color::Lab(_, _, _, a)
+            | Color::Lch(_, _, _, a)
+            | Color::Oklab(_, _, _, a)
+            | Color::Oklch(_, _, _, a)
+            | Color::Srgb(_, _, _, a)
+            | Color::DisplayP3(_, _, _, a)
+                if !(0.0..=1.0).contains(a) =>
+            {
+                Err(MewError::InvalidAlpha(*a))
+            }
+            // Functions are valid if their arguments and the colors they are made of are
+            Color::Mix(mix) => mix.validate(),
+            Color::Relative(relative) => relative.validate(),
+            Color::LightDark(light, dark) => {
+                light.validate()?;
+                dark.validate()
+            }
+            _ => Ok(()),
         }
     }
 }
@@ -396,47 +588,603 @@ impl fmt::Display for Color {
 /// Represents size and length values for CSS properties.
 ///
 /// The `Size` enum provides a type-safe way to specify sizes in CSS. It supports
-/// various unit types including absolute units (like pixels), relative units
-/// (like percentages, em, rem), viewport-relative units, and special values like
-/// `Auto` and `Zero`.
+/// absolute units (like pixels or centimeters), font-relative units (like em, rem
+/// or ch), percentages, viewport-relative and container-relative units, and special
+/// values like `Auto` and `Zero`.
+///
+/// Every length holds an `f32`, so fractional and negative lengths such as `0.5px` or
+/// `-1px` can be written. Lengths are written with at most 4 decimals, so that floating
+/// point noise (e.g., `0.30000001`) never reaches the CSS. Sizes can be combined with
+/// the `+`, `-`, `*` and `/` operators into `calc()` expressions (see `Calc`).
 ///
 /// This enum is used for properties like width, height, margin, padding, font-size,
 /// and any other CSS property that accepts a size or length value.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::values::Size;
+///
+/// assert_eq!(Size::Px(-1.0).to_string(), "-1px");
+/// assert_eq!(Size::Px(0.5).to_string(), "0.5px");
+/// assert_eq!(Size::Rem(0.1 + 0.2).to_string(), "0.3rem");
+/// assert_eq!(Size::Dvh(100.0).to_string(), "100dvh");
+/// assert_eq!(Size::Q(2.0).to_string(), "2Q");
+/// assert_eq!((Size::Percent(100.0) - Size::Px(1.0)).to_string(), "calc(100% - 1px)");
+/// ```
 #[derive(Debug, Clone, PartialEq)]
 pub enum Size {
     /// Zero value (equivalent to 0px)
     Zero,
 
     /// Pixel values - absolute length in pixels
-    Px(u32),
+    Px(f32),
+    /// Centimeters - absolute length (1cm = 96px/2.54)
+    Cm(f32),
+    /// Millimeters - absolute length (1mm = 1/10th of 1cm)
+    Mm(f32),
+    /// Quarter-millimeters - absolute length (1Q = 1/40th of 1cm)
+    Q(f32),
+    /// Inches - absolute length (1in = 96px)
+    In(f32),
+    /// Points - absolute length (1pt = 1/72nd of 1in)
+    Pt(f32),
+    /// Picas - absolute length (1pc = 1/6th of 1in)
+    Pc(f32),
+
     /// Percentage values - relative to parent element
     Percent(f32),
+
     /// Em values - relative to the element's font size
     Em(f32),
     /// Rem values - relative to the root element's font size
     Rem(f32),
+    /// Ex values - relative to the x-height of the element's font
+    Ex(f32),
+    /// Ch values - relative to the width of the "0" glyph of the element's font
+    Ch(f32),
+    /// Lh values - relative to the element's line height
+    Lh(f32),
+    /// Rlh values - relative to the root element's line height
+    Rlh(f32),
+
     /// Viewport width percentage - relative to viewport width
     Vw(f32),
     /// Viewport height percentage - relative to viewport height
     Vh(f32),
+    /// Percentage of the smaller viewport dimension
+    Vmin(f32),
+    /// Percentage of the larger viewport dimension
+    Vmax(f32),
+    /// Dynamic viewport width percentage - follows the browser UI as it expands and retracts
+    Dvw(f32),
+    /// Dynamic viewport height percentage - follows the browser UI as it expands and retracts
+    Dvh(f32),
+    /// Small viewport width percentage - with the browser UI expanded
+    Svw(f32),
+    /// Small viewport height percentage - with the browser UI expanded
+    Svh(f32),
+    /// Large viewport width percentage - with the browser UI retracted
+    Lvw(f32),
+    /// Large viewport height percentage - with the browser UI retracted
+    Lvh(f32),
+
+    /// Container width percentage - relative to the query container's width
+    Cqw(f32),
+    /// Container height percentage - relative to the query container's height
+    Cqh(f32),
+    /// Container inline size percentage - relative to the query container's inline size
+    Cqi(f32),
+    /// Container block size percentage - relative to the query container's block size
+    Cqb(f32),
+
     /// Auto value - browser determines the appropriate size
     Auto,
     /// CSS variable reference
     Var(crate::variable::CssVar),
+    /// Calculated value, usually built with arithmetic operators on sizes (see `Calc`)
+    Calc(Box<crate::calc::Calc>),
 }
 
+impl Size {
+    /// Returns the number and unit of a length, or `None` for `Zero`, `Auto`, `Var` and `Calc`.
+    pub(crate) fn length(&self) -> Option<(f32, &'static str)> {
+        let length = match self {
+            Size::Px(val) => (*val, "px"),
+            Size::Cm(val) => (*val, "cm"),
+            Size::Mm(val) => (*val, "mm"),
+            Size::Q(val) => (*val, "Q"),
+            Size::In(val) => (*val, "in"),
+            Size::Pt(val) => (*val, "pt"),
+            Size::Pc(val) => (*val, "pc"),
+            Size::Percent(val) => (*val, "%"),
+            Size::Em(val) => (*val, "em"),
+            Size::Rem(val) => (*val, "rem"),
+            Size::Ex(val) => (*val, "ex"),
+            Size::Ch(val) => (*val, "ch"),
+            Size::Lh(val) => (*val, "lh"),
+            Size::Rlh(val) => (*val, "rlh"),
+            Size::Vw(val) => (*val, "vw"),
+            Size::Vh(val) => (*val, "vh"),
+            Size::Vmin(val) => (*val, "vmin"),
+            Size::Vmax(val) => (*val, "vmax"),
+            Size::Dvw(val) => (*val, "dvw"),
+            Size::Dvh(val) => (*val, "dvh"),
+            Size::Svw(val) => (*val, "svw"),
+            Size::Svh(val) => (*val, "svh"),
+            Size::Lvw(val) => (*val, "lvw"),
+            Size::Lvh(val) => (*val, "lvh"),
+            Size::Cqw(val) => (*val, "cqw"),
+            Size::Cqh(val) => (*val, "cqh"),
+            Size::Cqi(val) => (*val, "cqi"),
+            Size::Cqb(val) => (*val, "cqb"),
+            Size::Zero | Size::Auto | Size::Var(_) | Size::Calc(_) => return None,
+        };
+        Some(length)
+    }
+
+    /// Applies a function to the number of a length, keeping its unit.
+    ///
+    /// Returns `None` for `Zero`, `Auto`, `Var` and `Calc`.
+    pub(crate) fn map_length(&self, f: impl FnOnce(f32) -> f32) -> Option<Size> {
+        let (val, unit) = self.length()?;
+        Size::with_unit(f(val), unit)
+    }
+
+    /// Creates a length from a number and a unit (case-insensitive), such as `(2.0, "rem")`.
+    pub(crate) fn with_unit(val: f32, unit: &str) -> Option<Size> {
+        SIZE_UNITS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+            .map(|(_, size)| size(val))
+    }
+}
+
+/// Creates a `Size` from a number, as the tuple variants of `Size` do.
+type SizeConstructor = fn(f32) -> Size;
+
+/// The units of `Size`, with the variant each one is parsed into.
+const SIZE_UNITS: [(&str, SizeConstructor); 28] = [
+    ("px", Size::Px),
+    ("cm", Size::Cm),
+    ("mm", Size::Mm),
+    ("q", Size::Q),
+    ("in", Size::In),
+    ("pt", Size::Pt),
+    ("pc", Size::Pc),
+    ("%", Size::Percent),
+    ("em", Size::Em),
+    ("rem", Size::Rem),
+    ("ex", Size::Ex),
+    ("ch", Size::Ch),
+    ("lh", Size::Lh),
+    ("rlh", Size::Rlh),
+    ("vw", Size::Vw),
+    ("vh", Size::Vh),
+    ("vmin", Size::Vmin),
+    ("vmax", Size::Vmax),
+    ("dvw", Size::Dvw),
+    ("dvh", Size::Dvh),
+    ("svw", Size::Svw),
+    ("svh", Size::Svh),
+    ("lvw", Size::Lvw),
+    ("lvh", Size::Lvh),
+    ("cqw", Size::Cqw),
+    ("cqh", Size::Cqh),
+    ("cqi", Size::Cqi),
+    ("cqb", Size::Cqb),
+];
+
 impl fmt::Display for Size {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((val, unit)) = self.length() {
+            return write!(f, "{}{}", Number::new(val), unit);
+        }
+
         match self {
-            Size::Zero => write!(f, "0"),
-            Size::Px(val) => write!(f, "{}px", val),
-            Size::Percent(val) => write!(f, "{}%", val),
-            Size::Em(val) => write!(f, "{}em", val),
-            Size::Rem(val) => write!(f, "{}rem", val),
-            Size::Vw(val) => write!(f, "{}vw", val),
-            Size::Vh(val) => write!(f, "{}vh", val),
             Size::Auto => write!(f, "auto"),
             Size::Var(var) => write!(f, "{}", var),
+            Size::Calc(calc) => write!(f, "{}", calc),
+            _ => write!(f, "0"),
+        }
+    }
+}
+
+/// Writes a number with at most 4 decimals, hiding the noise of `f32` arithmetic
+/// (e.g., `0.1 + 0.2` is written as `0.3` rather than `0.30000001`).
+///
+/// CSS has no literal for `NaN` and infinite numbers, so they are written as `0`
+/// rather than as invalid tokens such as `NaNpx` or `infpx`.
+pub(crate) struct Number(f32);
+
+impl Number {
+    /// Creates a number to write, replacing a non-finite value with `0`.
+    pub(crate) fn new(value: f32) -> Self {
+        Number(if value.is_finite() { value } else { 0.0 })
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rounded = (self.0 as f64 * 10_000.0).round() / 10_000.0;
+        // Avoid writing "-0"
+        if rounded == 0.0 { write!(f, "0") } else { write!(f, "{}", rounded) }
+    }
+}
+
+/// Represents angle values, for rotations, hues and gradients, and in math functions
+/// such as `sin()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::values::Angle;
+///
+/// assert_eq!(Angle::Deg(45.0).to_string(), "45deg");
+/// assert_eq!(Angle::Turn(0.25).to_string(), "0.25turn");
+/// assert_eq!(Angle::Turn(0.25).to_deg(), Some(90.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Angle {
+    /// Degrees - a full circle is 360deg
+    Deg(f32),
+    /// Radians - a full circle is 2π rad
+    Rad(f32),
+    /// Gradians - a full circle is 400grad
+    Grad(f32),
+    /// Turns - a full circle is 1turn
+    Turn(f32),
+    /// CSS variable
+    Var(crate::variable::CssVar),
+}
+
+/// Creates an `Angle` from a number, as the tuple variants of `Angle` do.
+type AngleConstructor = fn(f32) -> Angle;
+
+/// The units of `Angle`, with the variant each one is parsed into and the size of a full circle.
+const ANGLE_UNITS: [(&str, AngleConstructor, f64); 4] = [
+    ("deg", Angle::Deg, 360.0),
+    ("rad", Angle::Rad, std::f64::consts::TAU),
+    ("grad", Angle::Grad, 400.0),
+    ("turn", Angle::Turn, 1.0),
+];
+
+/// A table of units, with the variant each one is parsed into and the size of the same
+/// quantity in each unit (such as a full circle for `ANGLE_UNITS`).
+type UnitTable<T> = [(&'static str, fn(f32) -> T, f64)];
+
+/// Converts a number between two units of a table of units.
+fn convert_in<T>(units: &UnitTable<T>, val: f32, from: &str, to: &str) -> f64 {
+    let size = |unit: &str| {
+        let (.., size) = units.iter().find(|(name, ..)| *name == unit).expect("the unit is in the table");
+        *size
+    };
+    val as f64 / size(from) * size(to)
+}
+
+impl Angle {
+    /// Returns the number and unit of the angle, or `None` for a CSS variable.
+    pub(crate) fn value(&self) -> Option<(f32, &'static str)> {
+        let value = match self {
+            Angle::Deg(val) => (*val, "deg"),
+            Angle::Rad(val) => (*val, "rad"),
+            Angle::Grad(val) => (*val, "grad"),
+            Angle::Turn(val) => (*val, "turn"),
+            Angle::Var(_) => return None,
+        };
+        Some(value)
+    }
+
+    /// Creates an angle from a number and a unit (case-insensitive), such as `(90.0, "deg")`.
+    pub(crate) fn with_unit(val: f32, unit: &str) -> Option<Angle> {
+        ANGLE_UNITS
+            .iter()
+            .find(|(name, ..)| name.eq_ignore_ascii_case(unit))
+            .map(|(_, angle, _)| angle(val))
+    }
+
+    /// Applies a function to the number of the angle, keeping its unit.
+    ///
+    /// Returns `None` for `Var`.
+    pub(crate) fn map(&self, f: impl FnOnce(f32) -> f32) -> Option<Angle> {
+        let (val, unit) = self.value()?;
+        Angle::with_unit(f(val), unit)
+    }
+
+    /// Returns the angle in radians, in double precision for math functions.
+    pub(crate) fn radians(&self) -> Option<f64> {
+        let (val, unit) = self.value()?;
+        Some(convert_in(&ANGLE_UNITS, val, unit, "rad"))
+    }
+
+    /// Returns the angle in the given unit, or `None` for a CSS variable.
+    fn to_unit(&self, unit: &str) -> Option<f32> {
+        let (val, own_unit) = self.value()?;
+        Some(convert_in(&ANGLE_UNITS, val, own_unit, unit) as f32)
+    }
+
+    /// Returns the angle in degrees.
+    ///
+    /// # Returns
+    ///
+    /// The number of degrees, or `None` for a CSS variable
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Angle;
+    /// use mew_css::var;
+    ///
+    /// assert_eq!(Angle::Grad(100.0).to_deg(), Some(90.0));
+    /// assert_eq!(Angle::Var(var("hue")).to_deg(), None);
+    /// ```
+    pub fn to_deg(&self) -> Option<f32> {
+        self.to_unit("deg")
+    }
+
+    /// Returns the angle in radians, or `None` for a CSS variable.
+    pub fn to_rad(&self) -> Option<f32> {
+        self.to_unit("rad")
+    }
+
+    /// Returns the angle in gradians, or `None` for a CSS variable.
+    pub fn to_grad(&self) -> Option<f32> {
+        self.to_unit("grad")
+    }
+
+    /// Returns the angle in turns, or `None` for a CSS variable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Angle;
+    ///
+    /// assert_eq!(Angle::Deg(-90.0).to_turn(), Some(-0.25));
+    /// ```
+    pub fn to_turn(&self) -> Option<f32> {
+        self.to_unit("turn")
+    }
+}
+
+impl fmt::Display for Angle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Angle::Var(var) => write!(f, "{}", var),
+            angle => {
+                let (val, unit) = angle.value().expect("only variables have no value");
+                write!(f, "{}{}", Number::new(val), unit)
+            }
+        }
+    }
+}
+
+/// Represents time values, for the durations and delays of transitions and animations.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::values::Time;
+///
+/// assert_eq!(Time::Ms(250.0).to_string(), "250ms");
+/// assert_eq!(Time::Ms(250.0).to_seconds(), Some(0.25));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Time {
+    /// Seconds
+    S(f32),
+    /// Milliseconds - 1000ms is 1s
+    Ms(f32),
+    /// CSS variable
+    Var(crate::variable::CssVar),
+}
+
+/// Creates a `Time` from a number, as the tuple variants of `Time` do.
+type TimeConstructor = fn(f32) -> Time;
+
+/// The units of `Time`, with the variant each one is parsed into and the size of a second.
+const TIME_UNITS: [(&str, TimeConstructor, f64); 2] = [("s", Time::S, 1.0), ("ms", Time::Ms, 1000.0)];
+
+impl Time {
+    /// Returns the number and unit of the time, or `None` for a CSS variable.
+    fn value(&self) -> Option<(f32, &'static str)> {
+        match self {
+            Time::S(val) => Some((*val, "s")),
+            Time::Ms(val) => Some((*val, "ms")),
+            Time::Var(_) => None,
+        }
+    }
+
+    /// Returns the time in the given unit, or `None` for a CSS variable.
+    fn to_unit(&self, unit: &str) -> Option<f32> {
+        let (val, own_unit) = self.value()?;
+        Some(convert_in(&TIME_UNITS, val, own_unit, unit) as f32)
+    }
+
+    /// Returns the time in seconds, or `None` for a CSS variable.
+    pub fn to_seconds(&self) -> Option<f32> {
+        self.to_unit("s")
+    }
+
+    /// Returns the time in milliseconds, or `None` for a CSS variable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Time;
+    ///
+    /// assert_eq!(Time::S(0.3).to_milliseconds(), Some(300.0));
+    /// ```
+    pub fn to_milliseconds(&self) -> Option<f32> {
+        self.to_unit("ms")
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Time::Var(var) => write!(f, "{}", var),
+            time => {
+                let (val, unit) = time.value().expect("only variables have no value");
+                write!(f, "{}{}", Number::new(val), unit)
+            }
+        }
+    }
+}
+
+/// Represents frequency values, as used by aural properties.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::values::Frequency;
+///
+/// assert_eq!(Frequency::KHz(1.5).to_string(), "1.5kHz");
+/// assert_eq!(Frequency::KHz(1.5).to_hz(), Some(1500.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Frequency {
+    /// Hertz
+    Hz(f32),
+    /// Kilohertz - 1kHz is 1000Hz
+    KHz(f32),
+    /// CSS variable
+    Var(crate::variable::CssVar),
+}
+
+/// Creates a `Frequency` from a number, as the tuple variants of `Frequency` do.
+type FrequencyConstructor = fn(f32) -> Frequency;
+
+/// The units of `Frequency`, with the variant each one is parsed into and the size of a hertz.
+const FREQUENCY_UNITS: [(&str, FrequencyConstructor, f64); 2] =
+    [("Hz", Frequency::Hz, 1.0), ("kHz", Frequency::KHz, 0.001)];
+
+impl Frequency {
+    /// Returns the number and unit of the frequency, or `None` for a CSS variable.
+    fn value(&self) -> Option<(f32, &'static str)> {
+        match self {
+            Frequency::Hz(val) => Some((*val, "Hz")),
+            Frequency::KHz(val) => Some((*val, "kHz")),
+            Frequency::Var(_) => None,
+        }
+    }
+
+    /// Returns the frequency in the given unit, or `None` for a CSS variable.
+    fn to_unit(&self, unit: &str) -> Option<f32> {
+        let (val, own_unit) = self.value()?;
+        Some(convert_in(&FREQUENCY_UNITS, val, own_unit, unit) as f32)
+    }
+
+    /// Returns the frequency in hertz, or `None` for a CSS variable.
+    pub fn to_hz(&self) -> Option<f32> {
+        self.to_unit("Hz")
+    }
+
+    /// Returns the frequency in kilohertz, or `None` for a CSS variable.
+    pub fn to_khz(&self) -> Option<f32> {
+        self.to_unit("kHz")
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Frequency::Var(var) => write!(f, "{}", var),
+            frequency => {
+                let (val, unit) = frequency.value().expect("only variables have no value");
+                write!(f, "{}{}", Number::new(val), unit)
+            }
+        }
+    }
+}
+
+/// Represents resolution values, for the `resolution` media feature and `image-set()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::values::Resolution;
+///
+/// assert_eq!(Resolution::Dppx(2.0).to_string(), "2dppx");
+/// assert_eq!(Resolution::Dppx(2.0).to_dpi(), Some(192.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Resolution {
+    /// Dots per inch
+    Dpi(f32),
+    /// Dots per centimeter
+    Dpcm(f32),
+    /// Dots per CSS pixel - 1dppx is 96dpi
+    Dppx(f32),
+    /// Dots per CSS pixel, as written in `image-set()` (an alias of `dppx`)
+    X(f32),
+    /// CSS variable
+    Var(crate::variable::CssVar),
+}
+
+/// Creates a `Resolution` from a number, as the tuple variants of `Resolution` do.
+type ResolutionConstructor = fn(f32) -> Resolution;
+
+/// The units of `Resolution`, with the variant each one is parsed into and the size of a dot
+/// per CSS pixel.
+const RESOLUTION_UNITS: [(&str, ResolutionConstructor, f64); 4] = [
+    ("dpi", Resolution::Dpi, 96.0),
+    ("dpcm", Resolution::Dpcm, 96.0 / 2.54),
+    ("dppx", Resolution::Dppx, 1.0),
+    ("x", Resolution::X, 1.0),
+];
+
+impl Resolution {
+    /// Returns the number and unit of the resolution, or `None` for a CSS variable.
+    fn value(&self) -> Option<(f32, &'static str)> {
+        let value = match self {
+            Resolution::Dpi(val) => (*val, "dpi"),
+            Resolution::Dpcm(val) => (*val, "dpcm"),
+            Resolution::Dppx(val) => (*val, "dppx"),
+            Resolution::X(val) => (*val, "x"),
+            Resolution::Var(_) => return None,
+        };
+        Some(value)
+    }
+
+    /// Returns the resolution in the given unit, or `None` for a CSS variable.
+    fn to_unit(&self, unit: &str) -> Option<f32> {
+        let (val, own_unit) = self.value()?;
+        Some(convert_in(&RESOLUTION_UNITS, val, own_unit, unit) as f32)
+    }
+
+    /// Returns the resolution in dots per inch, or `None` for a CSS variable.
+    pub fn to_dpi(&self) -> Option<f32> {
+        self.to_unit("dpi")
+    }
+
+    /// Returns the resolution in dots per centimeter, or `None` for a CSS variable.
+    pub fn to_dpcm(&self) -> Option<f32> {
+        self.to_unit("dpcm")
+    }
+
+    /// Returns the resolution in dots per CSS pixel, or `None` for a CSS variable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Resolution;
+    ///
+    /// assert_eq!(Resolution::Dpi(144.0).to_dppx(), Some(1.5));
+    /// assert_eq!(Resolution::X(2.0).to_dppx(), Some(2.0));
+    /// ```
+    pub fn to_dppx(&self) -> Option<f32> {
+        self.to_unit("dppx")
+    }
+}
+
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Resolution::Var(var) => write!(f, "{}", var),
+            resolution => {
+                let (val, unit) = resolution.value().expect("only variables have no value");
+                write!(f, "{}{}", Number::new(val), unit)
+            }
         }
     }
 }
@@ -652,7 +1400,7 @@ pub enum FontWeight {
     Bold,
     Bolder,
     Lighter,
-    /// Numeric weight (100-900)
+    /// Numeric weight (1-1000)
     Weight(u16),
     /// CSS variable
     Var(crate::variable::CssVar),
@@ -666,8 +1414,8 @@ impl fmt::Display for FontWeight {
             FontWeight::Bolder => write!(f, "bolder"),
             FontWeight::Lighter => write!(f, "lighter"),
             FontWeight::Weight(w) => {
-                // Validate weight is between 100-900 and a multiple of 100
-                if *w >= 100 && *w <= 900 && w % 100 == 0 {
+                // Validate weight is between 1 and 1000
+                if (1..=1000).contains(w) {
                     write!(f, "{}", w)
                 } else {
                     write!(f, "400") // Default to normal if invalid
@@ -678,6 +1426,49 @@ impl fmt::Display for FontWeight {
     }
 }
 
+impl FontWeight {
+    /// Creates a numeric font weight, checking that it is between 1 and 1000.
+    ///
+    /// Unlike `FontWeight::Weight`, which is written as `400` when the weight is invalid,
+    /// this reports the invalid weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - The numeric weight
+    ///
+    /// # Returns
+    ///
+    /// A `FontWeight::Weight`, or `MewError::InvalidFontWeight` if the weight is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::error::MewError;
+    /// use mew_css::values::FontWeight;
+    ///
+    /// assert_eq!(FontWeight::try_weight(450), Ok(FontWeight::Weight(450)));
+    /// assert_eq!(FontWeight::try_weight(1200), Err(MewError::InvalidFontWeight(1200)));
+    /// ```
+    pub fn try_weight(weight: u16) -> Result<Self, MewError> {
+        let value = FontWeight::Weight(weight);
+        value.validate().map(|_| value)
+    }
+
+    /// Checks that a numeric font weight is between 1 and 1000, as in CSS Fonts Level 4.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the font weight is valid, or `MewError::InvalidFontWeight`
+    pub fn validate(&self) -> Result<(), MewError> {
+        match self {
+            FontWeight::Weight(w) if !(1..=1000).contains(w) => {
+                Err(MewError::InvalidFontWeight(*w))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 /// Text align values
 #[derive(Debug, Clone, PartialEq)]
 pub enum TextAlign {
@@ -844,7 +1635,7 @@ impl fmt::Display for BorderStyle {
 #[derive(Debug, Clone, PartialEq)]
 pub enum FontSize {
     /// Pixel values
-    Px(u32),
+    Px(f32),
     /// Percentage values
     Percent(f32),
     /// Em values (relative to font size)
@@ -864,18 +1655,25 @@ pub enum FontSize {
     XLarge,
     XxLarge,
     /// Calculated value
-    Calc(String),
+    Calc(crate::calc::Calc),
     /// CSS variable
     Var(crate::variable::CssVar),
 }
 
+// Implement From<Calc> for FontSize to use calc() and comparison functions as font sizes
+impl From<crate::calc::Calc> for FontSize {
+    fn from(calc: crate::calc::Calc) -> Self {
+        FontSize::Calc(calc)
+    }
+}
+
 impl fmt::Display for FontSize {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            FontSize::Px(val) => write!(f, "{}px", val),
-            FontSize::Percent(val) => write!(f, "{}%", val),
-            FontSize::Em(val) => write!(f, "{}em", val),
-            FontSize::Rem(val) => write!(f, "{}rem", val),
+            FontSize::Px(val) => write!(f, "{}px", Number::new(*val)),
+            FontSize::Percent(val) => write!(f, "{}%", Number::new(*val)),
+            FontSize::Em(val) => write!(f, "{}em", Number::new(*val)),
+            FontSize::Rem(val) => write!(f, "{}rem", Number::new(*val)),
             FontSize::Smaller => write!(f, "smaller"),
             FontSize::Larger => write!(f, "larger"),
             FontSize::XxSmall => write!(f, "xx-small"),
@@ -885,7 +1683,7 @@ impl fmt::Display for FontSize {
             FontSize::Large => write!(f, "large"),
             FontSize::XLarge => write!(f, "x-large"),
             FontSize::XxLarge => write!(f, "xx-large"),
-            FontSize::Calc(expr) => write!(f, "calc({})", expr),
+            FontSize::Calc(calc) => write!(f, "{}", calc),
             FontSize::Var(var) => write!(f, "{}", var),
         }
     }
@@ -903,19 +1701,26 @@ pub enum LineHeight {
     /// Percentage value
     Percent(f32),
     /// Calculated value
-    Calc(String),
+    Calc(crate::calc::Calc),
     /// CSS variable
     Var(crate::variable::CssVar),
 }
 
+// Implement From<Calc> for LineHeight to use calc() and comparison functions as line heights
+impl From<crate::calc::Calc> for LineHeight {
+    fn from(calc: crate::calc::Calc) -> Self {
+        LineHeight::Calc(calc)
+    }
+}
+
 impl fmt::Display for LineHeight {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             LineHeight::Normal => write!(f, "normal"),
-            LineHeight::Number(val) => write!(f, "{}", val),
+            LineHeight::Number(val) => write!(f, "{}", Number::new(*val)),
             LineHeight::Length(size) => write!(f, "{}", size),
-            LineHeight::Percent(val) => write!(f, "{}%", val),
-            LineHeight::Calc(expr) => write!(f, "calc({})", expr),
+            LineHeight::Percent(val) => write!(f, "{}%", Number::new(*val)),
+            LineHeight::Calc(calc) => write!(f, "{}", calc),
             LineHeight::Var(var) => write!(f, "{}", var),
         }
     }
@@ -960,92 +1765,398 @@ impl fmt::Display for BoxShadow {
 #[derive(Debug, Clone, PartialEq)]
 pub struct Transition {
     pub property: String,
-    pub duration: f32,
+    pub duration: Time,
     pub timing_function: Option<String>,
-    pub delay: Option<f32>,
+    pub delay: Option<Time>,
 }
 
 impl fmt::Display for Transition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}s", self.property, self.duration)?;
+        write!(f, "{} {}", self.property, self.duration)?;
 
         if let Some(timing) = &self.timing_function {
             write!(f, " {}", timing)?;
         }
 
-        if let Some(delay) = self.delay {
-            write!(f, " {}s", delay)?;
+        if let Some(delay) = &self.delay {
+            write!(f, " {}", delay)?;
         }
 
         Ok(())
     }
 }
 
-/// Z-index values
+/// Timing function values for animations and transitions
+///
+/// Timing functions describe how intermediate values are calculated during an
+/// animation or a transition.
 #[derive(Debug, Clone, PartialEq)]
-pub enum ZIndex {
-    Auto,
-    Index(i32),
+pub enum TimingFunction {
+    /// Slow start, fast middle, slow end (the default)
+    Ease,
+    /// Constant speed
+    Linear,
+    /// Slow start
+    EaseIn,
+    /// Slow end
+    EaseOut,
+    /// Slow start and slow end
+    EaseInOut,
+    /// Jumps to the final state at the start
+    StepStart,
+    /// Stays in the initial state until the end
+    StepEnd,
+    /// Custom cubic Bézier curve defined by two control points
+    CubicBezier(f32, f32, f32, f32),
+    /// Stepped progression with the given number of steps
+    Steps(u32),
     /// CSS variable
     Var(crate::variable::CssVar),
 }
 
-impl fmt::Display for ZIndex {
+impl fmt::Display for TimingFunction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ZIndex::Auto => write!(f, "auto"),
-            ZIndex::Index(val) => write!(f, "{}", val),
-            ZIndex::Var(var) => write!(f, "{}", var),
+            TimingFunction::Ease => write!(f, "ease"),
+            TimingFunction::Linear => write!(f, "linear"),
+            TimingFunction::EaseIn => write!(f, "ease-in"),
+            TimingFunction::EaseOut => write!(f, "ease-out"),
+            TimingFunction::EaseInOut => write!(f, "ease-in-out"),
+            TimingFunction::StepStart => write!(f, "step-start"),
+            TimingFunction::StepEnd => write!(f, "step-end"),
+            TimingFunction::CubicBezier(x1, y1, x2, y2) => write!(
+                f,
+                "cubic-bezier({}, {}, {}, {})",
+                Number::new(*x1),
+                Number::new(*y1),
+                Number::new(*x2),
+                Number::new(*y2)
+            ),
+            TimingFunction::Steps(steps) => write!(f, "steps({})", steps),
+            TimingFunction::Var(var) => write!(f, "{}", var),
         }
     }
 }
 
-// Implement From<CssVar> for Color to allow automatic conversion
-impl From<crate::variable::CssVar> for Color {
-    fn from(var: crate::variable::CssVar) -> Self {
-        Color::Var(var)
-    }
-}
-
-// Implement From<CssVar> for Size to allow automatic conversion
-impl From<crate::variable::CssVar> for Size {
-    fn from(var: crate::variable::CssVar) -> Self {
-        Size::Var(var)
-    }
+/// Animation iteration count values
+#[derive(Debug, Clone, PartialEq)]
+pub enum IterationCount {
+    /// The animation repeats forever
+    Infinite,
+    /// The animation plays the given number of times (fractions play part of a cycle)
+    Count(f32),
+    /// CSS variable
+    Var(crate::variable::CssVar),
 }
 
-// Implement From<CssVar> for Display to allow automatic conversion
-impl From<crate::variable::CssVar> for Display {
-    fn from(var: crate::variable::CssVar) -> Self {
-        Display::Var(var)
+impl fmt::Display for IterationCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IterationCount::Infinite => write!(f, "infinite"),
+            IterationCount::Count(count) => write!(f, "{}", Number::new(*count)),
+            IterationCount::Var(var) => write!(f, "{}", var),
+        }
     }
 }
 
-// Implement From<CssVar> for Position to allow automatic conversion
-impl From<crate::variable::CssVar> for Position {
-    fn from(var: crate::variable::CssVar) -> Self {
-        Position::Var(var)
-    }
+/// Animation direction values
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimationDirection {
+    /// The animation plays forwards each cycle
+    Normal,
+    /// The animation plays backwards each cycle
+    Reverse,
+    /// The animation reverses direction each cycle, starting forwards
+    Alternate,
+    /// The animation reverses direction each cycle, starting backwards
+    AlternateReverse,
+    /// CSS variable
+    Var(crate::variable::CssVar),
 }
 
-// Implement From<CssVar> for FlexDirection to allow automatic conversion
-impl From<crate::variable::CssVar> for FlexDirection {
-    fn from(var: crate::variable::CssVar) -> Self {
-        FlexDirection::Var(var)
+impl fmt::Display for AnimationDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimationDirection::Normal => write!(f, "normal"),
+            AnimationDirection::Reverse => write!(f, "reverse"),
+            AnimationDirection::Alternate => write!(f, "alternate"),
+            AnimationDirection::AlternateReverse => write!(f, "alternate-reverse"),
+            AnimationDirection::Var(var) => write!(f, "{}", var),
+        }
     }
 }
 
-// Implement From<CssVar> for JustifyContent to allow automatic conversion
-impl From<crate::variable::CssVar> for JustifyContent {
-    fn from(var: crate::variable::CssVar) -> Self {
-        JustifyContent::Var(var)
-    }
+/// Animation fill mode values
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimationFillMode {
+    /// No styles are applied outside of the animation's execution
+    None,
+    /// The element keeps the styles of the last keyframe after the animation ends
+    Forwards,
+    /// The element gets the styles of the first keyframe during the delay
+    Backwards,
+    /// Combines the rules of both `forwards` and `backwards`
+    Both,
+    /// CSS variable
+    Var(crate::variable::CssVar),
 }
 
-// Implement From<CssVar> for AlignItems to allow automatic conversion
-impl From<crate::variable::CssVar> for AlignItems {
-    fn from(var: crate::variable::CssVar) -> Self {
-        AlignItems::Var(var)
+impl fmt::Display for AnimationFillMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimationFillMode::None => write!(f, "none"),
+            AnimationFillMode::Forwards => write!(f, "forwards"),
+            AnimationFillMode::Backwards => write!(f, "backwards"),
+            AnimationFillMode::Both => write!(f, "both"),
+            AnimationFillMode::Var(var) => write!(f, "{}", var),
+        }
+    }
+}
+
+/// Animation play state values
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimationPlayState {
+    /// The animation is playing
+    Running,
+    /// The animation is paused
+    Paused,
+    /// CSS variable
+    Var(crate::variable::CssVar),
+}
+
+impl fmt::Display for AnimationPlayState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimationPlayState::Running => write!(f, "running"),
+            AnimationPlayState::Paused => write!(f, "paused"),
+            AnimationPlayState::Var(var) => write!(f, "{}", var),
+        }
+    }
+}
+
+/// Animation shorthand values
+///
+/// An `Animation` is created from a `Keyframes` handle, so the animation name always
+/// matches the `@keyframes` rule it refers to. Optional parts are set with the
+/// builder methods and omitted from the output when not set.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::keyframes::Keyframes;
+/// use mew_css::values::{Animation, AnimationDirection, IterationCount, Time, TimingFunction};
+///
+/// let bounce = Keyframes::new("bounce");
+/// let animation = Animation::new(&bounce, Time::S(1.5))
+///     .timing_function(TimingFunction::EaseInOut)
+///     .iteration_count(IterationCount::Infinite)
+///     .direction(AnimationDirection::Alternate);
+///
+/// assert_eq!(animation.to_string(), "bounce 1.5s ease-in-out infinite alternate");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation {
+    name: String,
+    duration: Time,
+    timing_function: Option<TimingFunction>,
+    delay: Option<Time>,
+    iteration_count: Option<IterationCount>,
+    direction: Option<AnimationDirection>,
+    fill_mode: Option<AnimationFillMode>,
+    play_state: Option<AnimationPlayState>,
+}
+
+impl Animation {
+    /// Creates a new animation running the given keyframes.
+    ///
+    /// # Arguments
+    ///
+    /// * `keyframes` - The `@keyframes` rule to run
+    /// * `duration` - The duration of one cycle
+    ///
+    /// # Returns
+    ///
+    /// A new `Animation` instance
+    pub fn new(keyframes: &crate::keyframes::Keyframes, duration: Time) -> Self {
+        Self {
+            name: keyframes.name().to_string(),
+            duration,
+            timing_function: None,
+            delay: None,
+            iteration_count: None,
+            direction: None,
+            fill_mode: None,
+            play_state: None,
+        }
+    }
+
+    /// Returns the name of the `@keyframes` rule run by this animation.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sets the timing function of the animation.
+    pub fn timing_function(mut self, value: TimingFunction) -> Self {
+        self.timing_function = Some(value);
+        self
+    }
+
+    /// Sets the delay before the animation starts.
+    pub fn delay(mut self, value: Time) -> Self {
+        self.delay = Some(value);
+        self
+    }
+
+    /// Sets the number of times the animation is played.
+    pub fn iteration_count(mut self, value: IterationCount) -> Self {
+        self.iteration_count = Some(value);
+        self
+    }
+
+    /// Sets the direction in which the animation is played.
+    pub fn direction(mut self, value: AnimationDirection) -> Self {
+        self.direction = Some(value);
+        self
+    }
+
+    /// Sets how styles are applied before and after the animation runs.
+    pub fn fill_mode(mut self, value: AnimationFillMode) -> Self {
+        self.fill_mode = Some(value);
+        self
+    }
+
+    /// Sets whether the animation is running or paused.
+    pub fn play_state(mut self, value: AnimationPlayState) -> Self {
+        self.play_state = Some(value);
+        self
+    }
+}
+
+impl fmt::Display for Animation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.duration)?;
+
+        if let Some(timing) = &self.timing_function {
+            write!(f, " {}", timing)?;
+        }
+
+        if let Some(delay) = &self.delay {
+            write!(f, " {}", delay)?;
+        }
+
+        if let Some(count) = &self.iteration_count {
+            write!(f, " {}", count)?;
+        }
+
+        if let Some(direction) = &self.direction {
+            write!(f, " {}", direction)?;
+        }
+
+        if let Some(fill_mode) = &self.fill_mode {
+            write!(f, " {}", fill_mode)?;
+        }
+
+        if let Some(play_state) = &self.play_state {
+            write!(f, " {}", play_state)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Z-index values
+#[derive(Debug, Clone, PartialEq)]
+pub enum ZIndex {
+    Auto,
+    Index(i32),
+    /// CSS variable
+    Var(crate::variable::CssVar),
+}
+
+impl fmt::Display for ZIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZIndex::Auto => write!(f, "auto"),
+            ZIndex::Index(val) => write!(f, "{}", val),
+            ZIndex::Var(var) => write!(f, "{}", var),
+        }
+    }
+}
+
+// Implement From<CssVar> for Color to allow automatic conversion
+impl From<crate::variable::CssVar> for Color {
+    fn from(var: crate::variable::CssVar) -> Self {
+        Color::Var(var)
+    }
+}
+
+// Implement From<CssVar> for Size to allow automatic conversion
+impl From<crate::variable::CssVar> for Size {
+    fn from(var: crate::variable::CssVar) -> Self {
+        Size::Var(var)
+    }
+}
+
+// Implement From<CssVar> for Angle to allow automatic conversion
+impl From<crate::variable::CssVar> for Angle {
+    fn from(var: crate::variable::CssVar) -> Self {
+        Angle::Var(var)
+    }
+}
+
+// Implement From<CssVar> for Time to allow automatic conversion
+impl From<crate::variable::CssVar> for Time {
+    fn from(var: crate::variable::CssVar) -> Self {
+        Time::Var(var)
+    }
+}
+
+// Implement From<CssVar> for Frequency to allow automatic conversion
+impl From<crate::variable::CssVar> for Frequency {
+    fn from(var: crate::variable::CssVar) -> Self {
+        Frequency::Var(var)
+    }
+}
+
+// Implement From<CssVar> for Resolution to allow automatic conversion
+impl From<crate::variable::CssVar> for Resolution {
+    fn from(var: crate::variable::CssVar) -> Self {
+        Resolution::Var(var)
+    }
+}
+
+// Implement From<CssVar> for Display to allow automatic conversion
+impl From<crate::variable::CssVar> for Display {
+    fn from(var: crate::variable::CssVar) -> Self {
+        Display::Var(var)
+    }
+}
+
+// Implement From<CssVar> for Position to allow automatic conversion
+impl From<crate::variable::CssVar> for Position {
+    fn from(var: crate::variable::CssVar) -> Self {
+        Position::Var(var)
+    }
+}
+
+// Implement From<CssVar> for FlexDirection to allow automatic conversion
+impl From<crate::variable::CssVar> for FlexDirection {
+    fn from(var: crate::variable::CssVar) -> Self {
+        FlexDirection::Var(var)
+    }
+}
+
+// Implement From<CssVar> for JustifyContent to allow automatic conversion
+impl From<crate::variable::CssVar> for JustifyContent {
+    fn from(var: crate::variable::CssVar) -> Self {
+        JustifyContent::Var(var)
+    }
+}
+
+// Implement From<CssVar> for AlignItems to allow automatic conversion
+impl From<crate::variable::CssVar> for AlignItems {
+    fn from(var: crate::variable::CssVar) -> Self {
+        AlignItems::Var(var)
     }
 }
 
@@ -1205,3 +2316,1754 @@ impl From<crate::variable::CssVar> for LineHeight {
         LineHeight::Var(var)
     }
 }
+
+// Implement From<CssVar> for TimingFunction to allow automatic conversion
+impl From<crate::variable::CssVar> for TimingFunction {
+    fn from(var: crate::variable::CssVar) -> Self {
+        TimingFunction::Var(var)
+    }
+}
+
+// Implement From<CssVar> for IterationCount to allow automatic conversion
+impl From<crate::variable::CssVar> for IterationCount {
+    fn from(var: crate::variable::CssVar) -> Self {
+        IterationCount::Var(var)
+    }
+}
+
+// Implement From<CssVar> for AnimationDirection to allow automatic conversion
+impl From<crate::variable::CssVar> for AnimationDirection {
+    fn from(var: crate::variable::CssVar) -> Self {
+        AnimationDirection::Var(var)
+    }
+}
+
+// Implement From<CssVar> for AnimationFillMode to allow automatic conversion
+impl From<crate::variable::CssVar> for AnimationFillMode {
+    fn from(var: crate::variable::CssVar) -> Self {
+        AnimationFillMode::Var(var)
+    }
+}
+
+// Implement From<CssVar> for AnimationPlayState to allow automatic conversion
+impl From<crate::variable::CssVar> for AnimationPlayState {
+    fn from(var: crate::variable::CssVar) -> Self {
+        AnimationPlayState::Var(var)
+    }
+}
+
+/// Container type values for container queries
+///
+/// The CSS container-type property establishes the element as a query container for
+/// container size queries, which can then be used by `@container` rules.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContainerType {
+    /// The element is not a query container for size queries, but remains one for style queries
+    Normal,
+    /// Establishes a query container for size queries on both the inline and block axis
+    Size,
+    /// Establishes a query container for size queries on the inline axis only
+    InlineSize,
+    /// CSS variable reference
+    Var(crate::variable::CssVar),
+}
+
+impl fmt::Display for ContainerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContainerType::Normal => write!(f, "normal"),
+            ContainerType::Size => write!(f, "size"),
+            ContainerType::InlineSize => write!(f, "inline-size"),
+            ContainerType::Var(var) => write!(f, "{}", var),
+        }
+    }
+}
+
+// Implement From<CssVar> for ContainerType to allow automatic conversion
+impl From<crate::variable::CssVar> for ContainerType {
+    fn from(var: crate::variable::CssVar) -> Self {
+        ContainerType::Var(var)
+    }
+}
+
+/// Error returned when a string cannot be parsed into one of the value types.
+///
+/// The error keeps the name of the expected type and the text that failed to parse.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::values::BorderStyle;
+///
+/// let error = "wavy".parse::<BorderStyle>().unwrap_err();
+/// assert_eq!(error.kind(), "BorderStyle");
+/// assert_eq!(error.value(), "wavy");
+/// assert_eq!(error.to_string(), "invalid BorderStyle value: \"wavy\"");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseValueError {
+    /// The name of the type that was expected
+    kind: &'static str,
+    /// The text that failed to parse
+    value: String,
+}
+
+impl ParseValueError {
+    /// Creates a new error for the given type name and offending text.
+    pub fn new(kind: &'static str, value: &str) -> Self {
+        Self {
+            kind,
+            value: value.trim().to_string(),
+        }
+    }
+
+    /// Returns the name of the type that was expected.
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// Returns the text that failed to parse.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {} value: \"{}\"", self.kind, self.value)
+    }
+}
+
+impl std::error::Error for ParseValueError {}
+
+/// Lowercases a keyword and collapses its inner whitespace, so that `"First  Baseline"`
+/// matches `"first baseline"`.
+fn normalize_keyword(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ").to_ascii_lowercase()
+}
+
+/// Splits a functional notation such as `rgb(0, 0, 0)` into its name and arguments.
+fn parse_function(s: &str) -> Option<(&str, &str)> {
+    let (name, rest) = s.trim().split_once('(')?;
+    let args = rest.strip_suffix(')')?;
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return None;
+    }
+    Some((name, args))
+}
+
+/// Parses a number followed by the given unit, such as `1.5rem`.
+fn parse_unit<T: FromStr>(s: &str, unit: &str) -> Option<T> {
+    let number = s.len().checked_sub(unit.len()).and_then(|i| {
+        let (number, suffix) = s.split_at_checked(i)?;
+        suffix.eq_ignore_ascii_case(unit).then_some(number)
+    })?;
+    if number.is_empty() || number.ends_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+/// Parses a number followed by one of the units of a table, such as `90deg` for
+/// `ANGLE_UNITS`.
+fn parse_unit_in<T>(s: &str, units: &UnitTable<T>) -> Option<T> {
+    units
+        .iter()
+        .find_map(|(unit, constructor, _)| parse_unit(s, unit).map(constructor))
+}
+
+impl FromStr for Angle {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        if let Ok(var) = value.parse::<crate::variable::CssVar>() {
+            return Ok(Angle::Var(var));
+        }
+        parse_unit_in(value, &ANGLE_UNITS).ok_or_else(|| ParseValueError::new("Angle", s))
+    }
+}
+
+impl FromStr for Time {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        if let Ok(var) = value.parse::<crate::variable::CssVar>() {
+            return Ok(Time::Var(var));
+        }
+        parse_unit_in(value, &TIME_UNITS).ok_or_else(|| ParseValueError::new("Time", s))
+    }
+}
+
+impl FromStr for Frequency {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        if let Ok(var) = value.parse::<crate::variable::CssVar>() {
+            return Ok(Frequency::Var(var));
+        }
+        parse_unit_in(value, &FREQUENCY_UNITS).ok_or_else(|| ParseValueError::new("Frequency", s))
+    }
+}
+
+impl FromStr for Resolution {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        if let Ok(var) = value.parse::<crate::variable::CssVar>() {
+            return Ok(Resolution::Var(var));
+        }
+        parse_unit_in(value, &RESOLUTION_UNITS).ok_or_else(|| ParseValueError::new("Resolution", s))
+    }
+}
+
+impl FromStr for Size {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+
+        if let Ok(var) = value.parse::<crate::variable::CssVar>() {
+            return Ok(Size::Var(var));
+        }
+
+        if value == "0" {
+            return Ok(Size::Zero);
+        }
+        if value.eq_ignore_ascii_case("auto") {
+            return Ok(Size::Auto);
+        }
+        if let Some(calc) = parse_calc(value).filter(crate::calc::Calc::is_length) {
+            return Ok(Size::from(calc));
+        }
+
+        // `parse_unit` rejects a number ending with a letter, so "rem" is never read as "em"
+        SIZE_UNITS
+            .iter()
+            .find_map(|(unit, size)| parse_unit(value, unit).map(size))
+            .ok_or_else(|| ParseValueError::new("Size", s))
+    }
+}
+
+impl Color {
+    /// Parses a CSS color into a typed `Color`.
+    ///
+    /// The following syntaxes are supported:
+    ///
+    /// - Named colors, `transparent`, `currentColor` and `inherit` (case-insensitive)
+    /// - Hex colors (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`), parsed into `Rgb` or `Rgba`
+    /// - `rgb()`/`rgba()` with legacy comma-separated or modern space-separated arguments,
+    ///   channels as numbers (0-255) or percentages, and an optional alpha
+    ///   (`/ alpha` in the modern syntax) as a number (0-1) or a percentage
+    /// - `hsl()`/`hsla()` with the same two syntaxes, and a hue in degrees
+    ///   (optionally with a `deg`, `grad`, `rad` or `turn` unit)
+    /// - `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()` and `color(srgb ...)`/
+    ///   `color(display-p3 ...)` with the modern syntax, channels as numbers or
+    ///   percentages, and `none` for a missing channel
+    /// - `var(--name)`, parsed into `Var`
+    /// - `color-mix()`, relative colors (e.g., `oklch(from red l c h)`) and `light-dark()`,
+    ///   parsed into `Mix`, `Relative` and `LightDark`; the channels of a relative color
+    ///   are kept as written
+    ///
+    /// A function with an alpha value is parsed into `Rgba` or `Hsla`, whatever its name.
+    /// Out-of-range RGB channels, HSL percentages and alpha values are clamped like CSS does
+    /// (e.g., `rgb(300, 0, 0)` is `Rgb(255, 0, 0)`). The legacy comma-separated `rgb()` syntax
+    /// requires its channels to be either all numbers or all percentages.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The CSS color
+    ///
+    /// # Returns
+    ///
+    /// The parsed color, or a `ParseValueError` if the text is not a supported color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::values::Color;
+    ///
+    /// assert_eq!(Color::parse("CornflowerBlue"), Ok(Color::CornflowerBlue));
+    /// assert_eq!(Color::parse("#ff000080"), Ok(Color::Rgba(255, 0, 0, 0.502)));
+    /// assert_eq!(Color::parse("rgb(0 128 255 / 50%)"), Ok(Color::Rgba(0, 128, 255, 0.5)));
+    /// assert_eq!(Color::parse("hsl(0.5turn, 100%, 25%)"), Ok(Color::Hsl(180, 100, 25)));
+    /// assert_eq!(Color::parse("oklch(70% 0.1 150)"), Ok(Color::Oklch(0.7, 0.1, 150.0, 1.0)));
+    /// assert!(Color::parse("rgb(0 0 0 0)").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ParseValueError> {
+        let value = s.trim();
+        let error = || ParseValueError::new("Color", s);
+
+        if let Ok(var) = value.parse::<crate::variable::CssVar>() {
+            return Ok(Color::Var(var));
+        }
+
+        if let Some(hex) = value.strip_prefix('#') {
+            return parse_hex_color(hex).ok_or_else(error);
+        }
+
+        if let Some((name, args)) = parse_function(value) {
+            let color = match name.to_ascii_lowercase().as_str() {
+                "color-mix" => parse_color_mix(args),
+                "light-dark" => parse_light_dark(args),
+                _ if split_components(args).first().is_some_and(|c| c.eq_ignore_ascii_case("from")) => {
+                    parse_relative_color(name, args)
+                }
+                _ => parse_color_function(name, args),
+            };
+            return color.ok_or_else(error);
+        }
+
+        named_color(&normalize_keyword(value)).ok_or_else(error)
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::parse(s)
+    }
+}
+
+/// Returns the color with the given lowercase name.
+fn named_color(name: &str) -> Option<Color> {
+    let color = match name {
+        "aliceblue" => Color::AliceBlue,
+        "antiquewhite" => Color::AntiqueWhite,
+        "aqua" => Color::Aqua,
+        "aquamarine" => Color::Aquamarine,
+        "azure" => Color::Azure,
+        "beige" => Color::Beige,
+        "bisque" => Color::Bisque,
+        "black" => Color::Black,
+        "blanchedalmond" => Color::BlanchedAlmond,
+        "blue" => Color::Blue,
+        "blueviolet" => Color::BlueViolet,
+        "brown" => Color::Brown,
+        "burlywood" => Color::BurlyWood,
+        "cadetblue" => Color::CadetBlue,
+        "chartreuse" => Color::Chartreuse,
+        "chocolate" => Color::Chocolate,
+        "coral" => Color::Coral,
+        "cornflowerblue" => Color::CornflowerBlue,
+        "cornsilk" => Color::Cornsilk,
+        "crimson" => Color::Crimson,
+        "cyan" => Color::Cyan,
+        "darkblue" => Color::DarkBlue,
+        "darkcyan" => Color::DarkCyan,
+        "darkgoldenrod" => Color::DarkGoldenRod,
+        "darkgray" => Color::DarkGray,
+        "darkgrey" => Color::DarkGrey,
+        "darkgreen" => Color::DarkGreen,
+        "darkkhaki" => Color::DarkKhaki,
+        "darkmagenta" => Color::DarkMagenta,
+        "darkolivegreen" => Color::DarkOliveGreen,
+        "darkorange" => Color::DarkOrange,
+        "darkorchid" => Color::DarkOrchid,
+        "darkred" => Color::DarkRed,
+        "darksalmon" => Color::DarkSalmon,
+        "darkseagreen" => Color::DarkSeaGreen,
+        "darkslateblue" => Color::DarkSlateBlue,
+        "darkslategray" => Color::DarkSlateGray,
+        "darkslategrey" => Color::DarkSlateGrey,
+        "darkturquoise" => Color::DarkTurquoise,
+        "darkviolet" => Color::DarkViolet,
+        "deeppink" => Color::DeepPink,
+        "deepskyblue" => Color::DeepSkyBlue,
+        "dimgray" => Color::DimGray,
+        "dimgrey" => Color::DimGrey,
+        "dodgerblue" => Color::DodgerBlue,
+        "firebrick" => Color::FireBrick,
+        "floralwhite" => Color::FloralWhite,
+        "forestgreen" => Color::ForestGreen,
+        "fuchsia" => Color::Fuchsia,
+        "gainsboro" => Color::Gainsboro,
+        "ghostwhite" => Color::GhostWhite,
+        "gold" => Color::Gold,
+        "goldenrod" => Color::GoldenRod,
+        "gray" => Color::Gray,
+        "grey" => Color::Grey,
+        "green" => Color::Green,
+        "greenyellow" => Color::GreenYellow,
+        "honeydew" => Color::HoneyDew,
+        "hotpink" => Color::HotPink,
+        "indianred" => Color::IndianRed,
+        "indigo" => Color::Indigo,
+        "ivory" => Color::Ivory,
+        "khaki" => Color::Khaki,
+        "lavender" => Color::Lavender,
+        "lavenderblush" => Color::LavenderBlush,
+        "lawngreen" => Color::LawnGreen,
+        "lemonchiffon" => Color::LemonChiffon,
+        "lightblue" => Color::LightBlue,
+        "lightcoral" => Color::LightCoral,
+        "lightcyan" => Color::LightCyan,
+        "lightgoldenrodyellow" => Color::LightGoldenRodYellow,
+        "lightgray" => Color::LightGray,
+        "lightgrey" => Color::LightGrey,
+        "lightgreen" => Color::LightGreen,
+        "lightpink" => Color::LightPink,
+        "lightsalmon" => Color::LightSalmon,
+        "lightseagreen" => Color::LightSeaGreen,
+        "lightskyblue" => Color::LightSkyBlue,
+        "lightslategray" => Color::LightSlateGray,
+        "lightslategrey" => Color::LightSlateGrey,
+        "lightsteelblue" => Color::LightSteelBlue,
+        "lightyellow" => Color::LightYellow,
+        "lime" => Color::Lime,
+        "limegreen" => Color::LimeGreen,
+        "linen" => Color::Linen,
+        "magenta" => Color::Magenta,
+        "maroon" => Color::Maroon,
+        "mediumaquamarine" => Color::MediumAquaMarine,
+        "mediumblue" => Color::MediumBlue,
+        "mediumorchid" => Color::MediumOrchid,
+        "mediumpurple" => Color::MediumPurple,
+        "mediumseagreen" => Color::MediumSeaGreen,
+        "mediumslateblue" => Color::MediumSlateBlue,
+        "mediumspringgreen" => Color::MediumSpringGreen,
+        "mediumturquoise" => Color::MediumTurquoise,
+        "mediumvioletred" => Color::MediumVioletRed,
+        "midnightblue" => Color::MidnightBlue,
+        "mintcream" => Color::MintCream,
+        "mistyrose" => Color::MistyRose,
+        "moccasin" => Color::Moccasin,
+        "navajowhite" => Color::NavajoWhite,
+        "navy" => Color::Navy,
+        "oldlace" => Color::OldLace,
+        "olive" => Color::Olive,
+        "olivedrab" => Color::OliveDrab,
+        "orange" => Color::Orange,
+        "orangered" => Color::OrangeRed,
+        "orchid" => Color::Orchid,
+        "palegoldenrod" => Color::PaleGoldenRod,
+        "palegreen" => Color::PaleGreen,
+        "paleturquoise" => Color::PaleTurquoise,
+        "palevioletred" => Color::PaleVioletRed,
+        "papayawhip" => Color::PapayaWhip,
+        "peachpuff" => Color::PeachPuff,
+        "peru" => Color::Peru,
+        "pink" => Color::Pink,
+        "plum" => Color::Plum,
+        "powderblue" => Color::PowderBlue,
+        "purple" => Color::Purple,
+        "rebeccapurple" => Color::RebeccaPurple,
+        "red" => Color::Red,
+        "rosybrown" => Color::RosyBrown,
+        "royalblue" => Color::RoyalBlue,
+        "saddlebrown" => Color::SaddleBrown,
+        "salmon" => Color::Salmon,
+        "sandybrown" => Color::SandyBrown,
+        "seagreen" => Color::SeaGreen,
+        "seashell" => Color::SeaShell,
+        "sienna" => Color::Sienna,
+        "silver" => Color::Silver,
+        "skyblue" => Color::SkyBlue,
+        "slateblue" => Color::SlateBlue,
+        "slategray" => Color::SlateGray,
+        "slategrey" => Color::SlateGrey,
+        "snow" => Color::Snow,
+        "springgreen" => Color::SpringGreen,
+        "steelblue" => Color::SteelBlue,
+        "tan" => Color::Tan,
+        "teal" => Color::Teal,
+        "thistle" => Color::Thistle,
+        "tomato" => Color::Tomato,
+        "turquoise" => Color::Turquoise,
+        "violet" => Color::Violet,
+        "wheat" => Color::Wheat,
+        "white" => Color::White,
+        "whitesmoke" => Color::WhiteSmoke,
+        "yellow" => Color::Yellow,
+        "yellowgreen" => Color::YellowGreen,
+        "transparent" => Color::Transparent,
+        "currentcolor" => Color::CurrentColor,
+        "inherit" => Color::Inherit,
+        _ => return None,
+    };
+    Some(color)
+}
+
+/// Parses the digits of a hex color (without the `#`) into `Rgb` or `Rgba`.
+fn parse_hex_color(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    // Short forms repeat each digit ("f" is "ff")
+    let digits: Vec<u8> = match hex.len() {
+        3 | 4 => hex.chars().map(|c| c.to_digit(16).map(|d| (d * 17) as u8)).collect::<Option<_>>()?,
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<_>>()?,
+        _ => return None,
+    };
+
+    match digits.as_slice() {
+        [r, g, b] => Some(Color::Rgb(*r, *g, *b)),
+        // Three decimals are enough to tell apart the 256 alpha values
+        [r, g, b, a] => Some(Color::Rgba(*r, *g, *b, (*a as f32 / 255.0 * 1000.0).round() / 1000.0)),
+        _ => None,
+    }
+}
+
+/// Splits the arguments of a function on the commas that are not nested in parentheses.
+fn split_arguments(args: &str) -> Vec<&str> {
+    let mut arguments = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, c) in args.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                arguments.push(args[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    arguments.push(args[start..].trim());
+    arguments
+}
+
+/// Parses the arguments of a `color-mix()` function:
+/// `in <space> [<hue interpolation>], <color> [<p>%], <color> [<p>%]`.
+fn parse_color_mix(args: &str) -> Option<Color> {
+    let [space, first, second] = split_arguments(args)[..] else {
+        return None;
+    };
+
+    let (space, hue) = match split_components(space)[..] {
+        [keyword, space] if keyword.eq_ignore_ascii_case("in") => (space, None),
+        [keyword, space, method, hue] if keyword.eq_ignore_ascii_case("in") => {
+            (space, Some(format!("{} {}", method, hue).parse().ok()?))
+        }
+        _ => return None,
+    };
+
+    // A color optionally followed by its percentage
+    let operand = |s: &str| -> Option<(Color, Option<f32>)> {
+        match split_components(s)[..] {
+            [color] => Some((Color::parse(color).ok()?, None)),
+            [color, percentage] => {
+                let percentage = parse_unit(percentage, "%").filter(|p| (0.0..=100.0).contains(p))?;
+                Some((Color::parse(color).ok()?, Some(percentage)))
+            }
+            _ => None,
+        }
+    };
+    let (first, first_percentage) = operand(first)?;
+    let (second, second_percentage) = operand(second)?;
+
+    let mix = crate::color::ColorMix {
+        space: space.parse().ok()?,
+        hue,
+        first,
+        first_percentage,
+        second,
+        second_percentage,
+    };
+    // A hue interpolation method is only allowed in polar spaces
+    mix.validate().is_ok().then(|| Color::Mix(Box::new(mix)))
+}
+
+/// Parses the arguments of a `light-dark()` function: `<color>, <color>`.
+fn parse_light_dark(args: &str) -> Option<Color> {
+    match split_arguments(args)[..] {
+        [light, dark] => Some(Color::light_dark(Color::parse(light).ok()?, Color::parse(dark).ok()?)),
+        _ => None,
+    }
+}
+
+/// Parses a relative color such as `oklch(from red l c h / 0.5)`, keeping the
+/// expressions of the channels as written.
+fn parse_relative_color(name: &str, args: &str) -> Option<Color> {
+    use crate::color::{ColorSpace, RelativeColor};
+
+    let components = split_components(args);
+    let (origin, rest) = match components.as_slice() {
+        [_from, origin, rest @ ..] => (Color::parse(origin).ok()?, rest),
+        _ => return None,
+    };
+
+    // `color()` takes the color space as its first argument
+    let (space, rest) = match name.to_ascii_lowercase().as_str() {
+        "rgb" | "rgba" => (ColorSpace::Srgb, rest),
+        "hsl" | "hsla" => (ColorSpace::Hsl, rest),
+        "hwb" | "lab" | "lch" | "oklab" | "oklch" => (name.parse().ok()?, rest),
+        "color" => {
+            let (space, rest) = rest.split_first()?;
+            match space.parse().ok()? {
+                space @ (ColorSpace::SrgbLinear | ColorSpace::DisplayP3 | ColorSpace::Xyz) => (space, rest),
+                // `RelativeColor` writes the other spaces with their own functions, whose
+                // channels have other ranges (e.g., 0 to 255 in `rgb()`)
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    let relative = match rest {
+        [c1, c2, c3] => RelativeColor::new(space, origin, [c1, c2, c3]),
+        [c1, c2, c3, "/", alpha] => RelativeColor::new(space, origin, [c1, c2, c3]).alpha(alpha),
+        _ => return None,
+    };
+    // Reject the expressions referring to channels of another space
+    relative.validate().is_ok().then(|| relative.into())
+}
+
+/// Parses the arguments of a color function such as `rgb()`, `hsl()` or `oklch()`.
+fn parse_color_function(name: &str, args: &str) -> Option<Color> {
+    let legacy = args.contains(',');
+
+    // Legacy syntax: "r, g, b[, a]"; modern syntax: "r g b[ / a]"
+    let (channels, alpha): (Vec<&str>, Option<&str>) = if legacy {
+        let mut parts: Vec<&str> = args.split(',').map(str::trim).collect();
+        let alpha = if parts.len() == 4 { parts.pop() } else { None };
+        (parts, alpha)
+    } else {
+        let (channels, alpha) = match args.split_once('/') {
+            Some((channels, alpha)) => (channels, Some(alpha.trim())),
+            None => (args, None),
+        };
+        (channels.split_whitespace().collect(), alpha)
+    };
+
+    let alpha = match alpha {
+        Some(alpha) => Some(parse_alpha(alpha)?),
+        None => None,
+    };
+
+    match (name.to_ascii_lowercase().as_str(), channels.as_slice()) {
+        // The legacy syntax does not mix numbers and percentages
+        ("rgb" | "rgba", [r, g, b]) if legacy && ![g, b].iter().all(|c| c.ends_with('%') == r.ends_with('%')) => {
+            None
+        }
+        ("rgb" | "rgba", [r, g, b]) => {
+            let (r, g, b) = (parse_rgb_channel(r)?, parse_rgb_channel(g)?, parse_rgb_channel(b)?);
+            Some(match alpha {
+                Some(a) => Color::Rgba(r, g, b, a),
+                None => Color::Rgb(r, g, b),
+            })
+        }
+        ("hsl" | "hsla", [h, s, l]) => {
+            let h = parse_hue(h)?;
+            let (s, l) = (parse_hsl_percent(s, legacy)?, parse_hsl_percent(l, legacy)?);
+            Some(match alpha {
+                Some(a) => Color::Hsla(h, s, l, a),
+                None => Color::Hsl(h, s, l),
+            })
+        }
+        // The other color functions only have the modern syntax
+        _ if legacy => None,
+        (name, channels) => {
+            let a = alpha.unwrap_or(1.0);
+            match (name, channels) {
+                ("hwb", [h, w, b]) => Some(Color::Hwb(parse_angle(h)?, parse_channel(w, 100.0)?, parse_channel(b, 100.0)?, a)),
+                ("lab", [l, x, y]) => Some(Color::Lab(parse_channel(l, 100.0)?, parse_channel(x, 125.0)?, parse_channel(y, 125.0)?, a)),
+                ("lch", [l, c, h]) => Some(Color::Lch(parse_channel(l, 100.0)?, parse_channel(c, 150.0)?, parse_angle(h)?, a)),
+                ("oklab", [l, x, y]) => Some(Color::Oklab(parse_channel(l, 1.0)?, parse_channel(x, 0.4)?, parse_channel(y, 0.4)?, a)),
+                ("oklch", [l, c, h]) => Some(Color::Oklch(parse_channel(l, 1.0)?, parse_channel(c, 0.4)?, parse_angle(h)?, a)),
+                ("color", [space, r, g, b]) => {
+                    let (r, g, b) = (parse_channel(r, 1.0)?, parse_channel(g, 1.0)?, parse_channel(b, 1.0)?);
+                    match space.to_ascii_lowercase().as_str() {
+                        "srgb" => Some(Color::Srgb(r, g, b, a)),
+                        "display-p3" => Some(Color::DisplayP3(r, g, b, a)),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Parses a channel of the modern color functions, either a number or a percentage of
+/// `percent_reference` (e.g., `100%` is `0.4` for the chroma of Oklch). The `none`
+/// keyword is read as zero.
+fn parse_channel(s: &str, percent_reference: f32) -> Option<f32> {
+    if s.eq_ignore_ascii_case("none") {
+        return Some(0.0);
+    }
+    match s.strip_suffix('%') {
+        Some(percent) => parse_number(percent).map(|p| p / 100.0 * percent_reference),
+        None => parse_number(s),
+    }
+}
+
+/// Parses a number, rejecting the values that `f32` accepts but CSS does not (`inf`, `NaN`).
+fn parse_number(s: &str) -> Option<f32> {
+    s.parse::<f32>().ok().filter(|n| n.is_finite())
+}
+
+/// Parses an RGB channel, either a number or a percentage, clamped from 0 to 255.
+fn parse_rgb_channel(s: &str) -> Option<u8> {
+    let value = match s.strip_suffix('%') {
+        Some(percent) => parse_number(percent)? * 2.55,
+        None => parse_number(s)?,
+    };
+    Some(value.clamp(0.0, 255.0).round() as u8)
+}
+
+/// Parses an alpha value, either a number or a percentage, clamped from 0 to 1.
+fn parse_alpha(s: &str) -> Option<f32> {
+    let alpha = match s.strip_suffix('%') {
+        Some(percent) => parse_number(percent)? / 100.0,
+        None => parse_number(s)?,
+    };
+    Some(alpha.clamp(0.0, 1.0))
+}
+
+/// Parses a hue into whole degrees between 0 and 359.
+fn parse_hue(s: &str) -> Option<u16> {
+    Some((parse_angle(s)?.round().rem_euclid(360.0)) as u16)
+}
+
+/// Parses an angle in degrees, optionally with a `deg`, `grad`, `rad` or `turn` unit.
+/// The `none` keyword is read as zero.
+fn parse_angle(s: &str) -> Option<f32> {
+    let lower = s.to_ascii_lowercase();
+    if lower == "none" {
+        return Some(0.0);
+    }
+    let degrees = if let Some(deg) = lower.strip_suffix("deg") {
+        parse_number(deg)?
+    } else if let Some(grad) = lower.strip_suffix("grad") {
+        parse_number(grad)? * 0.9
+    } else if let Some(rad) = lower.strip_suffix("rad") {
+        parse_number(rad)?.to_degrees()
+    } else if let Some(turn) = lower.strip_suffix("turn") {
+        parse_number(turn)? * 360.0
+    } else {
+        parse_number(&lower)?
+    };
+    Some(degrees)
+}
+
+/// Parses the saturation or lightness of an HSL color, clamped from 0 to 100. The legacy
+/// syntax requires a percentage, while the modern syntax also accepts a plain number.
+fn parse_hsl_percent(s: &str, legacy: bool) -> Option<u8> {
+    let value = match s.strip_suffix('%') {
+        Some(percent) => percent,
+        None if !legacy => s,
+        None => return None,
+    };
+    parse_number(value).map(|p| p.clamp(0.0, 100.0).round() as u8)
+}
+
+impl FromStr for AlignContent {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(AlignContent::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "normal" => Ok(AlignContent::Normal),
+            "start" => Ok(AlignContent::Start),
+            "center" => Ok(AlignContent::Center),
+            "end" => Ok(AlignContent::End),
+            "flex-start" => Ok(AlignContent::FlexStart),
+            "flex-end" => Ok(AlignContent::FlexEnd),
+            "baseline" => Ok(AlignContent::Baseline),
+            "first baseline" => Ok(AlignContent::FirstBaseline),
+            "last baseline" => Ok(AlignContent::LastBaseline),
+            "space-between" => Ok(AlignContent::SpaceBetween),
+            "space-around" => Ok(AlignContent::SpaceAround),
+            "space-evenly" => Ok(AlignContent::SpaceEvenly),
+            "stretch" => Ok(AlignContent::Stretch),
+            "safe center" => Ok(AlignContent::SafeCenter),
+            "unsafe center" => Ok(AlignContent::UnsafeCenter),
+            "inherit" => Ok(AlignContent::Inherit),
+            "initial" => Ok(AlignContent::Initial),
+            "revert" => Ok(AlignContent::Revert),
+            "revert-layer" => Ok(AlignContent::RevertLayer),
+            "unset" => Ok(AlignContent::Unset),
+            _ => Err(ParseValueError::new("AlignContent", s)),
+        }
+    }
+}
+
+impl FromStr for AlignItems {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(AlignItems::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "normal" => Ok(AlignItems::Normal),
+            "center" => Ok(AlignItems::Center),
+            "start" => Ok(AlignItems::Start),
+            "end" => Ok(AlignItems::End),
+            "flex-start" => Ok(AlignItems::FlexStart),
+            "flex-end" => Ok(AlignItems::FlexEnd),
+            "self-start" => Ok(AlignItems::SelfStart),
+            "self-end" => Ok(AlignItems::SelfEnd),
+            "baseline" => Ok(AlignItems::Baseline),
+            "first baseline" => Ok(AlignItems::FirstBaseline),
+            "last baseline" => Ok(AlignItems::LastBaseline),
+            "stretch" => Ok(AlignItems::Stretch),
+            "anchor-center" => Ok(AlignItems::AnchorCenter),
+            "safe center" => Ok(AlignItems::SafeCenter),
+            "unsafe center" => Ok(AlignItems::UnsafeCenter),
+            "inherit" => Ok(AlignItems::Inherit),
+            "initial" => Ok(AlignItems::Initial),
+            "revert" => Ok(AlignItems::Revert),
+            "revert-layer" => Ok(AlignItems::RevertLayer),
+            "unset" => Ok(AlignItems::Unset),
+            _ => Err(ParseValueError::new("AlignItems", s)),
+        }
+    }
+}
+
+impl FromStr for BorderStyle {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(BorderStyle::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "none" => Ok(BorderStyle::None),
+            "solid" => Ok(BorderStyle::Solid),
+            "dashed" => Ok(BorderStyle::Dashed),
+            "dotted" => Ok(BorderStyle::Dotted),
+            "double" => Ok(BorderStyle::Double),
+            "groove" => Ok(BorderStyle::Groove),
+            "ridge" => Ok(BorderStyle::Ridge),
+            "inset" => Ok(BorderStyle::Inset),
+            "outset" => Ok(BorderStyle::Outset),
+            _ => Err(ParseValueError::new("BorderStyle", s)),
+        }
+    }
+}
+
+impl FromStr for ContainerType {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(ContainerType::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "normal" => Ok(ContainerType::Normal),
+            "size" => Ok(ContainerType::Size),
+            "inline-size" => Ok(ContainerType::InlineSize),
+            _ => Err(ParseValueError::new("ContainerType", s)),
+        }
+    }
+}
+
+impl FromStr for Display {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(Display::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "none" => Ok(Display::None),
+            "block" => Ok(Display::Block),
+            "inline" => Ok(Display::Inline),
+            "inline-block" => Ok(Display::InlineBlock),
+            "flex" => Ok(Display::Flex),
+            "grid" => Ok(Display::Grid),
+            "table" => Ok(Display::Table),
+            _ => Err(ParseValueError::new("Display", s)),
+        }
+    }
+}
+
+
+impl FromStr for Position {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(Position::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "static" => Ok(Position::Static),
+            "relative" => Ok(Position::Relative),
+            "absolute" => Ok(Position::Absolute),
+            "fixed" => Ok(Position::Fixed),
+            "sticky" => Ok(Position::Sticky),
+            _ => Err(ParseValueError::new("Position", s)),
+        }
+    }
+}
+
+impl FromStr for FlexDirection {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(FlexDirection::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "row" => Ok(FlexDirection::Row),
+            "row-reverse" => Ok(FlexDirection::RowReverse),
+            "column" => Ok(FlexDirection::Column),
+            "column-reverse" => Ok(FlexDirection::ColumnReverse),
+            _ => Err(ParseValueError::new("FlexDirection", s)),
+        }
+    }
+}
+
+impl FromStr for JustifyContent {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(JustifyContent::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "flex-start" => Ok(JustifyContent::FlexStart),
+            "flex-end" => Ok(JustifyContent::FlexEnd),
+            "center" => Ok(JustifyContent::Center),
+            "space-between" => Ok(JustifyContent::SpaceBetween),
+            "space-around" => Ok(JustifyContent::SpaceAround),
+            "space-evenly" => Ok(JustifyContent::SpaceEvenly),
+            _ => Err(ParseValueError::new("JustifyContent", s)),
+        }
+    }
+}
+
+impl FromStr for TextAlign {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(TextAlign::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "left" => Ok(TextAlign::Left),
+            "right" => Ok(TextAlign::Right),
+            "center" => Ok(TextAlign::Center),
+            "justify" => Ok(TextAlign::Justify),
+            "start" => Ok(TextAlign::Start),
+            "end" => Ok(TextAlign::End),
+            _ => Err(ParseValueError::new("TextAlign", s)),
+        }
+    }
+}
+
+impl FromStr for TextDecoration {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(TextDecoration::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "none" => Ok(TextDecoration::None),
+            "underline" => Ok(TextDecoration::Underline),
+            "overline" => Ok(TextDecoration::Overline),
+            "line-through" => Ok(TextDecoration::LineThrough),
+            _ => Err(ParseValueError::new("TextDecoration", s)),
+        }
+    }
+}
+
+impl FromStr for Overflow {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(Overflow::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "visible" => Ok(Overflow::Visible),
+            "hidden" => Ok(Overflow::Hidden),
+            "scroll" => Ok(Overflow::Scroll),
+            "auto" => Ok(Overflow::Auto),
+            "clip" => Ok(Overflow::Clip),
+            _ => Err(ParseValueError::new("Overflow", s)),
+        }
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(Cursor::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "default" => Ok(Cursor::Default),
+            "pointer" => Ok(Cursor::Pointer),
+            "text" => Ok(Cursor::Text),
+            "not-allowed" => Ok(Cursor::NotAllowed),
+            "wait" => Ok(Cursor::Wait),
+            "move" => Ok(Cursor::Move),
+            "grab" => Ok(Cursor::Grab),
+            "zoom-in" => Ok(Cursor::ZoomIn),
+            "zoom-out" => Ok(Cursor::ZoomOut),
+            _ => Err(ParseValueError::new("Cursor", s)),
+        }
+    }
+}
+
+impl FromStr for Visibility {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(Visibility::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "visible" => Ok(Visibility::Visible),
+            "hidden" => Ok(Visibility::Hidden),
+            "collapse" => Ok(Visibility::Collapse),
+            _ => Err(ParseValueError::new("Visibility", s)),
+        }
+    }
+}
+
+impl FromStr for AnimationDirection {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(AnimationDirection::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "normal" => Ok(AnimationDirection::Normal),
+            "reverse" => Ok(AnimationDirection::Reverse),
+            "alternate" => Ok(AnimationDirection::Alternate),
+            "alternate-reverse" => Ok(AnimationDirection::AlternateReverse),
+            _ => Err(ParseValueError::new("AnimationDirection", s)),
+        }
+    }
+}
+
+impl FromStr for AnimationFillMode {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(AnimationFillMode::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "none" => Ok(AnimationFillMode::None),
+            "forwards" => Ok(AnimationFillMode::Forwards),
+            "backwards" => Ok(AnimationFillMode::Backwards),
+            "both" => Ok(AnimationFillMode::Both),
+            _ => Err(ParseValueError::new("AnimationFillMode", s)),
+        }
+    }
+}
+
+impl FromStr for AnimationPlayState {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(AnimationPlayState::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "running" => Ok(AnimationPlayState::Running),
+            "paused" => Ok(AnimationPlayState::Paused),
+            _ => Err(ParseValueError::new("AnimationPlayState", s)),
+        }
+    }
+}
+
+/// Splits a value into its top-level space-separated components, keeping
+/// functions such as `rgb(0, 0, 0)` together.
+pub(crate) fn split_components(value: &str) -> Vec<&str> {
+    let mut components = Vec::new();
+    let mut depth = 0usize;
+    let mut start = None;
+
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(s) = start.take() {
+                    components.push(&value[s..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+
+    if let Some(s) = start {
+        components.push(&value[s..]);
+    }
+    components
+}
+
+/// Parses a `calc()`, `min()`, `max()` or `clamp()` function into a typed expression.
+fn parse_calc(s: &str) -> Option<crate::calc::Calc> {
+    parse_function(s)?;
+    s.parse().ok()
+}
+
+impl FromStr for FontWeight {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(FontWeight::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "normal" => Ok(FontWeight::Normal),
+            "bold" => Ok(FontWeight::Bold),
+            "bolder" => Ok(FontWeight::Bolder),
+            "lighter" => Ok(FontWeight::Lighter),
+            // Only the weights that are rendered as written (1 to 1000)
+            weight => match weight.parse::<u16>() {
+                Ok(w) if (1..=1000).contains(&w) => Ok(FontWeight::Weight(w)),
+                _ => Err(ParseValueError::new("FontWeight", s)),
+            },
+        }
+    }
+}
+
+impl FromStr for FontSize {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+
+        if let Ok(var) = value.parse::<crate::variable::CssVar>() {
+            return Ok(FontSize::Var(var));
+        }
+        // A font size is a length, not a number
+        if let Some(calc) = parse_calc(value).filter(crate::calc::Calc::is_length) {
+            return Ok(FontSize::Calc(calc));
+        }
+
+        // Longer units first, so that "rem" is not read as "em"
+        if let Some(val) = parse_unit(value, "px") {
+            return Ok(FontSize::Px(val));
+        } else if let Some(val) = parse_unit(value, "%") {
+            return Ok(FontSize::Percent(val));
+        } else if let Some(val) = parse_unit(value, "rem") {
+            return Ok(FontSize::Rem(val));
+        } else if let Some(val) = parse_unit(value, "em") {
+            return Ok(FontSize::Em(val));
+        }
+
+        match normalize_keyword(value).as_str() {
+            "smaller" => Ok(FontSize::Smaller),
+            "larger" => Ok(FontSize::Larger),
+            "xx-small" => Ok(FontSize::XxSmall),
+            "x-small" => Ok(FontSize::XSmall),
+            "small" => Ok(FontSize::Small),
+            "medium" => Ok(FontSize::Medium),
+            "large" => Ok(FontSize::Large),
+            "x-large" => Ok(FontSize::XLarge),
+            "xx-large" => Ok(FontSize::XxLarge),
+            _ => Err(ParseValueError::new("FontSize", s)),
+        }
+    }
+}
+
+impl FromStr for LineHeight {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+
+        if let Ok(var) = value.parse::<crate::variable::CssVar>() {
+            return Ok(LineHeight::Var(var));
+        }
+        // Line heights are numbers or lengths, but not angles
+        if let Some(calc) = parse_calc(value).filter(|calc| calc.kind() != Some(crate::calc::Kind::Angle)) {
+            return Ok(LineHeight::Calc(calc));
+        }
+        if value.eq_ignore_ascii_case("normal") {
+            return Ok(LineHeight::Normal);
+        }
+
+        // Unitless numbers and percentages take precedence over the equivalent lengths
+        // ("0" and "150%" are also valid sizes)
+        if let Ok(val) = value.parse::<f32>() {
+            return Ok(LineHeight::Number(val));
+        }
+        if let Some(val) = parse_unit(value, "%") {
+            return Ok(LineHeight::Percent(val));
+        }
+
+        match value.parse::<Size>() {
+            Ok(Size::Auto) | Err(_) => Err(ParseValueError::new("LineHeight", s)),
+            Ok(size) => Ok(LineHeight::Length(size)),
+        }
+    }
+}
+
+impl FromStr for BoxShadow {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseValueError::new("BoxShadow", s);
+
+        let mut components = split_components(s);
+        let inset = components.first().is_some_and(|c| c.eq_ignore_ascii_case("inset"));
+        if inset {
+            components.remove(0);
+        }
+
+        // The color is always written last
+        let color = match components.last() {
+            Some(last) if components.len() > 2 && last.parse::<Size>().is_err() => {
+                let color = last.parse::<Color>().map_err(|_| error())?;
+                components.pop();
+                Some(color)
+            }
+            _ => None,
+        };
+
+        let sizes = components
+            .iter()
+            .map(|c| c.parse::<Size>().map_err(|_| error()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match sizes.as_slice() {
+            [h_offset, v_offset, rest @ ..] if rest.len() <= 2 => Ok(BoxShadow {
+                h_offset: h_offset.clone(),
+                v_offset: v_offset.clone(),
+                blur: rest.first().cloned(),
+                spread: rest.get(1).cloned(),
+                color,
+                inset,
+            }),
+            _ => Err(error()),
+        }
+    }
+}
+
+impl FromStr for Transition {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseValueError::new("Transition", s);
+
+        let components = split_components(s);
+        let (property, duration, rest) = match components.as_slice() {
+            [property, duration, rest @ ..] if rest.len() <= 2 => {
+                (property, duration.parse::<Time>().map_err(|_| error())?, rest)
+            }
+            _ => return Err(error()),
+        };
+
+        // A single trailing time is the delay, as no timing function is written in that case
+        // (a single trailing variable is taken as the timing function)
+        let (timing_function, delay) = match rest {
+            [] => (None, None),
+            [last] => match parse_unit_in(last, &TIME_UNITS) {
+                Some(delay) => (None, Some(delay)),
+                None => (Some(*last), None),
+            },
+            [timing, delay] => (Some(*timing), Some(delay.parse::<Time>().map_err(|_| error())?)),
+            _ => return Err(error()),
+        };
+
+        if let Some(timing) = timing_function {
+            timing.parse::<TimingFunction>().map_err(|_| error())?;
+        }
+
+        Ok(Transition {
+            property: property.to_string(),
+            duration,
+            timing_function: timing_function.map(str::to_string),
+            delay,
+        })
+    }
+}
+
+impl FromStr for TimingFunction {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseValueError::new("TimingFunction", s);
+
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(TimingFunction::Var(var));
+        }
+
+        if let Some((name, args)) = parse_function(s) {
+            let args: Vec<&str> = args.split(',').map(str::trim).collect();
+            let number = |arg: &str| arg.parse::<f32>().map_err(|_| error());
+
+            return match (name.to_ascii_lowercase().as_str(), args.as_slice()) {
+                ("cubic-bezier", [x1, y1, x2, y2]) => {
+                    Ok(TimingFunction::CubicBezier(number(x1)?, number(y1)?, number(x2)?, number(y2)?))
+                }
+                ("steps", [steps]) => steps.parse().map(TimingFunction::Steps).map_err(|_| error()),
+                _ => Err(error()),
+            };
+        }
+
+        match normalize_keyword(s).as_str() {
+            "ease" => Ok(TimingFunction::Ease),
+            "linear" => Ok(TimingFunction::Linear),
+            "ease-in" => Ok(TimingFunction::EaseIn),
+            "ease-out" => Ok(TimingFunction::EaseOut),
+            "ease-in-out" => Ok(TimingFunction::EaseInOut),
+            "step-start" => Ok(TimingFunction::StepStart),
+            "step-end" => Ok(TimingFunction::StepEnd),
+            _ => Err(error()),
+        }
+    }
+}
+
+impl FromStr for IterationCount {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(IterationCount::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "infinite" => Ok(IterationCount::Infinite),
+            count => match count.parse::<f32>() {
+                Ok(count) if count >= 0.0 => Ok(IterationCount::Count(count)),
+                _ => Err(ParseValueError::new("IterationCount", s)),
+            },
+        }
+    }
+}
+
+impl FromStr for Animation {
+    type Err = ParseValueError;
+
+    /// Parses an animation written as `name duration`, followed by the optional parts in
+    /// the order of the `Display` output. The keyframes are referenced by name only.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseValueError::new("Animation", s);
+
+        let components = split_components(s);
+        let (name, duration, mut rest) = match components.as_slice() {
+            [name, duration, rest @ ..] => (name, duration.parse::<Time>().map_err(|_| error())?, rest),
+            _ => return Err(error()),
+        };
+
+        // Each optional part is taken when the next component matches it
+        fn take<T>(rest: &mut &[&str], parse: impl Fn(&str) -> Option<T>) -> Option<T> {
+            let value = parse(rest.first()?)?;
+            *rest = &rest[1..];
+            Some(value)
+        }
+
+        let animation = Animation {
+            name: name.to_string(),
+            duration,
+            timing_function: take(&mut rest, |c| c.parse().ok()),
+            delay: take(&mut rest, |c| c.parse().ok()),
+            iteration_count: take(&mut rest, |c| c.parse().ok()),
+            direction: take(&mut rest, |c| c.parse().ok()),
+            fill_mode: take(&mut rest, |c| c.parse().ok()),
+            play_state: take(&mut rest, |c| c.parse().ok()),
+        };
+
+        if rest.is_empty() { Ok(animation) } else { Err(error()) }
+    }
+}
+
+impl FromStr for ZIndex {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<crate::variable::CssVar>() {
+            return Ok(ZIndex::Var(var));
+        }
+
+        match normalize_keyword(s).as_str() {
+            "auto" => Ok(ZIndex::Auto),
+            index => index.parse().map(ZIndex::Index).map_err(|_| ParseValueError::new("ZIndex", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variable::CssVar;
+
+    /// Asserts that the value is parsed back from its own `Display` output.
+    fn assert_round_trip<T: FromStr<Err = ParseValueError> + fmt::Display + PartialEq + fmt::Debug>(value: T) {
+        assert_eq!(value.to_string().parse::<T>(), Ok(value));
+    }
+
+    #[test]
+    fn test_keyword_round_trips() {
+        assert_round_trip(Position::Sticky);
+        assert_round_trip(FlexDirection::ColumnReverse);
+        assert_round_trip(JustifyContent::SpaceEvenly);
+        assert_round_trip(TextAlign::End);
+        assert_round_trip(TextDecoration::LineThrough);
+        assert_round_trip(Overflow::Clip);
+        assert_round_trip(Cursor::NotAllowed);
+        assert_round_trip(Visibility::Collapse);
+        assert_round_trip(AnimationDirection::AlternateReverse);
+        assert_round_trip(AnimationFillMode::Backwards);
+        assert_round_trip(AnimationPlayState::Paused);
+        assert_round_trip(AlignContent::SafeCenter);
+        assert_round_trip(Cursor::Var(CssVar::new("cursor")));
+
+        assert_eq!(" Space-Between ".parse::<JustifyContent>(), Ok(JustifyContent::SpaceBetween));
+        assert_eq!(
+            "zoom".parse::<Cursor>(),
+            Err(ParseValueError::new("Cursor", "zoom"))
+        );
+    }
+
+    #[test]
+    fn test_size_and_color() {
+        assert_eq!("12px".parse::<Size>(), Ok(Size::Px(12.0)));
+        assert_eq!("1.5rem".parse::<Size>(), Ok(Size::Rem(1.5)));
+        assert_eq!("0".parse::<Size>(), Ok(Size::Zero));
+        assert_eq!("-4px".parse::<Size>(), Ok(Size::Px(-4.0)));
+        assert!("12".parse::<Size>().is_err());
+
+        assert_eq!("rgba(0, 0, 0, 0.5)".parse::<Color>(), Ok(Color::Rgba(0, 0, 0, 0.5)));
+        assert_eq!("RebeccaPurple".parse::<Color>(), Ok(Color::RebeccaPurple));
+        assert!("var(--color, red)".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_size_units() {
+        let sizes = [
+            (Size::Px(0.5), "0.5px"),
+            (Size::Cm(2.54), "2.54cm"),
+            (Size::Mm(-3.0), "-3mm"),
+            (Size::Q(4.0), "4Q"),
+            (Size::In(1.0), "1in"),
+            (Size::Pt(12.0), "12pt"),
+            (Size::Pc(1.5), "1.5pc"),
+            (Size::Ex(2.0), "2ex"),
+            (Size::Ch(60.0), "60ch"),
+            (Size::Lh(1.0), "1lh"),
+            (Size::Rlh(2.0), "2rlh"),
+            (Size::Vmin(10.0), "10vmin"),
+            (Size::Vmax(10.0), "10vmax"),
+            (Size::Dvw(100.0), "100dvw"),
+            (Size::Dvh(100.0), "100dvh"),
+            (Size::Svw(50.0), "50svw"),
+            (Size::Svh(50.0), "50svh"),
+            (Size::Lvw(25.0), "25lvw"),
+            (Size::Lvh(25.0), "25lvh"),
+            (Size::Cqw(50.0), "50cqw"),
+            (Size::Cqh(50.0), "50cqh"),
+            (Size::Cqi(33.3333), "33.3333cqi"),
+            (Size::Cqb(5.0), "5cqb"),
+        ];
+        for (size, css) in sizes {
+            assert_eq!(size.to_string(), css);
+            assert_round_trip(size);
+        }
+
+        assert_eq!("1q".parse::<Size>(), Ok(Size::Q(1.0)));
+        assert_eq!("2REM".parse::<Size>(), Ok(Size::Rem(2.0)));
+        assert_eq!("1vmin".parse::<Size>(), Ok(Size::Vmin(1.0)));
+        assert!("1vq".parse::<Size>().is_err());
+    }
+
+    #[test]
+    fn test_size_formatting() {
+        assert_eq!(Size::Px(0.1 + 0.2).to_string(), "0.3px");
+        assert_eq!(Size::Em(1.0 / 3.0).to_string(), "0.3333em");
+        assert_eq!(Size::Px(-0.00001).to_string(), "0px");
+        assert_eq!(Size::Percent(100.0).to_string(), "100%");
+        assert_eq!(Size::Px(1e6).to_string(), "1000000px");
+        assert_eq!(FontSize::Px(13.5).to_string(), "13.5px");
+    }
+
+    #[test]
+    fn test_non_finite_numbers() {
+        assert_eq!(Size::Px(f32::NAN).to_string(), "0px");
+        assert_eq!(Size::Rem(f32::INFINITY).to_string(), "0rem");
+        assert_eq!(FontSize::Em(f32::NEG_INFINITY).to_string(), "0em");
+        assert_eq!(Angle::Deg(f32::NAN).to_string(), "0deg");
+        assert_eq!(Time::Ms(f32::INFINITY).to_string(), "0ms");
+        assert_eq!((crate::calc::Calc::Number(f32::NAN) + Size::Px(2.0).into()).to_string(), "calc(0 + 2px)");
+
+        // Colors, line heights and animation values are written the same way
+        assert_eq!(Color::Lab(f32::NAN, 0.0, 0.0, 1.0).to_string(), "lab(0 0 0)");
+        assert_eq!(Color::Oklch(0.7, 0.1, 250.0, f32::NAN).to_string(), "oklch(0.7 0.1 250 / 0)");
+        assert_eq!(Color::Rgba(0, 0, 0, 0.1 + 0.2).to_string(), "rgba(0, 0, 0, 0.3)");
+        assert_eq!(LineHeight::Number(f32::INFINITY).to_string(), "0");
+        assert_eq!(IterationCount::Count(f32::INFINITY).to_string(), "0");
+        assert_eq!(TimingFunction::CubicBezier(f32::NAN, 0.0, 1.0, 1.0).to_string(), "cubic-bezier(0, 0, 1, 1)");
+    }
+
+    #[test]
+    fn test_dimension_units() {
+        for angle in ["90deg", "1.5708rad", "100grad", "-0.25turn", "var(--hue)"] {
+            assert_eq!(angle.parse::<Angle>().map(|a| a.to_string()).as_deref(), Ok(angle));
+        }
+        assert_eq!("45DEG".parse::<Angle>(), Ok(Angle::Deg(45.0)));
+        assert!("45".parse::<Angle>().is_err());
+        assert!("45px".parse::<Angle>().is_err());
+        assert_eq!(Angle::Rad(std::f32::consts::PI).to_deg(), Some(180.0));
+        assert_eq!(Angle::Deg(180.0).to_grad(), Some(200.0));
+        assert_eq!(Angle::Turn(0.5).to_rad(), Some(std::f32::consts::PI));
+
+        assert_round_trip(Time::S(0.3));
+        assert_round_trip(Time::Ms(150.0));
+        assert_round_trip(Time::Var(CssVar::new("speed")));
+        assert!("1m".parse::<Time>().is_err());
+        assert_eq!("2S".parse::<Time>(), Ok(Time::S(2.0)));
+        assert_eq!(Time::S(1.5).to_milliseconds(), Some(1500.0));
+        assert_eq!(Time::Var(CssVar::new("speed")).to_seconds(), None);
+
+        assert_round_trip(Frequency::Hz(440.0));
+        assert_round_trip(Frequency::KHz(2.5));
+        assert_eq!("1khz".parse::<Frequency>(), Ok(Frequency::KHz(1.0)));
+        assert_eq!(Frequency::Hz(250.0).to_khz(), Some(0.25));
+
+        assert_round_trip(Resolution::Dpi(300.0));
+        assert_round_trip(Resolution::Dpcm(118.0));
+        assert_round_trip(Resolution::Dppx(2.0));
+        assert_round_trip(Resolution::X(1.5));
+        assert_round_trip(Resolution::Var(CssVar::new("density")));
+        assert!("2xx".parse::<Resolution>().is_err());
+        assert_eq!(Resolution::Dppx(1.0).to_dpcm().map(|dpcm| (dpcm * 100.0).round() / 100.0), Some(37.8));
+        assert_eq!(Resolution::X(2.0).to_dpi(), Some(192.0));
+    }
+
+    #[test]
+    fn test_color_parse_keywords() {
+        assert_eq!(Color::parse("aliceblue"), Ok(Color::AliceBlue));
+        assert_eq!(Color::parse(" YellowGreen "), Ok(Color::YellowGreen));
+        assert_eq!(Color::parse("transparent"), Ok(Color::Transparent));
+        assert_eq!(Color::parse("currentColor"), Ok(Color::CurrentColor));
+        assert_eq!(Color::parse("inherit"), Ok(Color::Inherit));
+        assert_eq!(Color::parse("var(--brand)"), Ok(Color::Var(CssVar::new("brand"))));
+        assert!(Color::parse("reddish").is_err());
+    }
+
+    #[test]
+    fn test_color_parse_hex() {
+        assert_eq!(Color::parse("#AbC"), Ok(Color::Rgb(170, 187, 204)));
+        assert_eq!(Color::parse("#0f08"), Ok(Color::Rgba(0, 255, 0, 0.533)));
+        assert_eq!(Color::parse("#1e90ff"), Ok(Color::Rgb(30, 144, 255)));
+        assert_eq!(Color::parse("#00000000"), Ok(Color::Rgba(0, 0, 0, 0.0)));
+        assert!(Color::parse("#abcde").is_err());
+        assert!(Color::parse("#ggg").is_err());
+        assert!(Color::parse("#").is_err());
+    }
+
+    #[test]
+    fn test_color_parse_rgb() {
+        assert_eq!(Color::parse("rgb(255, 0, 128)"), Ok(Color::Rgb(255, 0, 128)));
+        assert_eq!(Color::parse("rgba(255, 0, 128, 0.25)"), Ok(Color::Rgba(255, 0, 128, 0.25)));
+        assert_eq!(Color::parse("rgb(100%, 50%, 0%)"), Ok(Color::Rgb(255, 128, 0)));
+        assert_eq!(Color::parse("RGB(255 0 128)"), Ok(Color::Rgb(255, 0, 128)));
+        assert_eq!(Color::parse("rgb(255 0 128 / 25%)"), Ok(Color::Rgba(255, 0, 128, 0.25)));
+        assert_eq!(Color::parse("rgba(12.4 0 0)"), Ok(Color::Rgb(12, 0, 0)));
+        assert!(Color::parse("rgb(0, 0)").is_err());
+        assert!(Color::parse("rgb(0 0 0 / 2 3)").is_err());
+        assert!(Color::parse("rgb(0 0 0 / )").is_err());
+    }
+
+    #[test]
+    fn test_color_parse_hsl() {
+        assert_eq!(Color::parse("hsl(120, 100%, 50%)"), Ok(Color::Hsl(120, 100, 50)));
+        assert_eq!(Color::parse("hsla(120, 100%, 50%, 0.3)"), Ok(Color::Hsla(120, 100, 50, 0.3)));
+        assert_eq!(Color::parse("hsl(-90deg 40% 60% / 10%)"), Ok(Color::Hsla(270, 40, 60, 0.1)));
+        assert_eq!(Color::parse("hsl(200grad 50 50)"), Ok(Color::Hsl(180, 50, 50)));
+        assert_eq!(Color::parse("hsl(3.14159rad 0% 0%)"), Ok(Color::Hsl(180, 0, 0)));
+        assert!(Color::parse("hsl(120, 100, 50)").is_err());
+    }
+
+    #[test]
+    fn test_color_parse_clamps() {
+        assert_eq!(Color::parse("rgb(300, -20, 0)"), Ok(Color::Rgb(255, 0, 0)));
+        assert_eq!(Color::parse("rgb(0 150% 50%)"), Ok(Color::Rgb(0, 255, 128)));
+        assert_eq!(Color::parse("rgba(0, 0, 0, 2)"), Ok(Color::Rgba(0, 0, 0, 1.0)));
+        assert_eq!(Color::parse("rgb(0 0 0 / -50%)"), Ok(Color::Rgba(0, 0, 0, 0.0)));
+        assert_eq!(Color::parse("hsl(120 101% -5%)"), Ok(Color::Hsl(120, 100, 0)));
+
+        // Numbers and percentages are only mixed in the modern syntax
+        assert_eq!(Color::parse("rgb(255 50% 0)"), Ok(Color::Rgb(255, 128, 0)));
+        assert!(Color::parse("rgb(255, 50%, 0)").is_err());
+        assert!(Color::parse("rgba(100%, 0, 0, 0.5)").is_err());
+
+        // The parser is lenient, the typed constructors are not
+        assert!(Color::try_rgba(0, 0, 0, 2.0).is_err());
+    }
+
+    #[test]
+    fn test_color_parse_modern_spaces() {
+        assert_eq!(Color::parse("oklch(62.8% 0.2577 29.23)"), Ok(Color::Oklch(0.628, 0.2577, 29.23, 1.0)));
+        assert_eq!(Color::parse("oklab(0.5 -50% 0.1 / 0.5)"), Ok(Color::Oklab(0.5, -0.2, 0.1, 0.5)));
+        assert_eq!(Color::parse("lab(54.29 80.8 none)"), Ok(Color::Lab(54.29, 80.8, 0.0, 1.0)));
+        assert_eq!(Color::parse("lch(50% 100% 0.5turn)"), Ok(Color::Lch(50.0, 150.0, 180.0, 1.0)));
+        assert_eq!(Color::parse("hwb(90 10% 20%)"), Ok(Color::Hwb(90.0, 10.0, 20.0, 1.0)));
+        assert_eq!(Color::parse("color(display-p3 1 0 50%)"), Ok(Color::DisplayP3(1.0, 0.0, 0.5, 1.0)));
+        assert_eq!(Color::parse("color(srgb 0.2 0.4 0.6 / 80%)"), Ok(Color::Srgb(0.2, 0.4, 0.6, 0.8)));
+        assert!(Color::parse("oklch(0.5, 0.1, 30)").is_err());
+        assert!(Color::parse("color(rec2020 1 0 0)").is_err());
+
+        assert_round_trip(Color::Oklch(0.7, 0.15, 250.0, 0.5));
+        assert_round_trip(Color::Lab(40.0, -20.5, 33.0, 1.0));
+        assert_round_trip(Color::Hwb(200.0, 0.0, 25.0, 1.0));
+        assert_round_trip(Color::DisplayP3(0.9, 0.1, 0.2, 1.0));
+    }
+
+    #[test]
+    fn test_color_parse_functions() {
+        use crate::color::ColorSpace;
+
+        let brand = || Color::Var(CssVar::new("brand"));
+        assert_eq!(
+            Color::parse("color-mix(in oklch, var(--brand) 40%, white)"),
+            Ok(Color::color_mix(ColorSpace::Oklch, brand(), 40.0, Color::White))
+        );
+        assert_eq!(
+            Color::parse("oklch(from var(--brand) calc(l + 0.1) c h)"),
+            Ok(Color::relative(ColorSpace::Oklch, brand(), ["calc(l + 0.1)", "c", "h"]))
+        );
+        assert_eq!(
+            Color::parse("light-dark(rgb(0, 0, 0), var(--brand))"),
+            Ok(Color::light_dark(Color::Rgb(0, 0, 0), brand()))
+        );
+        assert!(Color::parse("color-mix(oklch, red, blue)").is_err());
+        assert!(Color::parse("color-mix(in oklch, red 150%, blue)").is_err());
+        assert!(Color::parse("light-dark(red)").is_err());
+        assert!(Color::parse("rgb(from red r g)").is_err());
+        assert!(Color::parse("color(from red srgb r g b)").is_err());
+
+        assert_round_trip(Color::color_mix(ColorSpace::Srgb, Color::Red, 25.5, Color::Hsla(0, 0, 0, 0.5)));
+        assert_round_trip(Color::from(
+            crate::color::ColorMix::new(ColorSpace::Lch, brand(), Color::Blue).second_percentage(10.0),
+        ));
+        assert_round_trip(Color::from(
+            crate::color::RelativeColor::new(ColorSpace::DisplayP3, Color::Red, ["r", "g", "calc(b * 2)"]).alpha("0.5"),
+        ));
+        assert_round_trip(Color::light_dark(
+            Color::relative(ColorSpace::Hsl, brand(), ["h", "s", "calc(l - 20)"]),
+            Color::color_mix(ColorSpace::Oklab, brand(), 50.0, Color::Black),
+        ));
+    }
+
+    #[test]
+    fn test_numeric_values() {
+        assert_round_trip(FontWeight::Weight(600));
+        assert_round_trip(FontWeight::Bolder);
+        assert_round_trip(FontWeight::Weight(450));
+        assert!("1200".parse::<FontWeight>().is_err());
+
+        assert_round_trip(FontSize::XxLarge);
+        assert_round_trip(FontSize::Rem(1.25));
+        assert_round_trip(FontSize::Calc(crate::calc::Calc::from(Size::Rem(1.0) + Size::Vw(2.0))));
+
+        assert_round_trip(LineHeight::Number(1.5));
+        assert_round_trip(LineHeight::Percent(150.0));
+        assert_round_trip(LineHeight::Length(Size::Px(24.0)));
+        assert!("auto".parse::<LineHeight>().is_err());
+
+        assert_round_trip(ZIndex::Index(-1));
+        assert_round_trip(ZIndex::Auto);
+        assert_round_trip(IterationCount::Count(2.5));
+        assert_round_trip(IterationCount::Infinite);
+        assert!("-1".parse::<IterationCount>().is_err());
+    }
+
+    #[test]
+    fn test_compound_values() {
+        assert_round_trip(TimingFunction::CubicBezier(0.4, 0.0, 0.2, 1.0));
+        assert_round_trip(TimingFunction::Steps(4));
+        assert_round_trip(TimingFunction::EaseInOut);
+
+        assert_round_trip(BoxShadow {
+            h_offset: Size::Px(2.0),
+            v_offset: Size::Px(4.0),
+            blur: Some(Size::Px(8.0)),
+            spread: None,
+            color: Some(Color::Rgba(0, 0, 0, 0.2)),
+            inset: true,
+        });
+        assert_round_trip(BoxShadow {
+            h_offset: Size::Zero,
+            v_offset: Size::Px(1.0),
+            blur: None,
+            spread: None,
+            color: None,
+            inset: false,
+        });
+        assert!("2px".parse::<BoxShadow>().is_err());
+        assert!("1px 2px 3px 4px 5px".parse::<BoxShadow>().is_err());
+
+        assert_round_trip(Transition {
+            property: "opacity".to_string(),
+            duration: Time::S(0.3),
+            timing_function: Some("ease-in".to_string()),
+            delay: Some(Time::Ms(100.0)),
+        });
+        assert_eq!(
+            "color 1s 0.5s".parse::<Transition>().map(|t| (t.timing_function, t.delay)),
+            Ok((None, Some(Time::S(0.5))))
+        );
+        assert_eq!(
+            "color var(--duration) var(--easing)".parse::<Transition>().map(|t| t.to_string()).as_deref(),
+            Ok("color var(--duration) var(--easing)")
+        );
+        assert_eq!(
+            "color 200ms ease var(--delay)".parse::<Transition>().map(|t| t.delay),
+            Ok(Some(Time::Var(CssVar::new("delay"))))
+        );
+        assert!("color 1s bouncy".parse::<Transition>().is_err());
+        assert!("color 1 ease".parse::<Transition>().is_err());
+    }
+
+    #[test]
+    fn test_animation() {
+        let animation: Animation = "fade-in 0.5s cubic-bezier(0.4, 0, 0.2, 1) 2 alternate both"
+            .parse()
+            .unwrap();
+
+        assert_eq!(animation.name(), "fade-in");
+        assert_eq!(animation.iteration_count, Some(IterationCount::Count(2.0)));
+        assert_eq!(animation.delay, None);
+        assert_eq!(
+            animation.to_string(),
+            "fade-in 0.5s cubic-bezier(0.4, 0, 0.2, 1) 2 alternate both"
+        );
+
+        // Parts out of the Display order are rejected
+        assert!("fade-in 0.5s alternate 2".parse::<Animation>().is_err());
+        assert!("fade-in".parse::<Animation>().is_err());
+
+        // Durations and delays keep the unit they were written in
+        let animation: Animation = "spin 300ms linear 50ms infinite".parse().unwrap();
+        assert_eq!(animation.duration, Time::Ms(300.0));
+        assert_eq!(animation.delay, Some(Time::Ms(50.0)));
+        assert_eq!(animation.to_string(), "spin 300ms linear 50ms infinite");
+        assert!("spin 300 linear".parse::<Animation>().is_err());
+    }
+}
@@ -10,7 +10,9 @@
 //! 1. **Defining variables** - Use the `set_var` method on `Style` to define a variable
 //! 2. **Using variables** - Use the `var` function to create a reference to a variable
 
+use crate::values::ParseValueError;
 use std::fmt;
+use std::str::FromStr;
 
 /// Represents a reference to a CSS custom property (CSS variable).
 ///
@@ -57,6 +59,19 @@ impl CssVar {
         };
         Self(name)
     }
+
+    /// Returns the name of the variable, including the `--` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mew_css::variable::CssVar;
+    ///
+    /// assert_eq!(CssVar::new("primary-color").name(), "--primary-color");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.0
+    }
 }
 
 impl fmt::Display for CssVar {
@@ -65,6 +80,40 @@ impl fmt::Display for CssVar {
     }
 }
 
+/// Parses a variable reference written as `var(--name)`.
+///
+/// Fallback values (`var(--name, red)`) cannot be represented by `CssVar` and are rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// use mew_css::variable::CssVar;
+///
+/// let var: CssVar = "var(--primary-color)".parse().unwrap();
+/// assert_eq!(var, CssVar::new("primary-color"));
+///
+/// assert!("var(--primary, red)".parse::<CssVar>().is_err());
+/// ```
+impl FromStr for CssVar {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s
+            .trim()
+            .strip_prefix("var(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .map(str::trim)
+            .filter(|name| {
+                name.len() > 2
+                    && name.starts_with("--")
+                    && name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+            })
+            .ok_or_else(|| ParseValueError::new("CssVar", s))?;
+
+        Ok(CssVar::new(name))
+    }
+}
+
 /// Creates a new CSS variable reference.
 ///
 /// This is a convenience function that creates a new `CssVar` instance.